@@ -0,0 +1,321 @@
+//! Easing functions that reshape a linear progress `t` in [0, 1] into an eased progress.
+//!
+//! Every Penner family is defined by its "in" curve. The "out" variant is the "in" curve
+//! mirrored in time and value, and the "in-out" variant plays the "in" curve over the first half
+//! and the "out" curve over the second half. As in Penner's equations, the back and elastic
+//! in-out variants instead use a stronger overshoot and a shorter period of their own. All of
+//! them map 0 to 0 and 1 to 1; the back and elastic curves leave [0, 1] in between.
+//!
+//! # Example
+//! ```
+//! use vectorama::animation::easing::{CubicBezier, Easing};
+//! assert_eq!(Easing::QuadIn.ease(0.5), 0.25);
+//! assert_eq!(Easing::QuadOut.ease(0.5), 0.75);
+//! let ease = Easing::CubicBezier(CubicBezier::EASE_IN_OUT);
+//! assert!((ease.ease(0.5) - 0.5).abs() < 1e-4);
+//! ```
+
+use std::f32::consts::{FRAC_PI_2, PI};
+
+/// An easing curve.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub enum Easing {
+    /// No easing: the progress is returned unchanged.
+    #[default]
+    Linear,
+    QuadIn,
+    QuadOut,
+    QuadInOut,
+    CubicIn,
+    CubicOut,
+    CubicInOut,
+    QuartIn,
+    QuartOut,
+    QuartInOut,
+    QuintIn,
+    QuintOut,
+    QuintInOut,
+    SineIn,
+    SineOut,
+    SineInOut,
+    ExpoIn,
+    ExpoOut,
+    ExpoInOut,
+    CircIn,
+    CircOut,
+    CircInOut,
+    /// Pulls back slightly before moving towards the end.
+    BackIn,
+    BackOut,
+    BackInOut,
+    /// Oscillates with growing amplitude before snapping to the end.
+    ElasticIn,
+    ElasticOut,
+    ElasticInOut,
+    /// Bounces off the start like a ball dropped in reverse.
+    BounceIn,
+    BounceOut,
+    BounceInOut,
+    /// A CSS `cubic-bezier()` timing function.
+    CubicBezier(CubicBezier),
+}
+
+impl Easing {
+    /// Applies the easing curve to a progress value.
+    ///
+    /// # Parameters
+    /// - `t`: The linear progress, clamped to [0, 1].
+    ///
+    /// # Returns
+    /// The eased progress.
+    pub fn ease(&self, t: f32) -> f32 {
+        let t = t.clamp(0.0, 1.0);
+        match self {
+            Easing::Linear => t,
+            Easing::QuadIn => quad(t),
+            Easing::QuadOut => ease_out(quad, t),
+            Easing::QuadInOut => ease_in_out(quad, t),
+            Easing::CubicIn => cubic(t),
+            Easing::CubicOut => ease_out(cubic, t),
+            Easing::CubicInOut => ease_in_out(cubic, t),
+            Easing::QuartIn => quart(t),
+            Easing::QuartOut => ease_out(quart, t),
+            Easing::QuartInOut => ease_in_out(quart, t),
+            Easing::QuintIn => quint(t),
+            Easing::QuintOut => ease_out(quint, t),
+            Easing::QuintInOut => ease_in_out(quint, t),
+            Easing::SineIn => sine(t),
+            Easing::SineOut => ease_out(sine, t),
+            Easing::SineInOut => ease_in_out(sine, t),
+            Easing::ExpoIn => expo(t),
+            Easing::ExpoOut => ease_out(expo, t),
+            Easing::ExpoInOut => ease_in_out(expo, t),
+            Easing::CircIn => circ(t),
+            Easing::CircOut => ease_out(circ, t),
+            Easing::CircInOut => ease_in_out(circ, t),
+            Easing::BackIn => back(t),
+            Easing::BackOut => ease_out(back, t),
+            Easing::BackInOut => back_in_out(t),
+            Easing::ElasticIn => elastic(t),
+            Easing::ElasticOut => ease_out(elastic, t),
+            Easing::ElasticInOut => elastic_in_out(t),
+            Easing::BounceIn => bounce(t),
+            Easing::BounceOut => ease_out(bounce, t),
+            Easing::BounceInOut => ease_in_out(bounce, t),
+            Easing::CubicBezier(curve) => curve.ease(t),
+        }
+    }
+}
+
+/// A CSS `cubic-bezier(x1, y1, x2, y2)` timing function.
+///
+/// The curve runs from `(0, 0)` to `(1, 1)` with the two given control points. The x axis is
+/// the linear progress and the y axis the eased progress, so evaluating it means solving for
+/// the curve parameter at a given x.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CubicBezier {
+    pub x1: f32,
+    pub y1: f32,
+    pub x2: f32,
+    pub y2: f32,
+}
+
+impl CubicBezier {
+    /// The CSS `ease` timing function.
+    pub const EASE: Self = Self::new(0.25, 0.1, 0.25, 1.0);
+
+    /// The CSS `ease-in` timing function.
+    pub const EASE_IN: Self = Self::new(0.42, 0.0, 1.0, 1.0);
+
+    /// The CSS `ease-out` timing function.
+    pub const EASE_OUT: Self = Self::new(0.0, 0.0, 0.58, 1.0);
+
+    /// The CSS `ease-in-out` timing function.
+    pub const EASE_IN_OUT: Self = Self::new(0.42, 0.0, 0.58, 1.0);
+
+    /// Creates a timing function from its two control points.
+    ///
+    /// # Parameters
+    /// - `x1`, `y1`: The first control point; `x1` is clamped to [0, 1] as in CSS.
+    /// - `x2`, `y2`: The second control point; `x2` is clamped to [0, 1] as in CSS.
+    ///
+    /// # Returns
+    /// A new `CubicBezier`.
+    pub const fn new(x1: f32, y1: f32, x2: f32, y2: f32) -> Self {
+        Self {
+            x1: x1.clamp(0.0, 1.0),
+            y1,
+            x2: x2.clamp(0.0, 1.0),
+            y2,
+        }
+    }
+
+    /// Evaluates the timing function.
+    ///
+    /// # Parameters
+    /// - `t`: The linear progress, clamped to [0, 1].
+    ///
+    /// # Returns
+    /// The eased progress.
+    pub fn ease(&self, t: f32) -> f32 {
+        let x = t.clamp(0.0, 1.0);
+        let s = self.solve_parameter(x);
+        bezier(self.y1, self.y2, s)
+    }
+
+    /// Finds the curve parameter whose x coordinate is `x`, with Newton's method falling back
+    /// to bisection. The x coordinate is monotonic because both control points lie in [0, 1].
+    fn solve_parameter(&self, x: f32) -> f32 {
+        const EPSILON: f32 = 1e-6;
+
+        let mut s = x;
+        for _ in 0..8 {
+            let error = bezier(self.x1, self.x2, s) - x;
+            if error.abs() < EPSILON {
+                return s;
+            }
+            let slope = bezier_derivative(self.x1, self.x2, s);
+            if slope.abs() < EPSILON {
+                break;
+            }
+            s -= error / slope;
+        }
+
+        let (mut low, mut high) = (0.0, 1.0);
+        s = x;
+        for _ in 0..32 {
+            let value = bezier(self.x1, self.x2, s);
+            if (value - x).abs() < EPSILON {
+                break;
+            }
+            if value < x {
+                low = s;
+            } else {
+                high = s;
+            }
+            s = 0.5 * (low + high);
+        }
+        s
+    }
+}
+
+impl From<CubicBezier> for Easing {
+    fn from(curve: CubicBezier) -> Self {
+        Easing::CubicBezier(curve)
+    }
+}
+
+/// Evaluates one coordinate of a cubic Bézier curve from 0 to 1 with inner controls `a`, `b`.
+fn bezier(a: f32, b: f32, s: f32) -> f32 {
+    let inverse = 1.0 - s;
+    3.0 * inverse * inverse * s * a + 3.0 * inverse * s * s * b + s * s * s
+}
+
+/// Evaluates the derivative of [`bezier`] with respect to `s`.
+fn bezier_derivative(a: f32, b: f32, s: f32) -> f32 {
+    let inverse = 1.0 - s;
+    3.0 * inverse * inverse * a + 6.0 * inverse * s * (b - a) + 3.0 * s * s * (1.0 - b)
+}
+
+fn ease_out(ease_in: fn(f32) -> f32, t: f32) -> f32 {
+    1.0 - ease_in(1.0 - t)
+}
+
+fn ease_in_out(ease_in: fn(f32) -> f32, t: f32) -> f32 {
+    if t < 0.5 {
+        0.5 * ease_in(2.0 * t)
+    } else {
+        1.0 - 0.5 * ease_in(2.0 - 2.0 * t)
+    }
+}
+
+fn quad(t: f32) -> f32 {
+    t * t
+}
+
+fn cubic(t: f32) -> f32 {
+    t * t * t
+}
+
+fn quart(t: f32) -> f32 {
+    t * t * t * t
+}
+
+fn quint(t: f32) -> f32 {
+    t * t * t * t * t
+}
+
+fn sine(t: f32) -> f32 {
+    1.0 - (t * FRAC_PI_2).cos()
+}
+
+fn expo(t: f32) -> f32 {
+    if t <= 0.0 {
+        0.0
+    } else {
+        2.0f32.powf(10.0 * t - 10.0)
+    }
+}
+
+fn circ(t: f32) -> f32 {
+    1.0 - (1.0 - t * t).sqrt()
+}
+
+/// Overshoot of the back curves, about 10% of the distance.
+const BACK_OVERSHOOT: f32 = 1.70158;
+
+fn back(t: f32) -> f32 {
+    t * t * ((BACK_OVERSHOOT + 1.0) * t - BACK_OVERSHOOT)
+}
+
+fn back_in_out(t: f32) -> f32 {
+    // Each half covers half the distance, so the overshoot is scaled to keep it visible.
+    const OVERSHOOT: f32 = BACK_OVERSHOOT * 1.525;
+    let t = 2.0 * t;
+    if t < 1.0 {
+        0.5 * t * t * ((OVERSHOOT + 1.0) * t - OVERSHOOT)
+    } else {
+        let t = t - 2.0;
+        0.5 * (t * t * ((OVERSHOOT + 1.0) * t + OVERSHOOT) + 2.0)
+    }
+}
+
+fn elastic(t: f32) -> f32 {
+    if t <= 0.0 || t >= 1.0 {
+        return t;
+    }
+    -(2.0f32.powf(10.0 * t - 10.0)) * ((10.0 * t - 10.75) * (2.0 * PI / 3.0)).sin()
+}
+
+fn elastic_in_out(t: f32) -> f32 {
+    if t <= 0.0 || t >= 1.0 {
+        return t;
+    }
+    let wave = ((20.0 * t - 11.125) * (2.0 * PI / 4.5)).sin();
+    if t < 0.5 {
+        -0.5 * 2.0f32.powf(20.0 * t - 10.0) * wave
+    } else {
+        0.5 * 2.0f32.powf(10.0 - 20.0 * t) * wave + 1.0
+    }
+}
+
+fn bounce(t: f32) -> f32 {
+    1.0 - bounce_out(1.0 - t)
+}
+
+fn bounce_out(t: f32) -> f32 {
+    const N: f32 = 7.5625;
+    const D: f32 = 2.75;
+    if t < 1.0 / D {
+        N * t * t
+    } else if t < 2.0 / D {
+        let t = t - 1.5 / D;
+        N * t * t + 0.75
+    } else if t < 2.5 / D {
+        let t = t - 2.25 / D;
+        N * t * t + 0.9375
+    } else {
+        let t = t - 2.625 / D;
+        N * t * t + 0.984375
+    }
+}
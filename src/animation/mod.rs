@@ -0,0 +1,178 @@
+use std::ops::{Add, Mul, Sub};
+
+use crate::{
+    quaternion::unit::UnitQuaternion,
+    scale::scale3::Scale3,
+    translation::translation3::Translation3,
+    vector::{Vector, vec2::Vec2, vec3::Vec3, vec4::Vec4},
+};
+
+pub mod easing;
+pub mod spring;
+pub mod tween;
+
+/// A value that can be scaled and added, such as a velocity or the offset between two values.
+///
+/// Implemented for `f32`, [`Vec2`], [`Vec3`] and [`Vec4`].
+pub trait VectorSpace:
+    Copy + Default + Add<Output = Self> + Sub<Output = Self> + Mul<f32, Output = Self>
+{
+    /// Computes the dot product with another value.
+    ///
+    /// # Parameters
+    /// - `other`: The other value.
+    ///
+    /// # Returns
+    /// The dot product as a `f32`.
+    fn dot(&self, other: &Self) -> f32;
+
+    /// Computes the magnitude (length) of the value.
+    ///
+    /// # Returns
+    /// The magnitude as a `f32`.
+    fn magnitude(&self) -> f32 {
+        self.dot(self).sqrt()
+    }
+}
+
+/// A value that can be animated by moving it along a velocity.
+///
+/// Implemented for `f32`, [`Vec2`], [`Vec3`], [`Vec4`], [`Translation3`], [`Scale3`] and
+/// [`UnitQuaternion`].
+///
+/// Plain values move in a straight line and use themselves as the velocity type. A
+/// [`UnitQuaternion`] moves along a rotation and uses a world-frame rotation vector (axis scaled
+/// by angle) as its velocity, so smoothing a rotation turns it at a constant angular velocity
+/// rather than cutting through the inside of the rotation sphere.
+///
+/// # Example
+/// ```
+/// use vectorama::{Vec3, animation::Animatable};
+/// let a = Vec3::new(1.0, 2.0, 3.0);
+/// let b = Vec3::new(4.0, 6.0, 3.0);
+/// assert_eq!(a.offset(b.difference(&a)).as_array(), b.as_array());
+/// ```
+pub trait Animatable: Copy {
+    /// The type of velocities and of offsets between two values.
+    type Velocity: VectorSpace;
+
+    /// Computes the offset that moves `base` onto this value.
+    ///
+    /// # Parameters
+    /// - `base`: The value to measure from.
+    ///
+    /// # Returns
+    /// The offset `delta` such that `base.offset(delta)` equals this value.
+    fn difference(&self, base: &Self) -> Self::Velocity;
+
+    /// Moves this value by an offset.
+    ///
+    /// # Parameters
+    /// - `delta`: The offset to apply, such as a velocity multiplied by a time step.
+    ///
+    /// # Returns
+    /// The moved value.
+    fn offset(&self, delta: Self::Velocity) -> Self;
+
+    /// Interpolates towards another value along the path described by [`Animatable::offset`].
+    ///
+    /// This is a linear interpolation for plain values and a shortest-arc slerp for rotations.
+    ///
+    /// # Parameters
+    /// - `other`: The value at `t = 1`.
+    /// - `t`: Interpolation factor; values outside [0, 1] extrapolate.
+    ///
+    /// # Returns
+    /// The interpolated value.
+    fn interpolate(&self, other: &Self, t: f32) -> Self {
+        self.offset(other.difference(self) * t)
+    }
+}
+
+// VectorSpace ------------------------------------------------------------------------------------
+impl VectorSpace for f32 {
+    fn dot(&self, other: &Self) -> f32 {
+        self * other
+    }
+
+    fn magnitude(&self) -> f32 {
+        self.abs()
+    }
+}
+
+macro_rules! vector_space {
+    ($vec:ident) => {
+        impl VectorSpace for $vec {
+            fn dot(&self, other: &Self) -> f32 {
+                Vector::from(*self).dot(&Vector::from(*other))
+            }
+        }
+
+        impl Animatable for $vec {
+            type Velocity = $vec;
+
+            fn difference(&self, base: &Self) -> Self::Velocity {
+                *self - *base
+            }
+
+            fn offset(&self, delta: Self::Velocity) -> Self {
+                *self + delta
+            }
+        }
+    };
+}
+
+vector_space!(Vec2);
+vector_space!(Vec3);
+vector_space!(Vec4);
+// VectorSpace ------------------------------------------------------------------------------------
+
+// Animatable -------------------------------------------------------------------------------------
+impl Animatable for f32 {
+    type Velocity = f32;
+
+    fn difference(&self, base: &Self) -> Self::Velocity {
+        self - base
+    }
+
+    fn offset(&self, delta: Self::Velocity) -> Self {
+        self + delta
+    }
+}
+
+impl Animatable for Translation3 {
+    type Velocity = Vec3;
+
+    fn difference(&self, base: &Self) -> Self::Velocity {
+        **self - **base
+    }
+
+    fn offset(&self, delta: Self::Velocity) -> Self {
+        (**self + delta).into()
+    }
+}
+
+impl Animatable for Scale3 {
+    type Velocity = Vec3;
+
+    fn difference(&self, base: &Self) -> Self::Velocity {
+        **self - **base
+    }
+
+    fn offset(&self, delta: Self::Velocity) -> Self {
+        (**self + delta).into()
+    }
+}
+
+impl Animatable for UnitQuaternion {
+    type Velocity = Vec3;
+
+    fn difference(&self, base: &Self) -> Self::Velocity {
+        (self * base.inverse()).log()
+    }
+
+    fn offset(&self, delta: Self::Velocity) -> Self {
+        Self::from_scaled_axis(delta) * self
+    }
+}
+// Animatable -------------------------------------------------------------------------------------
@@ -0,0 +1,119 @@
+//! Frame-rate independent smoothing towards a moving target.
+//!
+//! Every function works for any [`Animatable`] value, including `f32`, the float vectors and
+//! [`UnitQuaternion`](crate::UnitQuaternion). The stateful ones read and update a velocity
+//! that the caller keeps between frames; start it at `Default::default()`.
+//!
+//! # Example
+//! ```
+//! use vectorama::{Vec3, animation::spring};
+//!
+//! let target = Vec3::new(10.0, 0.0, 0.0);
+//! let mut camera = Vec3::ZERO;
+//! let mut velocity = Vec3::ZERO;
+//! for _ in 0..120 {
+//!     camera = spring::smooth_damp(camera, target, &mut velocity, 0.3, f32::INFINITY, 1.0 / 60.0);
+//! }
+//! assert!((camera.x - 10.0).abs() < 0.01);
+//! ```
+
+use crate::animation::{Animatable, VectorSpace};
+
+/// Moves a value towards a target by a fixed fraction of the remaining distance per unit of
+/// time.
+///
+/// Equivalent to `lerp(current, target, 1 - exp(-decay_rate * dt))`, which gives the same result
+/// whether a second is simulated in one step or in many.
+///
+/// # Parameters
+/// - `current`: The current value.
+/// - `target`: The value to move towards.
+/// - `decay_rate`: How quickly the distance shrinks; it shrinks by a factor of `e` every
+///   `1 / decay_rate` units of time.
+/// - `dt`: The time step.
+///
+/// # Returns
+/// The value after `dt`.
+pub fn exponential_decay<T: Animatable>(current: T, target: T, decay_rate: f32, dt: f32) -> T {
+    target.offset(current.difference(&target) * (-decay_rate * dt).exp())
+}
+
+/// Moves a value towards a target with a critically damped spring.
+///
+/// The spring reaches the target as quickly as possible without oscillating. The motion is
+/// solved exactly, so it is stable and frame-rate independent for any `dt`.
+///
+/// # Parameters
+/// - `current`: The current value.
+/// - `target`: The rest position of the spring.
+/// - `velocity`: The current velocity, updated in place.
+/// - `angular_frequency`: The stiffness of the spring in radians per unit of time; the
+///   remaining distance roughly halves every `1.7 / angular_frequency` units of time.
+/// - `dt`: The time step.
+///
+/// # Returns
+/// The value after `dt`.
+pub fn critically_damped_spring<T: Animatable>(
+    current: T,
+    target: T,
+    velocity: &mut T::Velocity,
+    angular_frequency: f32,
+    dt: f32,
+) -> T {
+    let displacement = current.difference(&target);
+    let decay = (-angular_frequency * dt).exp();
+    let momentum = (*velocity + displacement * angular_frequency) * dt;
+    *velocity = (*velocity - momentum * angular_frequency) * decay;
+    target.offset((displacement + momentum) * decay)
+}
+
+/// Moves a value towards a target like a critically damped spring that reaches it in roughly
+/// `smooth_time`, with an optional speed limit.
+///
+/// Uses the rational approximation of the exponential popularized by Unity's `SmoothDamp`,
+/// which stays stable for large `dt`, and never overshoots the target.
+///
+/// # Parameters
+/// - `current`: The current value.
+/// - `target`: The value to move towards.
+/// - `velocity`: The current velocity, updated in place.
+/// - `smooth_time`: The approximate time to reach the target; clamped to at least `1e-4`.
+/// - `max_speed`: The largest speed; pass `f32::INFINITY` for no limit.
+/// - `dt`: The time step.
+///
+/// # Returns
+/// The value after `dt`.
+pub fn smooth_damp<T: Animatable>(
+    current: T,
+    target: T,
+    velocity: &mut T::Velocity,
+    smooth_time: f32,
+    max_speed: f32,
+    dt: f32,
+) -> T {
+    let smooth_time = smooth_time.max(1e-4);
+    let omega = 2.0 / smooth_time;
+    let x = omega * dt;
+    let decay = 1.0 / (1.0 + x + 0.48 * x * x + 0.235 * x * x * x);
+
+    // Work in offsets from the target, clamping how far away the spring may pull from.
+    let displacement = current.difference(&target);
+    let max_distance = max_speed * smooth_time;
+    let distance = displacement.magnitude();
+    let change = if distance > max_distance {
+        displacement * (max_distance / distance)
+    } else {
+        displacement
+    };
+
+    let momentum = (*velocity + change * omega) * dt;
+    *velocity = (*velocity - momentum * omega) * decay;
+    let mut result = displacement - change + (change + momentum) * decay;
+
+    // Stop on the target rather than passing it.
+    if displacement.dot(&result) < 0.0 {
+        result = T::Velocity::default();
+        *velocity = T::Velocity::default();
+    }
+    target.offset(result)
+}
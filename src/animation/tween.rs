@@ -0,0 +1,120 @@
+//! Time-based interpolation between two values with an easing curve.
+//!
+//! # Example
+//! ```
+//! use vectorama::{Vec3, animation::{easing::Easing, tween::Tween}};
+//!
+//! let mut tween = Tween::new(Vec3::ZERO, Vec3::new(10.0, 0.0, 0.0), 2.0, Easing::QuadIn);
+//! let halfway = tween.advance(1.0);
+//! assert_eq!(halfway.x, 2.5);
+//! assert!(!tween.is_finished());
+//! assert_eq!(tween.advance(5.0).x, 10.0);
+//! assert!(tween.is_finished());
+//! ```
+
+use crate::animation::{Animatable, easing::Easing};
+
+/// Eases a value from a start to an end over a fixed duration.
+///
+/// Works for any [`Animatable`] value; rotations follow the shortest arc.
+#[derive(Debug, Clone, Copy)]
+pub struct Tween<T: Animatable> {
+    pub start: T,
+    pub end: T,
+    pub duration: f32,
+    pub easing: Easing,
+    elapsed: f32,
+}
+
+impl<T: Animatable> Tween<T> {
+    /// Creates a tween at its start.
+    ///
+    /// # Parameters
+    /// - `start`: The value at the start of the tween.
+    /// - `end`: The value at the end of the tween.
+    /// - `duration`: The length of the tween; zero or less finishes immediately.
+    /// - `easing`: The easing curve applied to the progress.
+    ///
+    /// # Returns
+    /// A new `Tween`.
+    pub fn new(start: T, end: T, duration: f32, easing: Easing) -> Self {
+        Self {
+            start,
+            end,
+            duration,
+            easing,
+            elapsed: 0.0,
+        }
+    }
+
+    /// Advances the tween by a time step.
+    ///
+    /// # Parameters
+    /// - `dt`: The time step; the elapsed time is clamped to [0, `duration`].
+    ///
+    /// # Returns
+    /// The value after the step.
+    pub fn advance(&mut self, dt: f32) -> T {
+        self.elapsed = (self.elapsed + dt).clamp(0.0, self.duration.max(0.0));
+        self.value()
+    }
+
+    /// Computes the current value.
+    ///
+    /// # Returns
+    /// The value at the elapsed time.
+    pub fn value(&self) -> T {
+        self.start
+            .interpolate(&self.end, self.easing.ease(self.progress()))
+    }
+
+    /// Computes the value at a given time without changing the tween.
+    ///
+    /// # Parameters
+    /// - `time`: The time since the start, clamped to [0, `duration`].
+    ///
+    /// # Returns
+    /// The value at `time`.
+    pub fn sample(&self, time: f32) -> T {
+        let progress = if self.duration > 0.0 {
+            time / self.duration
+        } else {
+            1.0
+        };
+        self.start
+            .interpolate(&self.end, self.easing.ease(progress))
+    }
+
+    /// Computes the linear progress before easing.
+    ///
+    /// # Returns
+    /// The elapsed fraction of the duration in [0, 1].
+    pub fn progress(&self) -> f32 {
+        if self.duration > 0.0 {
+            (self.elapsed / self.duration).clamp(0.0, 1.0)
+        } else {
+            1.0
+        }
+    }
+
+    /// Returns the time since the start.
+    ///
+    /// # Returns
+    /// The elapsed time.
+    pub fn elapsed(&self) -> f32 {
+        self.elapsed
+    }
+
+    /// Checks whether the tween has reached its end.
+    ///
+    /// # Returns
+    /// `true` if the whole duration has elapsed.
+    pub fn is_finished(&self) -> bool {
+        self.progress() >= 1.0
+    }
+
+    /// Rewinds the tween to its start.
+    pub fn reset(&mut self) {
+        self.elapsed = 0.0;
+    }
+}
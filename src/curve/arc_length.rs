@@ -0,0 +1,205 @@
+//! Arc-length measurement and reparameterization of curves.
+//!
+//! Curves are rarely traversed at constant speed by their own parameter. An [`ArcLength`] table
+//! measures a curve once and then maps distances along it back to curve parameters, so objects
+//! can move along the curve at a constant speed.
+//!
+//! # Example
+//! ```
+//! use vectorama::{Vec2, curve::{Curve, bezier::CubicBezierCurve}};
+//!
+//! let curve = CubicBezierCurve::new([
+//!     Vec2::new(0.0, 0.0),
+//!     Vec2::new(0.0, 1.0),
+//!     Vec2::new(1.0, 1.0),
+//!     Vec2::new(1.0, 0.0),
+//! ]);
+//! let table = curve.arc_length(16);
+//! let halfway = table.parameter_at(0.5 * table.length());
+//! assert!((halfway - 0.5).abs() < 1e-4);
+//! ```
+
+use crate::{animation::VectorSpace, curve::Curve};
+
+/// A table of cumulative arc lengths over evenly spaced parameters of a curve.
+#[derive(Debug, Clone)]
+pub struct ArcLength<'a, C: Curve> {
+    curve: &'a C,
+    parameters: Vec<f32>,
+    lengths: Vec<f32>,
+}
+
+impl<'a, C: Curve> ArcLength<'a, C> {
+    /// Measures a curve.
+    ///
+    /// # Parameters
+    /// - `curve`: The curve to measure.
+    /// - `segments`: The number of parameter intervals to measure; at least one is used.
+    ///
+    /// # Returns
+    /// A new `ArcLength` table borrowing the curve.
+    pub fn new(curve: &'a C, segments: usize) -> Self {
+        let segments = segments.max(1);
+        let (start, end) = curve.domain();
+        let parameters: Vec<f32> = (0..=segments)
+            .map(|i| start + (end - start) * i as f32 / segments as f32)
+            .collect();
+        let mut lengths = Vec::with_capacity(parameters.len());
+        lengths.push(0.0);
+        for pair in parameters.windows(2) {
+            let previous = lengths[lengths.len() - 1];
+            lengths.push(previous + curve.length_between(pair[0], pair[1]));
+        }
+        Self {
+            curve,
+            parameters,
+            lengths,
+        }
+    }
+
+    /// Returns the measured curve.
+    ///
+    /// # Returns
+    /// A reference to the curve.
+    pub fn curve(&self) -> &'a C {
+        self.curve
+    }
+
+    /// Returns the length of the whole curve.
+    ///
+    /// # Returns
+    /// The arc length over the domain.
+    pub fn length(&self) -> f32 {
+        self.lengths[self.lengths.len() - 1]
+    }
+
+    /// Computes the arc length from the start of the curve to a parameter.
+    ///
+    /// # Parameters
+    /// - `t`: The curve parameter, clamped to the domain.
+    ///
+    /// # Returns
+    /// The distance along the curve.
+    pub fn length_at(&self, t: f32) -> f32 {
+        let (start, end) = self.curve.domain();
+        let t = t.clamp(start, end);
+        let index = interval(&self.parameters, t);
+        self.lengths[index] + self.curve.length_between(self.parameters[index], t)
+    }
+
+    /// Finds the curve parameter at a given distance along the curve.
+    ///
+    /// The table brackets the parameter, which is then refined with Newton's method on the arc
+    /// length, falling back to bisection whenever a step leaves the bracket.
+    ///
+    /// # Parameters
+    /// - `length`: The distance from the start of the curve, clamped to [0, `length()`].
+    ///
+    /// # Returns
+    /// The curve parameter.
+    pub fn parameter_at(&self, length: f32) -> f32 {
+        let length = length.clamp(0.0, self.length());
+        let index = interval(&self.lengths, length);
+        let (mut low, mut high) = (self.parameters[index], self.parameters[index + 1]);
+        let (base, span) = (
+            self.lengths[index],
+            self.lengths[index + 1] - self.lengths[index],
+        );
+        if span <= 0.0 {
+            return low;
+        }
+
+        let tolerance = 1e-6 * self.length();
+        let mut t = low + (high - low) * ((length - base) / span);
+        for _ in 0..16 {
+            let error = base + self.curve.length_between(self.parameters[index], t) - length;
+            if error.abs() <= tolerance {
+                break;
+            }
+            if error > 0.0 {
+                high = t;
+            } else {
+                low = t;
+            }
+            let speed = self.curve.derivative(t).magnitude();
+            let next = t - error / speed;
+            t = if speed > 0.0 && next > low && next < high {
+                next
+            } else {
+                0.5 * (low + high)
+            };
+        }
+        t
+    }
+
+    /// Evaluates the curve at a distance along it.
+    ///
+    /// # Parameters
+    /// - `length`: The distance from the start of the curve, clamped to [0, `length()`].
+    ///
+    /// # Returns
+    /// The point at that distance.
+    pub fn evaluate(&self, length: f32) -> C::Point {
+        self.curve.evaluate(self.parameter_at(length))
+    }
+
+    /// Computes curve parameters that are evenly spaced along the curve.
+    ///
+    /// # Parameters
+    /// - `count`: The number of parameters, including both ends; at least two are returned.
+    ///
+    /// # Returns
+    /// The increasing parameters, starting and ending at the ends of the domain.
+    pub fn uniform_parameters(&self, count: usize) -> Vec<f32> {
+        let intervals = count.max(2) - 1;
+        (0..=intervals)
+            .map(|i| self.parameter_at(self.length() * i as f32 / intervals as f32))
+            .collect()
+    }
+}
+
+/// Finds the table interval whose range of `values` holds `value`.
+fn interval(values: &[f32], value: f32) -> usize {
+    values[1..values.len() - 1].partition_point(|&entry| entry <= value)
+}
+
+/// Integrates a function with adaptive 5-point Gauss–Legendre quadrature.
+pub(crate) fn integrate<F: Fn(f32) -> f32>(f: F, start: f32, end: f32) -> f32 {
+    let whole = gauss_legendre(&f, start, end);
+    let tolerance = 1e-6 * whole.abs().max(f32::EPSILON);
+    refine(&f, start, end, whole, tolerance, 12)
+}
+
+fn refine<F: Fn(f32) -> f32>(
+    f: &F,
+    start: f32,
+    end: f32,
+    whole: f32,
+    tolerance: f32,
+    depth: u32,
+) -> f32 {
+    let middle = 0.5 * (start + end);
+    let left = gauss_legendre(f, start, middle);
+    let right = gauss_legendre(f, middle, end);
+    if depth == 0 || (left + right - whole).abs() <= tolerance {
+        return left + right;
+    }
+    refine(f, start, middle, left, 0.5 * tolerance, depth - 1)
+        + refine(f, middle, end, right, 0.5 * tolerance, depth - 1)
+}
+
+fn gauss_legendre<F: Fn(f32) -> f32>(f: &F, start: f32, end: f32) -> f32 {
+    const NODES: [(f32, f32); 5] = [
+        (0.0, 0.568_888_9),
+        (-0.538_469_3, 0.478_628_67),
+        (0.538_469_3, 0.478_628_67),
+        (-0.906_179_85, 0.236_926_88),
+        (0.906_179_85, 0.236_926_88),
+    ];
+    let center = 0.5 * (start + end);
+    let half = 0.5 * (end - start);
+    half * NODES
+        .iter()
+        .map(|&(node, weight)| weight * f(center + half * node))
+        .sum::<f32>()
+}
@@ -0,0 +1,210 @@
+//! B-splines of any degree with uniform or non-uniform knot vectors.
+//!
+//! A B-spline of degree `p` with `n` control points uses `n + p + 1` non-decreasing knots and is
+//! defined over the parameter range [`knots[p]`, `knots[n]`]. Unlike Bézier and Catmull-Rom
+//! curves, moving one control point only changes the `p + 1` segments around it, and the curve
+//! is `p - 1` times continuously differentiable wherever knots are not repeated.
+//!
+//! # Example
+//! ```
+//! use vectorama::{Vec2, curve::b_spline::BSpline};
+//!
+//! let points = vec![
+//!     Vec2::new(0.0, 0.0),
+//!     Vec2::new(1.0, 2.0),
+//!     Vec2::new(3.0, 2.0),
+//!     Vec2::new(4.0, 0.0),
+//! ];
+//! // A clamped spline starts and ends on its end control points.
+//! let spline = BSpline::clamped(3, points).unwrap();
+//! assert_eq!(spline.domain(), (0.0, 1.0));
+//! assert_eq!(spline.evaluate(1.0).as_array(), &[4.0, 0.0]);
+//! ```
+
+use crate::curve::{Curve, CurvePoint};
+
+/// A B-spline curve.
+#[derive(Debug, Clone)]
+pub struct BSpline<T: CurvePoint> {
+    degree: usize,
+    points: Vec<T>,
+    knots: Vec<f32>,
+}
+
+impl<T: CurvePoint> BSpline<T> {
+    /// Creates a B-spline from an explicit knot vector.
+    ///
+    /// # Parameters
+    /// - `degree`: The polynomial degree of each segment, at least 1.
+    /// - `points`: The control points; more than `degree` of them.
+    /// - `knots`: The finite, non-decreasing knot vector, with `points.len() + degree + 1`
+    ///   entries.
+    ///
+    /// # Returns
+    /// `Some(spline)`, or `None` if the inputs do not describe a valid B-spline with a non-empty
+    /// domain.
+    pub fn new(degree: usize, points: Vec<T>, knots: Vec<f32>) -> Option<Self> {
+        let n = points.len();
+        if degree == 0
+            || n <= degree
+            || knots.len() != n + degree + 1
+            || knots.iter().any(|knot| !knot.is_finite())
+            || knots.windows(2).any(|pair| pair[0] > pair[1])
+            || knots[degree] >= knots[n]
+        {
+            return None;
+        }
+        Some(Self {
+            degree,
+            points,
+            knots,
+        })
+    }
+
+    /// Creates a B-spline with evenly spaced knots `0, 1, 2, ...`.
+    ///
+    /// The curve does not pass through its end control points; its domain is
+    /// [`degree`, `points.len()`].
+    ///
+    /// # Parameters
+    /// - `degree`: The polynomial degree of each segment, at least 1.
+    /// - `points`: The control points; more than `degree` of them.
+    ///
+    /// # Returns
+    /// `Some(spline)`, or `None` if there are too few points or the degree is zero.
+    pub fn uniform(degree: usize, points: Vec<T>) -> Option<Self> {
+        let knots = (0..points.len() + degree + 1).map(|i| i as f32).collect();
+        Self::new(degree, points, knots)
+    }
+
+    /// Creates a B-spline with evenly spaced interior knots and `degree + 1` repeated knots at
+    /// each end, so the curve starts and ends on its end control points.
+    ///
+    /// The domain is [0, 1].
+    ///
+    /// # Parameters
+    /// - `degree`: The polynomial degree of each segment, at least 1.
+    /// - `points`: The control points; more than `degree` of them.
+    ///
+    /// # Returns
+    /// `Some(spline)`, or `None` if there are too few points or the degree is zero.
+    pub fn clamped(degree: usize, points: Vec<T>) -> Option<Self> {
+        if points.len() <= degree {
+            return None;
+        }
+        let segments = (points.len() - degree) as f32;
+        let knots = (0..points.len() + degree + 1)
+            .map(|i| (i.saturating_sub(degree) as f32 / segments).min(1.0))
+            .collect();
+        Self::new(degree, points, knots)
+    }
+
+    /// Returns the polynomial degree of each segment.
+    ///
+    /// # Returns
+    /// The degree.
+    pub fn degree(&self) -> usize {
+        self.degree
+    }
+
+    /// Returns the control points.
+    ///
+    /// # Returns
+    /// A slice of the control points.
+    pub fn points(&self) -> &[T] {
+        &self.points
+    }
+
+    /// Returns the knot vector.
+    ///
+    /// # Returns
+    /// A slice of the knots.
+    pub fn knots(&self) -> &[f32] {
+        &self.knots
+    }
+
+    /// Returns the parameter range spanned by the spline.
+    ///
+    /// # Returns
+    /// `(knots[degree], knots[points.len()])`.
+    pub fn domain(&self) -> (f32, f32) {
+        (self.knots[self.degree], self.knots[self.points.len()])
+    }
+
+    /// Evaluates the spline with de Boor's algorithm.
+    ///
+    /// # Parameters
+    /// - `u`: The spline parameter, clamped to the domain.
+    ///
+    /// # Returns
+    /// The point at `u`.
+    pub fn evaluate(&self, u: f32) -> T {
+        de_boor(self.degree, &self.points, &self.knots, u)
+    }
+
+    /// Evaluates the first derivative with respect to the spline parameter.
+    ///
+    /// # Parameters
+    /// - `u`: The spline parameter, clamped to the domain.
+    ///
+    /// # Returns
+    /// The tangent vector at `u`.
+    pub fn derivative(&self, u: f32) -> T {
+        // The derivative is a B-spline of one degree lower over the inner knots.
+        let p = self.degree;
+        let points: Vec<T> = self
+            .points
+            .windows(2)
+            .enumerate()
+            .map(|(i, pair)| {
+                let span = self.knots[i + p + 1] - self.knots[i + 1];
+                if span <= 0.0 {
+                    T::default()
+                } else {
+                    (pair[1] - pair[0]) * (p as f32 / span)
+                }
+            })
+            .collect();
+        de_boor(p - 1, &points, &self.knots[1..self.knots.len() - 1], u)
+    }
+}
+
+impl<T: CurvePoint> Curve for BSpline<T> {
+    type Point = T;
+
+    fn domain(&self) -> (f32, f32) {
+        BSpline::domain(self)
+    }
+
+    fn evaluate(&self, t: f32) -> Self::Point {
+        BSpline::evaluate(self, t)
+    }
+
+    fn derivative(&self, t: f32) -> Self::Point {
+        BSpline::derivative(self, t)
+    }
+}
+
+/// Evaluates the B-spline with the given degree, control points and knots at `u`.
+fn de_boor<T: CurvePoint>(degree: usize, points: &[T], knots: &[f32], u: f32) -> T {
+    let n = points.len();
+    let u = u.clamp(knots[degree], knots[n]);
+
+    // The knot span [knots[k], knots[k + 1]) holding `u`, using the last span at the very end.
+    let span = knots[degree + 1..n].partition_point(|&knot| knot <= u) + degree;
+
+    let mut d: Vec<T> = points[span - degree..=span].to_vec();
+    for r in 1..=degree {
+        for j in (r..=degree).rev() {
+            let i = j + span - degree;
+            let width = knots[i + degree + 1 - r] - knots[i];
+            let alpha = if width > 0.0 {
+                (u - knots[i]) / width
+            } else {
+                0.0
+            };
+            d[j] = d[j - 1] * (1.0 - alpha) + d[j] * alpha;
+        }
+    }
+    d[degree]
+}
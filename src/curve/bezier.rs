@@ -0,0 +1,340 @@
+//! Bézier curves of any degree over [`CurvePoint`]s.
+//!
+//! A [`Bezier<T, N>`] has `N` control points and degree `N - 1`; [`QuadraticBezierCurve`] and
+//! [`CubicBezierCurve`] name the common cases. Everything is computed with de Casteljau's
+//! algorithm, which stays numerically stable at any degree.
+//!
+//! # Example
+//! ```
+//! use vectorama::{Vec2, curve::bezier::CubicBezierCurve};
+//!
+//! let curve = CubicBezierCurve::new([
+//!     Vec2::new(0.0, 0.0),
+//!     Vec2::new(0.0, 1.0),
+//!     Vec2::new(1.0, 1.0),
+//!     Vec2::new(1.0, 0.0),
+//! ]);
+//! assert_eq!(curve.evaluate(0.5).as_array(), &[0.5, 0.75]);
+//! let (min, max) = curve.bounding_box();
+//! assert!((max.y - 0.75).abs() < 1e-5 && min.y == 0.0);
+//! ```
+
+use crate::curve::{Curve, CurvePoint};
+
+/// A Bézier curve with `N` control points.
+#[derive(Debug, Clone, Copy)]
+pub struct Bezier<T: CurvePoint, const N: usize> {
+    pub points: [T; N],
+}
+
+/// A Bézier curve of degree 2.
+pub type QuadraticBezierCurve<T> = Bezier<T, 3>;
+
+/// A Bézier curve of degree 3.
+pub type CubicBezierCurve<T> = Bezier<T, 4>;
+
+impl<T: CurvePoint, const N: usize> Bezier<T, N> {
+    /// Creates a curve from its control points.
+    ///
+    /// # Parameters
+    /// - `points`: The control points; the curve starts at the first and ends at the last.
+    ///
+    /// # Returns
+    /// A new `Bezier`.
+    ///
+    /// # Panics
+    /// Panics if `N` is zero.
+    pub fn new(points: [T; N]) -> Self {
+        assert!(N > 0, "a Bézier curve needs at least one control point");
+        Self { points }
+    }
+
+    /// Returns the degree of the curve.
+    ///
+    /// # Returns
+    /// `N - 1`.
+    pub fn degree(&self) -> usize {
+        N - 1
+    }
+
+    /// Evaluates the curve.
+    ///
+    /// # Parameters
+    /// - `t`: The curve parameter; [0, 1] spans the curve and other values extrapolate.
+    ///
+    /// # Returns
+    /// The point at `t`.
+    pub fn evaluate(&self, t: f32) -> T {
+        de_casteljau(self.points, N, t)
+    }
+
+    /// Evaluates the first derivative with respect to the curve parameter.
+    ///
+    /// # Parameters
+    /// - `t`: The curve parameter.
+    ///
+    /// # Returns
+    /// The tangent vector at `t`, whose length is the parametric speed.
+    pub fn derivative(&self, t: f32) -> T {
+        self.nth_derivative(1, t)
+    }
+
+    /// Evaluates the second derivative with respect to the curve parameter.
+    ///
+    /// # Parameters
+    /// - `t`: The curve parameter.
+    ///
+    /// # Returns
+    /// The second derivative at `t`.
+    pub fn second_derivative(&self, t: f32) -> T {
+        self.nth_derivative(2, t)
+    }
+
+    /// Evaluates a derivative of any order with respect to the curve parameter.
+    ///
+    /// # Parameters
+    /// - `order`: The order of the derivative; `0` evaluates the curve itself.
+    /// - `t`: The curve parameter.
+    ///
+    /// # Returns
+    /// The derivative at `t`, which is zero when `order` exceeds the degree.
+    pub fn nth_derivative(&self, order: usize, t: f32) -> T {
+        if order >= N {
+            return T::default();
+        }
+        let (points, len) = hodograph(self.points, N, order);
+        de_casteljau(points, len, t)
+    }
+
+    /// Splits the curve into two curves of the same degree.
+    ///
+    /// # Parameters
+    /// - `t`: The curve parameter to split at.
+    ///
+    /// # Returns
+    /// The part over [0, `t`] and the part over [`t`, 1], each reparameterized to [0, 1].
+    pub fn split(&self, t: f32) -> (Self, Self) {
+        let mut points = self.points;
+        let mut left = self.points;
+        let mut right = self.points;
+        for level in 1..N {
+            for i in 0..N - level {
+                points[i] = points[i] * (1.0 - t) + points[i + 1] * t;
+            }
+            left[level] = points[0];
+            right[N - 1 - level] = points[N - 1 - level];
+        }
+        (Self { points: left }, Self { points: right })
+    }
+
+    /// Computes the tight axis-aligned bounding box of the curve over [0, 1].
+    ///
+    /// The box is found from the endpoints and the extrema of every axis, which are the roots of
+    /// the derivative, rather than from the looser control polygon.
+    ///
+    /// # Returns
+    /// The component-wise minimum and maximum corners.
+    pub fn bounding_box(&self) -> (T, T) {
+        let mut min = self.points[0].min(&self.points[N - 1]);
+        let mut max = self.points[0].max(&self.points[N - 1]);
+        if N < 3 {
+            return (min, max);
+        }
+
+        // Every candidate lies on the curve, so spurious roots can only cost time, not accuracy.
+        let (derivative, len) = hodograph(self.points, N, 1);
+        let mut roots = Vec::new();
+        for axis in 0..T::DIMENSION {
+            let mut coefficients = [0.0; N];
+            for (coefficient, point) in coefficients.iter_mut().zip(&derivative[..len]) {
+                *coefficient = point.axis(axis);
+            }
+            let scale = coefficients
+                .iter()
+                .fold(0.0f32, |scale, c| scale.max(c.abs()));
+            let finder = RootFinder {
+                tolerance: 1e-6 * scale,
+                len,
+            };
+            finder.roots(coefficients, 0.0, 1.0, 24, &mut roots);
+        }
+        for t in roots {
+            let point = self.evaluate(t);
+            min = min.min(&point);
+            max = max.max(&point);
+        }
+        (min, max)
+    }
+
+    /// Finds the point of the curve closest to a given point.
+    ///
+    /// The curve is sampled to find the closest region, which is then refined with Newton's
+    /// method on the squared distance.
+    ///
+    /// # Parameters
+    /// - `point`: The point to project onto the curve.
+    ///
+    /// # Returns
+    /// The curve parameter in [0, 1] and the closest point of the curve.
+    pub fn closest_point(&self, point: &T) -> (f32, T) {
+        let samples = 8 * N.max(2);
+        let distance_squared = |t: f32| {
+            let offset = self.evaluate(t) - *point;
+            offset.dot(&offset)
+        };
+
+        let mut best = 0.0;
+        let mut best_distance = distance_squared(0.0);
+        for i in 1..=samples {
+            let t = i as f32 / samples as f32;
+            let distance = distance_squared(t);
+            if distance < best_distance {
+                best = t;
+                best_distance = distance;
+            }
+        }
+
+        for _ in 0..8 {
+            let offset = self.evaluate(best) - *point;
+            let first = self.derivative(best);
+            let slope = offset.dot(&first);
+            let curvature = first.dot(&first) + offset.dot(&self.second_derivative(best));
+            if curvature <= f32::EPSILON {
+                break;
+            }
+            let t = (best - slope / curvature).clamp(0.0, 1.0);
+            let distance = distance_squared(t);
+            if distance >= best_distance {
+                break;
+            }
+            best = t;
+            best_distance = distance;
+        }
+        (best, self.evaluate(best))
+    }
+
+    /// Approximates the curve with a polyline.
+    ///
+    /// The curve is subdivided until every control polygon lies within `tolerance` of its
+    /// chord, which bounds the distance between the curve and the polyline.
+    ///
+    /// # Parameters
+    /// - `tolerance`: The largest allowed distance between the curve and the polyline.
+    ///
+    /// # Returns
+    /// The polyline vertices, starting at the first control point and ending at the last.
+    pub fn flatten(&self, tolerance: f32) -> Vec<T> {
+        let mut vertices = vec![self.points[0]];
+        self.flatten_into(tolerance, 16, &mut vertices);
+        vertices
+    }
+
+    fn flatten_into(&self, tolerance: f32, depth: u32, vertices: &mut Vec<T>) {
+        let (start, end) = (self.points[0], self.points[N - 1]);
+        let flat = self.points[1..N.saturating_sub(1)]
+            .iter()
+            .all(|point| point.distance_to_segment(&start, &end) <= tolerance);
+        if flat || depth == 0 {
+            vertices.push(end);
+            return;
+        }
+        let (left, right) = self.split(0.5);
+        left.flatten_into(tolerance, depth - 1, vertices);
+        right.flatten_into(tolerance, depth - 1, vertices);
+    }
+}
+
+impl<T: CurvePoint, const N: usize> Curve for Bezier<T, N> {
+    type Point = T;
+
+    fn domain(&self) -> (f32, f32) {
+        (0.0, 1.0)
+    }
+
+    fn evaluate(&self, t: f32) -> Self::Point {
+        Bezier::evaluate(self, t)
+    }
+
+    fn derivative(&self, t: f32) -> Self::Point {
+        Bezier::derivative(self, t)
+    }
+
+    fn second_derivative(&self, t: f32) -> Self::Point {
+        Bezier::second_derivative(self, t)
+    }
+}
+
+impl<T: CurvePoint, const N: usize> From<[T; N]> for Bezier<T, N> {
+    fn from(points: [T; N]) -> Self {
+        Self::new(points)
+    }
+}
+
+/// Evaluates the Bézier curve given by the first `len` entries of `points`.
+fn de_casteljau<T: CurvePoint, const N: usize>(mut points: [T; N], len: usize, t: f32) -> T {
+    for level in 1..len {
+        for i in 0..len - level {
+            points[i] = points[i] * (1.0 - t) + points[i + 1] * t;
+        }
+    }
+    points[0]
+}
+
+/// Computes the control points of a derivative curve, returning them and their count.
+fn hodograph<T: CurvePoint, const N: usize>(
+    mut points: [T; N],
+    mut len: usize,
+    order: usize,
+) -> ([T; N], usize) {
+    for _ in 0..order {
+        let degree = (len - 1) as f32;
+        for i in 0..len - 1 {
+            points[i] = (points[i + 1] - points[i]) * degree;
+        }
+        len -= 1;
+    }
+    (points, len)
+}
+
+/// Finds the roots of a polynomial given by its first `len` Bernstein coefficients.
+struct RootFinder {
+    /// Coefficients this small are treated as zero.
+    tolerance: f32,
+    len: usize,
+}
+
+impl RootFinder {
+    /// Collects the roots in [`start`, `end`], using the convex hull property of the Bernstein
+    /// basis to discard intervals without a sign change.
+    fn roots<const N: usize>(
+        &self,
+        coefficients: [f32; N],
+        start: f32,
+        end: f32,
+        depth: u32,
+        roots: &mut Vec<f32>,
+    ) {
+        let used = &coefficients[..self.len];
+        if used.iter().all(|&c| c > self.tolerance) || used.iter().all(|&c| c < -self.tolerance) {
+            return;
+        }
+        let middle = 0.5 * (start + end);
+        if depth == 0 || used.iter().all(|&c| c.abs() <= self.tolerance) {
+            roots.push(middle);
+            return;
+        }
+
+        let mut left = coefficients;
+        let mut right = coefficients;
+        let mut values = coefficients;
+        for level in 1..self.len {
+            for i in 0..self.len - level {
+                values[i] = 0.5 * (values[i] + values[i + 1]);
+            }
+            left[level] = values[0];
+            right[self.len - 1 - level] = values[self.len - 1 - level];
+        }
+        self.roots(left, start, middle, depth - 1, roots);
+        self.roots(right, middle, end, depth - 1, roots);
+    }
+}
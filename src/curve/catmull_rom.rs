@@ -0,0 +1,207 @@
+//! Catmull-Rom splines, which pass through their control points.
+//!
+//! Each segment between two points is shaped by the points on either side. The
+//! [`Parameterization`] controls how the spacing of the points affects the tangents: the
+//! centripetal variant never forms cusps or self-intersections within a segment, which makes it
+//! the usual choice for paths through unevenly spaced waypoints.
+//!
+//! # Example
+//! ```
+//! use vectorama::{Vec2, curve::catmull_rom::{CatmullRom, Parameterization}};
+//!
+//! let waypoints = [Vec2::new(0.0, 0.0), Vec2::new(1.0, 2.0), Vec2::new(4.0, 2.0)];
+//! let path = CatmullRom::through(&waypoints, Parameterization::Centripetal).unwrap();
+//! assert_eq!(path.segment_count(), 2);
+//! assert_eq!(path.evaluate(1.0).as_array(), &[1.0, 2.0]);
+//! ```
+
+use crate::curve::{Curve, CurvePoint, hermite::Hermite, locate_segment};
+
+/// How the spacing of the control points shapes a Catmull-Rom spline.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Parameterization {
+    /// Ignores the spacing; segments can cusp and overshoot when points are uneven.
+    Uniform,
+    /// Weights by the square root of the distance; never cusps or self-intersects in a segment.
+    #[default]
+    Centripetal,
+    /// Weights by the distance; follows the control polygon most tightly.
+    Chordal,
+}
+
+impl Parameterization {
+    /// Returns the exponent applied to the distance between consecutive points.
+    ///
+    /// # Returns
+    /// `0` for uniform, `0.5` for centripetal and `1` for chordal.
+    pub fn alpha(&self) -> f32 {
+        match self {
+            Parameterization::Uniform => 0.0,
+            Parameterization::Centripetal => 0.5,
+            Parameterization::Chordal => 1.0,
+        }
+    }
+}
+
+/// A Catmull-Rom spline through a list of control points.
+///
+/// The spline runs from the second point to the second-to-last point; the first and last points
+/// only shape the end segments. Segment `i` runs from `points[i + 1]` to `points[i + 2]` over
+/// the spline parameter range [`i`, `i + 1`]. With a non-uniform parameterization the tangent
+/// direction is continuous where segments meet, but its length is not, because every segment
+/// spans one unit of the spline parameter regardless of its knot interval.
+#[derive(Debug, Clone)]
+pub struct CatmullRom<T: CurvePoint> {
+    points: Vec<T>,
+    parameterization: Parameterization,
+}
+
+impl<T: CurvePoint> CatmullRom<T> {
+    /// Creates a spline from its control points.
+    ///
+    /// # Parameters
+    /// - `points`: The control points, including the two outer ones that only shape the ends.
+    /// - `parameterization`: How the spacing of the points shapes the spline.
+    ///
+    /// # Returns
+    /// `Some(spline)`, or `None` if there are fewer than four points.
+    pub fn new(points: Vec<T>, parameterization: Parameterization) -> Option<Self> {
+        if points.len() < 4 {
+            return None;
+        }
+        Some(Self {
+            points,
+            parameterization,
+        })
+    }
+
+    /// Creates a spline that passes through every waypoint, from the first to the last.
+    ///
+    /// The outer control points are extrapolated by mirroring the second and second-to-last
+    /// waypoints through the ends.
+    ///
+    /// # Parameters
+    /// - `waypoints`: The points to pass through.
+    /// - `parameterization`: How the spacing of the points shapes the spline.
+    ///
+    /// # Returns
+    /// `Some(spline)`, or `None` if there are fewer than two waypoints.
+    pub fn through(waypoints: &[T], parameterization: Parameterization) -> Option<Self> {
+        let n = waypoints.len();
+        if n < 2 {
+            return None;
+        }
+        let mut points = Vec::with_capacity(n + 2);
+        points.push(waypoints[0] * 2.0 - waypoints[1]);
+        points.extend_from_slice(waypoints);
+        points.push(waypoints[n - 1] * 2.0 - waypoints[n - 2]);
+        Self::new(points, parameterization)
+    }
+
+    /// Returns the control points.
+    ///
+    /// # Returns
+    /// A slice of the control points.
+    pub fn points(&self) -> &[T] {
+        &self.points
+    }
+
+    /// Returns how the spacing of the points shapes the spline.
+    ///
+    /// # Returns
+    /// The parameterization.
+    pub fn parameterization(&self) -> Parameterization {
+        self.parameterization
+    }
+
+    /// Returns the number of segments.
+    ///
+    /// # Returns
+    /// Three less than the number of control points.
+    pub fn segment_count(&self) -> usize {
+        self.points.len() - 3
+    }
+
+    /// Returns one segment of the spline as a Hermite segment.
+    ///
+    /// # Parameters
+    /// - `index`: The index of the segment.
+    ///
+    /// # Returns
+    /// The segment from `points[index + 1]` to `points[index + 2]`.
+    ///
+    /// # Panics
+    /// Panics if `index` is not below [`CatmullRom::segment_count`].
+    pub fn segment(&self, index: usize) -> Hermite<T> {
+        let [p0, p1, p2, p3] = [0, 1, 2, 3].map(|i| self.points[index + i]);
+        let alpha = self.parameterization.alpha();
+        let spacing = |a: T, b: T| {
+            let spacing = (b - a).magnitude().powf(alpha);
+            // Coincident points would divide by zero; treat them as evenly spaced.
+            if spacing <= f32::EPSILON {
+                1.0
+            } else {
+                spacing
+            }
+        };
+        let (d0, d1, d2) = (spacing(p0, p1), spacing(p1, p2), spacing(p2, p3));
+
+        // Barry and Goldman's tangents, scaled from knot time to the segment's [0, 1].
+        let start_tangent = ((p1 - p0) * (1.0 / d0) - (p2 - p0) * (1.0 / (d0 + d1))) * d1 + p2 - p1;
+        let end_tangent = p2 - p1 + ((p3 - p2) * (1.0 / d2) - (p3 - p1) * (1.0 / (d1 + d2))) * d1;
+        Hermite::new(p1, start_tangent, p2, end_tangent)
+    }
+
+    /// Returns the parameter range spanned by the spline.
+    ///
+    /// # Returns
+    /// `(0, segment_count)`.
+    pub fn domain(&self) -> (f32, f32) {
+        (0.0, self.segment_count() as f32)
+    }
+
+    /// Evaluates the spline.
+    ///
+    /// # Parameters
+    /// - `t`: The spline parameter; values outside the domain extrapolate the end segments.
+    ///
+    /// # Returns
+    /// The point at `t`.
+    pub fn evaluate(&self, t: f32) -> T {
+        let (index, local) = locate_segment(t, self.segment_count());
+        self.segment(index).evaluate(local)
+    }
+
+    /// Evaluates the first derivative with respect to the spline parameter.
+    ///
+    /// # Parameters
+    /// - `t`: The spline parameter.
+    ///
+    /// # Returns
+    /// The tangent vector at `t`.
+    pub fn derivative(&self, t: f32) -> T {
+        let (index, local) = locate_segment(t, self.segment_count());
+        self.segment(index).derivative(local)
+    }
+}
+
+impl<T: CurvePoint> Curve for CatmullRom<T> {
+    type Point = T;
+
+    fn domain(&self) -> (f32, f32) {
+        CatmullRom::domain(self)
+    }
+
+    fn evaluate(&self, t: f32) -> Self::Point {
+        CatmullRom::evaluate(self, t)
+    }
+
+    fn derivative(&self, t: f32) -> Self::Point {
+        CatmullRom::derivative(self, t)
+    }
+
+    fn second_derivative(&self, t: f32) -> Self::Point {
+        let (index, local) = locate_segment(t, self.segment_count());
+        self.segment(index).second_derivative(local)
+    }
+}
@@ -0,0 +1,108 @@
+//! Moving frames that orient objects along a curve.
+
+use crate::{quaternion::unit::UnitQuaternion, vector::vec3::Vec3};
+
+/// An orthonormal frame attached to a point of a curve.
+#[derive(Debug, Clone, Copy)]
+pub struct Frame {
+    pub position: Vec3,
+    /// The unit direction of travel.
+    pub tangent: Vec3,
+    /// A unit vector perpendicular to the tangent.
+    pub normal: Vec3,
+    /// The unit vector `tangent × normal` completing the right-handed frame.
+    pub binormal: Vec3,
+}
+
+impl Frame {
+    /// Computes the orientation of the frame.
+    ///
+    /// Follows the convention of [`UnitQuaternion::look_rotation`]: the local `-Z` axis points
+    /// along the tangent, the local `Y` axis along the normal and the local `X` axis along the
+    /// binormal.
+    ///
+    /// # Returns
+    /// The rotation from local space to the frame.
+    pub fn orientation(&self) -> UnitQuaternion {
+        UnitQuaternion::look_rotation(self.tangent, self.normal)
+    }
+}
+
+/// Builds the Frenet frame from a position and the first two derivatives.
+pub(crate) fn frenet(position: Vec3, first: Vec3, second: Vec3) -> Option<Frame> {
+    let speed = first.magnitude();
+    if speed <= f32::EPSILON {
+        return None;
+    }
+    let binormal = first.cross(&second);
+    if binormal.magnitude() <= 1e-6 * speed * second.magnitude().max(speed) {
+        return None;
+    }
+    let tangent = first * (1.0 / speed);
+    let binormal = binormal.normalize();
+    Some(Frame {
+        position,
+        tangent,
+        normal: binormal.cross(&tangent),
+        binormal,
+    })
+}
+
+/// Propagates a frame along `(position, derivative)` samples with the double reflection method
+/// of Wang, Jüttler, Zheng and Liu.
+pub(crate) fn rotation_minimizing(samples: &[(Vec3, Vec3)], initial_normal: Vec3) -> Vec<Frame> {
+    let mut frames: Vec<Frame> = Vec::with_capacity(samples.len());
+    for &(position, derivative) in samples {
+        let Some(previous) = frames.last() else {
+            let tangent = direction(derivative, Vec3::new(0.0, 0.0, -1.0));
+            let mut normal = initial_normal - tangent * tangent.dot(&initial_normal);
+            if normal.magnitude() <= 1e-6 {
+                normal = tangent.any_orthonormal_basis().0;
+            }
+            frames.push(frame(position, tangent, normal.normalize()));
+            continue;
+        };
+        let tangent = direction(derivative, previous.tangent);
+
+        // Reflect across the bisector of the two positions, then across the bisector of the
+        // reflected and actual tangents. One reflection alone would mirror the frame, so when
+        // either bisector is undefined the frame is rotated from the previous tangent instead.
+        let v1 = position - previous.position;
+        let c1 = v1.dot(&v1);
+        let reflect =
+            |vector: Vec3, axis: Vec3, c: f32| vector - axis * (2.0 / c * axis.dot(&vector));
+        let reflected_tangent = reflect(previous.tangent, v1, c1);
+        let v2 = tangent - reflected_tangent;
+        let c2 = v2.dot(&v2);
+        let normal = if c1 > 0.0 && (2.0 / c1).is_finite() && c2 > f32::EPSILON {
+            reflect(reflect(previous.normal, v1, c1), v2, c2)
+        } else {
+            UnitQuaternion::rotation_between(previous.tangent, tangent)
+                .rotate_vector(previous.normal)
+        };
+
+        // Remove the rounding error that builds up over many reflections.
+        let normal = (normal - tangent * tangent.dot(&normal)).normalize();
+        frames.push(frame(position, tangent, normal));
+    }
+    frames
+}
+
+fn frame(position: Vec3, tangent: Vec3, normal: Vec3) -> Frame {
+    Frame {
+        position,
+        tangent,
+        normal,
+        binormal: tangent.cross(&normal),
+    }
+}
+
+/// Normalizes a derivative, falling back to `fallback` where the curve is stationary.
+fn direction(derivative: Vec3, fallback: Vec3) -> Vec3 {
+    let speed = derivative.magnitude();
+    if speed <= f32::EPSILON {
+        fallback
+    } else {
+        derivative * (1.0 / speed)
+    }
+}
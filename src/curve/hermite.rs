@@ -0,0 +1,247 @@
+//! Cubic Hermite curves, defined by their endpoints and the tangents at them.
+//!
+//! # Example
+//! ```
+//! use vectorama::{Vec2, curve::hermite::HermiteSpline};
+//!
+//! let spline = HermiteSpline::new(
+//!     vec![Vec2::new(0.0, 0.0), Vec2::new(1.0, 1.0), Vec2::new(2.0, 0.0)],
+//!     vec![Vec2::new(1.0, 0.0), Vec2::new(1.0, 0.0), Vec2::new(1.0, 0.0)],
+//! )
+//! .unwrap();
+//! assert_eq!(spline.segment_count(), 2);
+//! assert_eq!(spline.evaluate(1.0).as_array(), &[1.0, 1.0]);
+//! ```
+
+use crate::curve::{Curve, CurvePoint, bezier::CubicBezierCurve, locate_segment};
+
+/// A cubic Hermite segment over the parameter range [0, 1].
+#[derive(Debug, Clone, Copy)]
+pub struct Hermite<T: CurvePoint> {
+    pub start: T,
+    pub start_tangent: T,
+    pub end: T,
+    pub end_tangent: T,
+}
+
+impl<T: CurvePoint> Hermite<T> {
+    /// Creates a segment from its endpoints and tangents.
+    ///
+    /// # Parameters
+    /// - `start`: The point at `t = 0`.
+    /// - `start_tangent`: The derivative at `t = 0`.
+    /// - `end`: The point at `t = 1`.
+    /// - `end_tangent`: The derivative at `t = 1`.
+    ///
+    /// # Returns
+    /// A new `Hermite`.
+    pub fn new(start: T, start_tangent: T, end: T, end_tangent: T) -> Self {
+        Self {
+            start,
+            start_tangent,
+            end,
+            end_tangent,
+        }
+    }
+
+    /// Evaluates the segment.
+    ///
+    /// # Parameters
+    /// - `t`: The curve parameter; [0, 1] spans the segment and other values extrapolate.
+    ///
+    /// # Returns
+    /// The point at `t`.
+    pub fn evaluate(&self, t: f32) -> T {
+        let t2 = t * t;
+        let t3 = t2 * t;
+        self.start * (2.0 * t3 - 3.0 * t2 + 1.0)
+            + self.start_tangent * (t3 - 2.0 * t2 + t)
+            + self.end * (3.0 * t2 - 2.0 * t3)
+            + self.end_tangent * (t3 - t2)
+    }
+
+    /// Evaluates the first derivative with respect to the curve parameter.
+    ///
+    /// # Parameters
+    /// - `t`: The curve parameter.
+    ///
+    /// # Returns
+    /// The tangent vector at `t`.
+    pub fn derivative(&self, t: f32) -> T {
+        let t2 = t * t;
+        self.start * (6.0 * t2 - 6.0 * t)
+            + self.start_tangent * (3.0 * t2 - 4.0 * t + 1.0)
+            + self.end * (6.0 * t - 6.0 * t2)
+            + self.end_tangent * (3.0 * t2 - 2.0 * t)
+    }
+
+    /// Evaluates the second derivative with respect to the curve parameter.
+    ///
+    /// # Parameters
+    /// - `t`: The curve parameter.
+    ///
+    /// # Returns
+    /// The second derivative at `t`.
+    pub fn second_derivative(&self, t: f32) -> T {
+        self.start * (12.0 * t - 6.0)
+            + self.start_tangent * (6.0 * t - 4.0)
+            + self.end * (6.0 - 12.0 * t)
+            + self.end_tangent * (6.0 * t - 2.0)
+    }
+
+    /// Converts the segment to the equivalent cubic Bézier curve.
+    ///
+    /// # Returns
+    /// A `CubicBezierCurve` tracing the same curve with the same parameterization.
+    pub fn to_bezier(&self) -> CubicBezierCurve<T> {
+        CubicBezierCurve::new([
+            self.start,
+            self.start + self.start_tangent * (1.0 / 3.0),
+            self.end - self.end_tangent * (1.0 / 3.0),
+            self.end,
+        ])
+    }
+}
+
+impl<T: CurvePoint> Curve for Hermite<T> {
+    type Point = T;
+
+    fn domain(&self) -> (f32, f32) {
+        (0.0, 1.0)
+    }
+
+    fn evaluate(&self, t: f32) -> Self::Point {
+        Hermite::evaluate(self, t)
+    }
+
+    fn derivative(&self, t: f32) -> Self::Point {
+        Hermite::derivative(self, t)
+    }
+
+    fn second_derivative(&self, t: f32) -> Self::Point {
+        Hermite::second_derivative(self, t)
+    }
+}
+
+/// A chain of cubic Hermite segments through a list of points.
+///
+/// Segment `i` runs from `points[i]` to `points[i + 1]` over the spline parameter range
+/// [`i`, `i + 1`], so the whole spline spans [0, `segment_count`].
+#[derive(Debug, Clone)]
+pub struct HermiteSpline<T: CurvePoint> {
+    points: Vec<T>,
+    tangents: Vec<T>,
+}
+
+impl<T: CurvePoint> HermiteSpline<T> {
+    /// Creates a spline from its points and the tangents at them.
+    ///
+    /// # Parameters
+    /// - `points`: The points the spline passes through.
+    /// - `tangents`: The derivative of the spline at each point.
+    ///
+    /// # Returns
+    /// `Some(spline)`, or `None` if there are fewer than two points or the lengths differ.
+    pub fn new(points: Vec<T>, tangents: Vec<T>) -> Option<Self> {
+        if points.len() < 2 || points.len() != tangents.len() {
+            return None;
+        }
+        Some(Self { points, tangents })
+    }
+
+    /// Returns the points the spline passes through.
+    ///
+    /// # Returns
+    /// A slice of the points.
+    pub fn points(&self) -> &[T] {
+        &self.points
+    }
+
+    /// Returns the tangents at the points.
+    ///
+    /// # Returns
+    /// A slice of the tangents.
+    pub fn tangents(&self) -> &[T] {
+        &self.tangents
+    }
+
+    /// Returns the number of segments.
+    ///
+    /// # Returns
+    /// One less than the number of points.
+    pub fn segment_count(&self) -> usize {
+        self.points.len() - 1
+    }
+
+    /// Returns one segment of the spline.
+    ///
+    /// # Parameters
+    /// - `index`: The index of the segment.
+    ///
+    /// # Returns
+    /// The segment from `points[index]` to `points[index + 1]`.
+    ///
+    /// # Panics
+    /// Panics if `index` is not below [`HermiteSpline::segment_count`].
+    pub fn segment(&self, index: usize) -> Hermite<T> {
+        Hermite::new(
+            self.points[index],
+            self.tangents[index],
+            self.points[index + 1],
+            self.tangents[index + 1],
+        )
+    }
+
+    /// Returns the parameter range spanned by the spline.
+    ///
+    /// # Returns
+    /// `(0, segment_count)`.
+    pub fn domain(&self) -> (f32, f32) {
+        (0.0, self.segment_count() as f32)
+    }
+
+    /// Evaluates the spline.
+    ///
+    /// # Parameters
+    /// - `t`: The spline parameter; values outside the domain extrapolate the end segments.
+    ///
+    /// # Returns
+    /// The point at `t`.
+    pub fn evaluate(&self, t: f32) -> T {
+        let (index, local) = locate_segment(t, self.segment_count());
+        self.segment(index).evaluate(local)
+    }
+
+    /// Evaluates the first derivative with respect to the spline parameter.
+    ///
+    /// # Parameters
+    /// - `t`: The spline parameter.
+    ///
+    /// # Returns
+    /// The tangent vector at `t`.
+    pub fn derivative(&self, t: f32) -> T {
+        let (index, local) = locate_segment(t, self.segment_count());
+        self.segment(index).derivative(local)
+    }
+}
+
+impl<T: CurvePoint> Curve for HermiteSpline<T> {
+    type Point = T;
+
+    fn domain(&self) -> (f32, f32) {
+        HermiteSpline::domain(self)
+    }
+
+    fn evaluate(&self, t: f32) -> Self::Point {
+        HermiteSpline::evaluate(self, t)
+    }
+
+    fn derivative(&self, t: f32) -> Self::Point {
+        HermiteSpline::derivative(self, t)
+    }
+
+    fn second_derivative(&self, t: f32) -> Self::Point {
+        let (index, local) = locate_segment(t, self.segment_count());
+        self.segment(index).second_derivative(local)
+    }
+}
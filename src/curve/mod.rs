@@ -0,0 +1,248 @@
+use crate::{
+    animation::VectorSpace,
+    curve::{arc_length::ArcLength, frame::Frame},
+    vector::{vec2::Vec2, vec3::Vec3, vec4::Vec4},
+};
+
+pub mod arc_length;
+pub mod b_spline;
+pub mod bezier;
+pub mod catmull_rom;
+pub mod frame;
+pub mod hermite;
+pub mod nurbs;
+
+/// A point that curves can be built from.
+///
+/// Implemented for [`Vec2`], [`Vec3`] and [`Vec4`]; the latter holds homogeneous points.
+pub trait CurvePoint: VectorSpace {
+    /// The number of components.
+    const DIMENSION: usize;
+
+    /// Returns one component.
+    ///
+    /// # Parameters
+    /// - `axis`: The index of the component.
+    ///
+    /// # Returns
+    /// The component as a `f32`.
+    ///
+    /// # Panics
+    /// Panics if `axis` is not below [`CurvePoint::DIMENSION`].
+    fn axis(&self, axis: usize) -> f32;
+
+    /// Computes the component-wise minimum.
+    ///
+    /// # Parameters
+    /// - `other`: The other point.
+    ///
+    /// # Returns
+    /// A new point holding the smaller of each pair of components.
+    fn min(&self, other: &Self) -> Self;
+
+    /// Computes the component-wise maximum.
+    ///
+    /// # Parameters
+    /// - `other`: The other point.
+    ///
+    /// # Returns
+    /// A new point holding the larger of each pair of components.
+    fn max(&self, other: &Self) -> Self;
+
+    /// Embeds the point in 3D space.
+    ///
+    /// # Returns
+    /// The point as a [`Vec3`]; 2D points get `z = 0` and 4D points drop `w`.
+    fn to_vec3(&self) -> Vec3;
+
+    /// Computes the distance to the closest point of a line segment.
+    ///
+    /// # Parameters
+    /// - `start`: The start of the segment.
+    /// - `end`: The end of the segment.
+    ///
+    /// # Returns
+    /// The distance as a `f32`.
+    fn distance_to_segment(&self, start: &Self, end: &Self) -> f32 {
+        let direction = *end - *start;
+        let length_squared = direction.dot(&direction);
+        let offset = *self - *start;
+        if length_squared <= f32::EPSILON {
+            return offset.magnitude();
+        }
+        let t = (offset.dot(&direction) / length_squared).clamp(0.0, 1.0);
+        (offset - direction * t).magnitude()
+    }
+}
+
+macro_rules! curve_point {
+    ($vec:ident, $m:expr, $point:ident => $to_vec3:expr) => {
+        impl CurvePoint for $vec {
+            const DIMENSION: usize = $m;
+
+            fn axis(&self, axis: usize) -> f32 {
+                self.as_array()[axis]
+            }
+
+            fn min(&self, other: &Self) -> Self {
+                $vec::min(self, other)
+            }
+
+            fn max(&self, other: &Self) -> Self {
+                $vec::max(self, other)
+            }
+
+            fn to_vec3(&self) -> Vec3 {
+                let $point = self;
+                $to_vec3
+            }
+        }
+    };
+}
+
+curve_point!(Vec2, 2, point => point.xyz(0.0));
+curve_point!(Vec3, 3, point => *point);
+curve_point!(Vec4, 4, point => point.xyz());
+
+/// A parametric curve.
+///
+/// Implemented for every curve in this module. The arc length and frame methods are provided on
+/// top of [`Curve::evaluate`] and [`Curve::derivative`].
+///
+/// # Example
+/// ```
+/// use vectorama::{Vec2, curve::{Curve, bezier::QuadraticBezierCurve}};
+///
+/// let curve = QuadraticBezierCurve::new([Vec2::ZERO, Vec2::new(1.0, 0.0), Vec2::new(2.0, 0.0)]);
+/// assert!((curve.length() - 2.0).abs() < 1e-5);
+/// ```
+pub trait Curve {
+    /// The type of points on the curve.
+    type Point: CurvePoint;
+
+    /// Returns the parameter range spanned by the curve.
+    ///
+    /// # Returns
+    /// The first and last parameter values.
+    fn domain(&self) -> (f32, f32);
+
+    /// Evaluates the curve.
+    ///
+    /// # Parameters
+    /// - `t`: The curve parameter.
+    ///
+    /// # Returns
+    /// The point at `t`.
+    fn evaluate(&self, t: f32) -> Self::Point;
+
+    /// Evaluates the first derivative with respect to the curve parameter.
+    ///
+    /// # Parameters
+    /// - `t`: The curve parameter.
+    ///
+    /// # Returns
+    /// The tangent vector at `t`.
+    fn derivative(&self, t: f32) -> Self::Point;
+
+    /// Evaluates the second derivative with respect to the curve parameter.
+    ///
+    /// The default implementation differentiates [`Curve::derivative`] numerically within the
+    /// domain.
+    ///
+    /// # Parameters
+    /// - `t`: The curve parameter.
+    ///
+    /// # Returns
+    /// The second derivative at `t`.
+    fn second_derivative(&self, t: f32) -> Self::Point {
+        let (start, end) = self.domain();
+        let step = 1e-3 * (end - start);
+        let before = (t - step).max(start);
+        let after = (t + step).min(end);
+        if after <= before {
+            return Self::Point::default();
+        }
+        (self.derivative(after) - self.derivative(before)) * (1.0 / (after - before))
+    }
+
+    /// Computes the length of the whole curve.
+    ///
+    /// # Returns
+    /// The arc length over the domain.
+    fn length(&self) -> f32 {
+        let (start, end) = self.domain();
+        self.length_between(start, end)
+    }
+
+    /// Computes the length of part of the curve with adaptive Gauss–Legendre quadrature.
+    ///
+    /// # Parameters
+    /// - `start`: The parameter to measure from.
+    /// - `end`: The parameter to measure to.
+    ///
+    /// # Returns
+    /// The arc length between the parameters, negative if `end` is before `start`.
+    fn length_between(&self, start: f32, end: f32) -> f32 {
+        arc_length::integrate(|t| self.derivative(t).magnitude(), start, end)
+    }
+
+    /// Builds a table for moving along the curve by arc length.
+    ///
+    /// # Parameters
+    /// - `segments`: The number of parameter intervals the table measures; more intervals make
+    ///   inverse lookups faster. At least one is used.
+    ///
+    /// # Returns
+    /// The arc length table, borrowing the curve.
+    fn arc_length(&self, segments: usize) -> ArcLength<'_, Self>
+    where
+        Self: Sized,
+    {
+        ArcLength::new(self, segments)
+    }
+
+    /// Computes the Frenet frame, whose normal points towards the centre of curvature.
+    ///
+    /// # Parameters
+    /// - `t`: The curve parameter.
+    ///
+    /// # Returns
+    /// `Some(frame)`, or `None` where the curve is stationary or straight and the normal is
+    /// undefined.
+    fn frenet_frame(&self, t: f32) -> Option<Frame> {
+        frame::frenet(
+            self.evaluate(t).to_vec3(),
+            self.derivative(t).to_vec3(),
+            self.second_derivative(t).to_vec3(),
+        )
+    }
+
+    /// Computes rotation-minimizing frames with the double reflection method.
+    ///
+    /// Unlike Frenet frames, these frames exist along straight parts and do not flip at
+    /// inflection points, which makes them suitable for sweeping tubes and orienting objects.
+    ///
+    /// # Parameters
+    /// - `parameters`: The increasing curve parameters to place frames at; closer parameters
+    ///   give less twist.
+    /// - `initial_normal`: The approximate normal of the first frame; it is made perpendicular
+    ///   to the tangent, and an arbitrary normal is used if it is parallel to the tangent.
+    ///
+    /// # Returns
+    /// One frame per parameter.
+    fn rotation_minimizing_frames(&self, parameters: &[f32], initial_normal: Vec3) -> Vec<Frame> {
+        let samples: Vec<(Vec3, Vec3)> = parameters
+            .iter()
+            .map(|&t| (self.evaluate(t).to_vec3(), self.derivative(t).to_vec3()))
+            .collect();
+        frame::rotation_minimizing(&samples, initial_normal)
+    }
+}
+
+/// Splits a spline parameter into a segment index and the parameter within that segment.
+///
+/// Parameters before the first or after the last segment extrapolate that segment.
+pub(crate) fn locate_segment(t: f32, segment_count: usize) -> (usize, f32) {
+    let index = (t.floor().max(0.0) as usize).min(segment_count - 1);
+    (index, t - index as f32)
+}
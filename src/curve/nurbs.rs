@@ -0,0 +1,157 @@
+//! Non-uniform rational B-splines (NURBS).
+//!
+//! A NURBS curve is a [`BSpline`] over homogeneous [`Vec4`] control points `(w x, w y, w z, w)`
+//! that is projected back to 3D after evaluation. The weights pull the curve towards or push it
+//! away from their control points, which lets NURBS represent conic sections such as circles
+//! exactly. Planar curves use `z = 0`.
+//!
+//! # Example
+//! ```
+//! use vectorama::{Vec3, curve::nurbs::Nurbs};
+//!
+//! // A quarter of the unit circle.
+//! let points = [Vec3::new(1.0, 0.0, 0.0), Vec3::new(1.0, 1.0, 0.0), Vec3::new(0.0, 1.0, 0.0)];
+//! let arc = Nurbs::clamped(2, &points, &[1.0, 0.5f32.sqrt(), 1.0]).unwrap();
+//! assert!((arc.evaluate(0.3).magnitude() - 1.0).abs() < 1e-5);
+//! ```
+
+use crate::{
+    curve::{Curve, b_spline::BSpline},
+    vector::{vec3::Vec3, vec4::Vec4},
+};
+
+/// A rational B-spline curve in 3D.
+#[derive(Debug, Clone)]
+pub struct Nurbs {
+    spline: BSpline<Vec4>,
+}
+
+impl Nurbs {
+    /// Creates a NURBS curve from weighted control points and an explicit knot vector.
+    ///
+    /// # Parameters
+    /// - `degree`: The polynomial degree of each segment, at least 1.
+    /// - `points`: The control points; more than `degree` of them.
+    /// - `weights`: The positive weight of each control point.
+    /// - `knots`: The non-decreasing knot vector, with `points.len() + degree + 1` entries.
+    ///
+    /// # Returns
+    /// `Some(curve)`, or `None` if the lengths differ, a weight is not positive, or the inputs do
+    /// not describe a valid B-spline.
+    pub fn new(degree: usize, points: &[Vec3], weights: &[f32], knots: Vec<f32>) -> Option<Self> {
+        Self::from_homogeneous(BSpline::new(degree, homogeneous(points, weights)?, knots)?)
+    }
+
+    /// Creates a NURBS curve with clamped uniform knots that starts and ends on its end control
+    /// points.
+    ///
+    /// The domain is [0, 1].
+    ///
+    /// # Parameters
+    /// - `degree`: The polynomial degree of each segment, at least 1.
+    /// - `points`: The control points; more than `degree` of them.
+    /// - `weights`: The positive weight of each control point.
+    ///
+    /// # Returns
+    /// `Some(curve)`, or `None` if the lengths differ, a weight is not positive, or there are too
+    /// few points.
+    pub fn clamped(degree: usize, points: &[Vec3], weights: &[f32]) -> Option<Self> {
+        Self::from_homogeneous(BSpline::clamped(degree, homogeneous(points, weights)?)?)
+    }
+
+    /// Creates a NURBS curve from a B-spline over homogeneous control points.
+    ///
+    /// # Parameters
+    /// - `spline`: The B-spline over `(w x, w y, w z, w)` control points.
+    ///
+    /// # Returns
+    /// `Some(curve)`, or `None` if a weight is not positive.
+    pub fn from_homogeneous(spline: BSpline<Vec4>) -> Option<Self> {
+        if spline.points().iter().any(|point| point.w <= 0.0) {
+            return None;
+        }
+        Some(Self { spline })
+    }
+
+    /// Returns the underlying B-spline over homogeneous control points.
+    ///
+    /// # Returns
+    /// A reference to the B-spline.
+    pub fn homogeneous(&self) -> &BSpline<Vec4> {
+        &self.spline
+    }
+
+    /// Returns the polynomial degree of each segment.
+    ///
+    /// # Returns
+    /// The degree.
+    pub fn degree(&self) -> usize {
+        self.spline.degree()
+    }
+
+    /// Returns the parameter range spanned by the curve.
+    ///
+    /// # Returns
+    /// The first and last parameter values.
+    pub fn domain(&self) -> (f32, f32) {
+        self.spline.domain()
+    }
+
+    /// Evaluates the curve.
+    ///
+    /// # Parameters
+    /// - `u`: The curve parameter, clamped to the domain.
+    ///
+    /// # Returns
+    /// The point at `u`.
+    pub fn evaluate(&self, u: f32) -> Vec3 {
+        let point = self.spline.evaluate(u);
+        Vec3::new(point.x, point.y, point.z) * (1.0 / point.w)
+    }
+
+    /// Evaluates the first derivative with respect to the curve parameter.
+    ///
+    /// # Parameters
+    /// - `u`: The curve parameter, clamped to the domain.
+    ///
+    /// # Returns
+    /// The tangent vector at `u`.
+    pub fn derivative(&self, u: f32) -> Vec3 {
+        // Quotient rule on the projection: C' = (A' - w' C) / w.
+        let point = self.evaluate(u);
+        let weight = self.spline.evaluate(u).w;
+        let derivative = self.spline.derivative(u);
+        let numerator = Vec3::new(derivative.x, derivative.y, derivative.z);
+        (numerator - point * derivative.w) * (1.0 / weight)
+    }
+}
+
+impl Curve for Nurbs {
+    type Point = Vec3;
+
+    fn domain(&self) -> (f32, f32) {
+        Nurbs::domain(self)
+    }
+
+    fn evaluate(&self, t: f32) -> Self::Point {
+        Nurbs::evaluate(self, t)
+    }
+
+    fn derivative(&self, t: f32) -> Self::Point {
+        Nurbs::derivative(self, t)
+    }
+}
+
+/// Lifts weighted points to homogeneous coordinates, or returns `None` if the input is invalid.
+fn homogeneous(points: &[Vec3], weights: &[f32]) -> Option<Vec<Vec4>> {
+    if points.len() != weights.len() || weights.iter().any(|&weight| weight <= 0.0) {
+        return None;
+    }
+    Some(
+        points
+            .iter()
+            .zip(weights)
+            .map(|(point, &weight)| (*point * weight).xyzw(weight))
+            .collect(),
+    )
+}
@@ -0,0 +1,54 @@
+use std::ops::{Add, AddAssign};
+
+use crate::dual_quaternion::DualQuaternion;
+
+// DualQuaternion ---------------------------------------------------------------------------------
+impl Add<&DualQuaternion> for &DualQuaternion {
+    type Output = DualQuaternion;
+
+    fn add(self, rhs: &DualQuaternion) -> Self::Output {
+        DualQuaternion {
+            real: self.real + rhs.real,
+            dual: self.dual + rhs.dual,
+        }
+    }
+}
+
+#[allow(clippy::op_ref)]
+impl Add<DualQuaternion> for &DualQuaternion {
+    type Output = DualQuaternion;
+
+    fn add(self, rhs: DualQuaternion) -> Self::Output {
+        self + &rhs
+    }
+}
+
+#[allow(clippy::op_ref)]
+impl Add<&DualQuaternion> for DualQuaternion {
+    type Output = DualQuaternion;
+
+    fn add(self, rhs: &DualQuaternion) -> Self::Output {
+        &self + rhs
+    }
+}
+
+impl Add<DualQuaternion> for DualQuaternion {
+    type Output = DualQuaternion;
+
+    fn add(self, rhs: DualQuaternion) -> Self::Output {
+        &self + &rhs
+    }
+}
+
+impl AddAssign<&DualQuaternion> for DualQuaternion {
+    fn add_assign(&mut self, rhs: &DualQuaternion) {
+        *self = *self + rhs;
+    }
+}
+
+impl AddAssign<DualQuaternion> for DualQuaternion {
+    fn add_assign(&mut self, rhs: DualQuaternion) {
+        *self = *self + rhs;
+    }
+}
+// DualQuaternion ---------------------------------------------------------------------------------
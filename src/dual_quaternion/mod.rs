@@ -0,0 +1,109 @@
+use crate::quaternion::Quaternion;
+
+pub mod add;
+pub mod mul;
+pub mod neg;
+pub mod sub;
+pub mod unit;
+
+/// A dual quaternion `real + ε dual`, where `ε² = 0`.
+///
+/// Dual quaternions of unit length encode rigid transforms (a rotation followed by a
+/// translation) and compose by multiplication, like matrices. This type does **not** guarantee
+/// unit length; sums of dual quaternions, as used for blending, generally are not. For a
+/// guaranteed rigid transform, use [`UnitDualQuaternion`](unit::UnitDualQuaternion).
+///
+/// # Example
+/// ```
+/// use vectorama::{DualQuaternion, Quaternion};
+/// let dq = DualQuaternion::new(Quaternion::identity(), Quaternion::ZERO);
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct DualQuaternion {
+    pub real: Quaternion,
+    pub dual: Quaternion,
+}
+
+impl Default for DualQuaternion {
+    fn default() -> Self {
+        Self::identity()
+    }
+}
+
+impl DualQuaternion {
+    /// The identity dual quaternion (no rotation or translation).
+    pub const IDENTITY: Self = Self::identity();
+
+    /// The zero dual quaternion.
+    pub const ZERO: Self = Self::new(Quaternion::ZERO, Quaternion::ZERO);
+
+    /// Creates a new dual quaternion from its real and dual parts.
+    ///
+    /// # Parameters
+    /// - `real`: The real part.
+    /// - `dual`: The dual part, multiplied by `ε`.
+    ///
+    /// # Returns
+    /// A new dual quaternion with the specified parts.
+    pub const fn new(real: Quaternion, dual: Quaternion) -> Self {
+        Self { real, dual }
+    }
+
+    /// Returns the identity dual quaternion (no rotation or translation).
+    ///
+    /// # Returns
+    /// The identity dual quaternion.
+    pub const fn identity() -> Self {
+        Self::new(Quaternion::IDENTITY, Quaternion::ZERO)
+    }
+
+    /// Returns the quaternion conjugate, which conjugates both parts.
+    ///
+    /// # Returns
+    /// `real* + ε dual*`. For a unit dual quaternion this is the inverse transform.
+    pub fn conjugate(&self) -> Self {
+        Self::new(self.real.conjugate(), self.dual.conjugate())
+    }
+
+    /// Returns the dual conjugate, which negates the dual part.
+    ///
+    /// # Returns
+    /// `real - ε dual`.
+    pub fn dual_conjugate(&self) -> Self {
+        Self::new(self.real, -self.dual)
+    }
+
+    /// Returns the combined conjugate, applying both the quaternion and the dual conjugate.
+    ///
+    /// # Returns
+    /// `real* - ε dual*`.
+    pub fn combined_conjugate(&self) -> Self {
+        Self::new(self.real.conjugate(), -self.dual.conjugate())
+    }
+
+    /// Computes the magnitude of the real part, which is the scale a unit dual quaternion
+    /// would be divided by when normalizing.
+    ///
+    /// # Returns
+    /// The magnitude as a `f32`.
+    pub fn magnitude(&self) -> f32 {
+        self.real.magnitude()
+    }
+
+    /// Returns the normalized dual quaternion.
+    ///
+    /// Both parts are divided by the magnitude of the real part, then the component of the dual
+    /// part along the real part is removed so that the result is a valid rigid transform.
+    ///
+    /// # Returns
+    /// The normalized dual quaternion, or the identity if the real part is too small.
+    pub fn normalize(&self) -> Self {
+        let magnitude = self.magnitude();
+        if magnitude < f32::EPSILON {
+            return Self::identity();
+        }
+        let real = self.real / magnitude;
+        let dual = self.dual / magnitude;
+        Self::new(real, dual - real * real.dot(&dual))
+    }
+}
@@ -0,0 +1,185 @@
+use std::ops::{Mul, MulAssign};
+
+use crate::{
+    dual_quaternion::{DualQuaternion, unit::UnitDualQuaternion},
+    vector::vec3::Vec3,
+};
+
+// DualQuaternion ---------------------------------------------------------------------------------
+impl Mul<&DualQuaternion> for &DualQuaternion {
+    type Output = DualQuaternion;
+
+    fn mul(self, rhs: &DualQuaternion) -> Self::Output {
+        DualQuaternion {
+            real: self.real * rhs.real,
+            dual: self.real * rhs.dual + self.dual * rhs.real,
+        }
+    }
+}
+
+#[allow(clippy::op_ref)]
+impl Mul<&DualQuaternion> for DualQuaternion {
+    type Output = DualQuaternion;
+
+    fn mul(self, rhs: &DualQuaternion) -> Self::Output {
+        &self * rhs
+    }
+}
+
+#[allow(clippy::op_ref)]
+impl Mul<DualQuaternion> for &DualQuaternion {
+    type Output = DualQuaternion;
+
+    fn mul(self, rhs: DualQuaternion) -> Self::Output {
+        self * &rhs
+    }
+}
+
+impl Mul<DualQuaternion> for DualQuaternion {
+    type Output = DualQuaternion;
+
+    fn mul(self, rhs: DualQuaternion) -> Self::Output {
+        &self * &rhs
+    }
+}
+
+impl MulAssign<&DualQuaternion> for DualQuaternion {
+    fn mul_assign(&mut self, rhs: &DualQuaternion) {
+        *self = *self * rhs;
+    }
+}
+
+impl MulAssign<DualQuaternion> for DualQuaternion {
+    fn mul_assign(&mut self, rhs: DualQuaternion) {
+        *self *= &rhs;
+    }
+}
+// DualQuaternion ---------------------------------------------------------------------------------
+
+// Scalar -----------------------------------------------------------------------------------------
+impl Mul<f32> for &DualQuaternion {
+    type Output = DualQuaternion;
+
+    fn mul(self, rhs: f32) -> Self::Output {
+        DualQuaternion {
+            real: self.real * rhs,
+            dual: self.dual * rhs,
+        }
+    }
+}
+
+#[allow(clippy::op_ref)]
+impl Mul<f32> for DualQuaternion {
+    type Output = DualQuaternion;
+
+    fn mul(self, rhs: f32) -> Self::Output {
+        &self * rhs
+    }
+}
+
+impl Mul<&DualQuaternion> for f32 {
+    type Output = DualQuaternion;
+
+    fn mul(self, rhs: &DualQuaternion) -> Self::Output {
+        rhs * self
+    }
+}
+
+impl Mul<DualQuaternion> for f32 {
+    type Output = DualQuaternion;
+
+    fn mul(self, rhs: DualQuaternion) -> Self::Output {
+        rhs * self
+    }
+}
+
+impl MulAssign<f32> for DualQuaternion {
+    fn mul_assign(&mut self, rhs: f32) {
+        *self = *self * rhs;
+    }
+}
+// Scalar -----------------------------------------------------------------------------------------
+
+// UnitDualQuaternion -----------------------------------------------------------------------------
+impl Mul<&UnitDualQuaternion> for &UnitDualQuaternion {
+    type Output = UnitDualQuaternion;
+
+    fn mul(self, rhs: &UnitDualQuaternion) -> Self::Output {
+        (**self * **rhs).into()
+    }
+}
+
+#[allow(clippy::op_ref)]
+impl Mul<&UnitDualQuaternion> for UnitDualQuaternion {
+    type Output = UnitDualQuaternion;
+
+    fn mul(self, rhs: &UnitDualQuaternion) -> Self::Output {
+        &self * rhs
+    }
+}
+
+#[allow(clippy::op_ref)]
+impl Mul<UnitDualQuaternion> for &UnitDualQuaternion {
+    type Output = UnitDualQuaternion;
+
+    fn mul(self, rhs: UnitDualQuaternion) -> Self::Output {
+        self * &rhs
+    }
+}
+
+impl Mul<UnitDualQuaternion> for UnitDualQuaternion {
+    type Output = UnitDualQuaternion;
+
+    fn mul(self, rhs: UnitDualQuaternion) -> Self::Output {
+        &self * &rhs
+    }
+}
+
+impl MulAssign<&UnitDualQuaternion> for UnitDualQuaternion {
+    fn mul_assign(&mut self, rhs: &UnitDualQuaternion) {
+        *self = *self * rhs;
+    }
+}
+
+impl MulAssign<UnitDualQuaternion> for UnitDualQuaternion {
+    fn mul_assign(&mut self, rhs: UnitDualQuaternion) {
+        *self *= &rhs;
+    }
+}
+// UnitDualQuaternion -----------------------------------------------------------------------------
+
+// Vector -----------------------------------------------------------------------------------------
+impl Mul<&Vec3> for &UnitDualQuaternion {
+    type Output = Vec3;
+
+    fn mul(self, point: &Vec3) -> Self::Output {
+        UnitDualQuaternion::transform_point(self, point)
+    }
+}
+
+#[allow(clippy::op_ref)]
+impl Mul<Vec3> for &UnitDualQuaternion {
+    type Output = Vec3;
+
+    fn mul(self, point: Vec3) -> Self::Output {
+        self * &point
+    }
+}
+
+#[allow(clippy::op_ref)]
+impl Mul<&Vec3> for UnitDualQuaternion {
+    type Output = Vec3;
+
+    fn mul(self, point: &Vec3) -> Self::Output {
+        &self * point
+    }
+}
+
+impl Mul<Vec3> for UnitDualQuaternion {
+    type Output = Vec3;
+
+    fn mul(self, point: Vec3) -> Self::Output {
+        &self * &point
+    }
+}
+// Vector -----------------------------------------------------------------------------------------
@@ -0,0 +1,24 @@
+use std::ops::Neg;
+
+use crate::dual_quaternion::DualQuaternion;
+
+// DualQuaternion ---------------------------------------------------------------------------------
+impl Neg for &DualQuaternion {
+    type Output = DualQuaternion;
+
+    fn neg(self) -> Self::Output {
+        DualQuaternion {
+            real: -self.real,
+            dual: -self.dual,
+        }
+    }
+}
+
+impl Neg for DualQuaternion {
+    type Output = DualQuaternion;
+
+    fn neg(self) -> Self::Output {
+        -&self
+    }
+}
+// DualQuaternion ---------------------------------------------------------------------------------
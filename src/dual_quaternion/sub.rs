@@ -0,0 +1,54 @@
+use std::ops::{Sub, SubAssign};
+
+use crate::dual_quaternion::DualQuaternion;
+
+// DualQuaternion ---------------------------------------------------------------------------------
+impl Sub<&DualQuaternion> for &DualQuaternion {
+    type Output = DualQuaternion;
+
+    fn sub(self, rhs: &DualQuaternion) -> Self::Output {
+        DualQuaternion {
+            real: self.real - rhs.real,
+            dual: self.dual - rhs.dual,
+        }
+    }
+}
+
+#[allow(clippy::op_ref)]
+impl Sub<DualQuaternion> for &DualQuaternion {
+    type Output = DualQuaternion;
+
+    fn sub(self, rhs: DualQuaternion) -> Self::Output {
+        self - &rhs
+    }
+}
+
+#[allow(clippy::op_ref)]
+impl Sub<&DualQuaternion> for DualQuaternion {
+    type Output = DualQuaternion;
+
+    fn sub(self, rhs: &DualQuaternion) -> Self::Output {
+        &self - rhs
+    }
+}
+
+impl Sub<DualQuaternion> for DualQuaternion {
+    type Output = DualQuaternion;
+
+    fn sub(self, rhs: DualQuaternion) -> Self::Output {
+        &self - &rhs
+    }
+}
+
+impl SubAssign<&DualQuaternion> for DualQuaternion {
+    fn sub_assign(&mut self, rhs: &DualQuaternion) {
+        *self = *self - rhs;
+    }
+}
+
+impl SubAssign<DualQuaternion> for DualQuaternion {
+    fn sub_assign(&mut self, rhs: DualQuaternion) {
+        *self = *self - rhs;
+    }
+}
+// DualQuaternion ---------------------------------------------------------------------------------
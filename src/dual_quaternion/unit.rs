@@ -0,0 +1,341 @@
+use std::ops::Deref;
+
+use crate::{
+    dual_quaternion::DualQuaternion,
+    matrix::Matrix,
+    quaternion::{Quaternion, unit::UnitQuaternion},
+    transform::Transform3,
+    translation::translation3::Translation3,
+    vector::vec3::Vec3,
+};
+
+/// A dual quaternion that is guaranteed to be normalized, representing a rigid transform.
+///
+/// Applied to a point `p`, a `UnitDualQuaternion` computes `rotation * p + translation`, like
+/// [`Matrix::from_rotation_translation`].
+/// Blending rigid transforms as dual quaternions keeps them rigid, which avoids the volume loss
+/// ("candy-wrapper" artefacts) of blending skinning matrices linearly.
+///
+/// # Example
+/// ```
+/// use vectorama::{Translation3, UnitDualQuaternion, UnitQuaternion, Vec3};
+/// let rotation = UnitQuaternion::from_z_axis(std::f32::consts::FRAC_PI_2);
+/// let translation = Translation3::new(0.0, 0.0, 1.0);
+/// let dq = UnitDualQuaternion::from_rotation_translation(rotation, translation);
+/// let p = dq.transform_point(&Vec3::X);
+/// assert!(p.x.abs() < 1e-6 && (p.y - 1.0).abs() < 1e-6 && (p.z - 1.0).abs() < 1e-6);
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct UnitDualQuaternion {
+    dual_quat: DualQuaternion,
+}
+
+impl Default for UnitDualQuaternion {
+    fn default() -> Self {
+        Self::identity()
+    }
+}
+
+impl UnitDualQuaternion {
+    /// The identity unit dual quaternion (no rotation or translation).
+    pub const IDENTITY: Self = Self::identity();
+
+    /// Returns the identity unit dual quaternion (no rotation or translation).
+    ///
+    /// # Returns
+    /// The identity unit dual quaternion.
+    pub const fn identity() -> Self {
+        Self {
+            dual_quat: DualQuaternion::IDENTITY,
+        }
+    }
+
+    /// Creates a rigid transform that rotates, then translates.
+    ///
+    /// # Parameters
+    /// - `rotation`: The rotation.
+    /// - `translation`: The translation applied after the rotation.
+    ///
+    /// # Returns
+    /// A new `UnitDualQuaternion`.
+    pub fn from_rotation_translation(rotation: UnitQuaternion, translation: Translation3) -> Self {
+        let real = Quaternion::from(rotation);
+        let dual = Quaternion::new(*translation, 0.0) * real * 0.5;
+        Self {
+            dual_quat: DualQuaternion::new(real, dual),
+        }
+    }
+
+    /// Creates a pure rotation.
+    ///
+    /// # Parameters
+    /// - `rotation`: The rotation.
+    ///
+    /// # Returns
+    /// A new `UnitDualQuaternion`.
+    pub fn from_rotation(rotation: UnitQuaternion) -> Self {
+        Self::from_rotation_translation(rotation, Translation3::IDENTITY)
+    }
+
+    /// Creates a pure translation.
+    ///
+    /// # Parameters
+    /// - `translation`: The translation.
+    ///
+    /// # Returns
+    /// A new `UnitDualQuaternion`.
+    pub fn from_translation(translation: Translation3) -> Self {
+        Self::from_rotation_translation(UnitQuaternion::IDENTITY, translation)
+    }
+
+    /// Returns the rotation part of the transform.
+    ///
+    /// # Returns
+    /// The rotation as a unit quaternion.
+    pub fn rotation(&self) -> UnitQuaternion {
+        self.real.into()
+    }
+
+    /// Returns the translation part of the transform.
+    ///
+    /// # Returns
+    /// The translation applied after the rotation.
+    pub fn translation(&self) -> Translation3 {
+        let translation = self.dual * self.real.conjugate() * 2.0;
+        translation.vector.into()
+    }
+
+    /// Returns the conjugate of the unit dual quaternion.
+    ///
+    /// # Returns
+    /// The quaternion conjugate of both parts, which for unit dual quaternions is the inverse.
+    pub fn conjugate(&self) -> Self {
+        Self {
+            dual_quat: self.dual_quat.conjugate(),
+        }
+    }
+
+    /// Returns the inverse of the unit dual quaternion.
+    ///
+    /// # Returns
+    /// The transform that undoes this one.
+    pub fn inverse(&self) -> Self {
+        self.conjugate()
+    }
+
+    /// Transforms a point, applying both the rotation and the translation.
+    ///
+    /// # Parameters
+    /// - `point`: The point to transform.
+    ///
+    /// # Returns
+    /// The transformed point.
+    pub fn transform_point(&self, point: &Vec3) -> Vec3 {
+        self.transform_vector(point) + *self.translation()
+    }
+
+    /// Transforms a direction vector, applying only the rotation.
+    ///
+    /// # Parameters
+    /// - `vector`: The vector to transform.
+    ///
+    /// # Returns
+    /// The rotated vector.
+    pub fn transform_vector(&self, vector: &Vec3) -> Vec3 {
+        self.rotation().rotate_vector(*vector)
+    }
+
+    /// Transforms a point by the inverse of this transform.
+    ///
+    /// # Parameters
+    /// - `point`: The point to transform.
+    ///
+    /// # Returns
+    /// The transformed point.
+    pub fn inverse_transform_point(&self, point: &Vec3) -> Vec3 {
+        self.inverse().transform_point(point)
+    }
+
+    /// Raises the transform to a power along its screw axis.
+    ///
+    /// Every rigid transform is a rotation around an axis combined with a translation along it
+    /// (a screw motion); the power scales both the angle and the translation by `exponent`.
+    ///
+    /// # Parameters
+    /// - `exponent`: The exponent.
+    ///
+    /// # Returns
+    /// The transform raised to `exponent`.
+    pub fn powf(&self, exponent: f32) -> Self {
+        let (real, dual) = (self.real, self.dual);
+        let sin_half_angle = real.vector.magnitude();
+        if sin_half_angle < 1e-6 {
+            // Pure translation: the screw axis is undefined, but scaling the offset is exact.
+            let translation = *self.translation() * exponent;
+            return Self::from_rotation_translation(
+                self.rotation().powf(exponent),
+                translation.into(),
+            );
+        }
+
+        // Screw parameters: unit axis, half the pitch along it and the moment of the axis line.
+        let half_angle = sin_half_angle.atan2(real.scalar);
+        let axis = real.vector / sin_half_angle;
+        let half_pitch = -dual.scalar / sin_half_angle;
+        let moment = (dual.vector - axis * (half_pitch * real.scalar)) / sin_half_angle;
+
+        let (sin, cos) = (half_angle * exponent).sin_cos();
+        let half_pitch = half_pitch * exponent;
+        let real = Quaternion::new(axis * sin, cos);
+        let dual = Quaternion::new(moment * sin + axis * (half_pitch * cos), -half_pitch * sin);
+        DualQuaternion::new(real, dual).into()
+    }
+
+    /// Performs screw linear interpolation (ScLERP) along the shorter path.
+    ///
+    /// The result moves along the screw motion from `self` to `other` with constant angular and
+    /// linear velocity, so it is the rigid-transform equivalent of `slerp`.
+    ///
+    /// # Parameters
+    /// - `other`: The target transform.
+    /// - `t`: Interpolation factor in [0, 1].
+    ///
+    /// # Returns
+    /// The interpolated transform.
+    pub fn sclerp(&self, other: &Self, t: f32) -> Self {
+        let mut delta = self.inverse() * other;
+        if delta.real.scalar < 0.0 {
+            delta.dual_quat = -delta.dual_quat;
+        }
+        self * delta.powf(t)
+    }
+
+    /// Blends a weighted set of transforms with dual quaternion linear blending (DLB).
+    ///
+    /// Each transform is flipped onto the hemisphere of the first before the weighted sum is
+    /// normalized, so the result is always rigid. This is the blend used by dual quaternion
+    /// skinning.
+    ///
+    /// # Parameters
+    /// - `transforms`: The transforms to blend, such as the bones influencing a vertex.
+    /// - `weights`: One weight per transform.
+    ///
+    /// # Returns
+    /// `Some(blend)`, or `None` if `transforms` is empty or the weighted sum vanishes.
+    ///
+    /// # Panics
+    /// Panics if `transforms` and `weights` have different lengths.
+    pub fn blend(transforms: &[Self], weights: &[f32]) -> Option<Self> {
+        assert_eq!(
+            transforms.len(),
+            weights.len(),
+            "every transform needs exactly one weight"
+        );
+        let reference = transforms.first()?;
+        let sum = transforms.iter().zip(weights).fold(
+            DualQuaternion::ZERO,
+            |sum, (transform, weight)| {
+                let sign = if reference.real.dot(&transform.real) < 0.0 {
+                    -1.0
+                } else {
+                    1.0
+                };
+                sum + **transform * (sign * weight)
+            },
+        );
+        if sum.magnitude() < f32::EPSILON {
+            return None;
+        }
+        Some(sum.into())
+    }
+
+    /// Returns the homogeneous 4x4 matrix of the transform.
+    ///
+    /// # Returns
+    /// A 4x4 homogeneous matrix.
+    pub fn homogeneous_matrix(&self) -> Matrix<4, 4> {
+        Matrix::from_rotation_translation(self.rotation(), self.translation())
+    }
+}
+
+// From -------------------------------------------------------------------------------------------
+impl From<&DualQuaternion> for UnitDualQuaternion {
+    fn from(dual_quat: &DualQuaternion) -> Self {
+        Self {
+            dual_quat: dual_quat.normalize(),
+        }
+    }
+}
+
+impl From<DualQuaternion> for UnitDualQuaternion {
+    fn from(dual_quat: DualQuaternion) -> Self {
+        Self::from(&dual_quat)
+    }
+}
+
+impl From<UnitDualQuaternion> for DualQuaternion {
+    fn from(unit_dual_quat: UnitDualQuaternion) -> Self {
+        unit_dual_quat.dual_quat
+    }
+}
+
+impl From<&UnitDualQuaternion> for DualQuaternion {
+    fn from(unit_dual_quat: &UnitDualQuaternion) -> Self {
+        unit_dual_quat.dual_quat
+    }
+}
+
+impl From<UnitQuaternion> for UnitDualQuaternion {
+    fn from(rotation: UnitQuaternion) -> Self {
+        Self::from_rotation(rotation)
+    }
+}
+
+impl From<Translation3> for UnitDualQuaternion {
+    fn from(translation: Translation3) -> Self {
+        Self::from_translation(translation)
+    }
+}
+
+impl From<&UnitDualQuaternion> for Matrix<4, 4> {
+    fn from(unit_dual_quat: &UnitDualQuaternion) -> Self {
+        unit_dual_quat.homogeneous_matrix()
+    }
+}
+
+impl From<UnitDualQuaternion> for Matrix<4, 4> {
+    fn from(unit_dual_quat: UnitDualQuaternion) -> Self {
+        Self::from(&unit_dual_quat)
+    }
+}
+// From -------------------------------------------------------------------------------------------
+
+// Deref ------------------------------------------------------------------------------------------
+impl Deref for UnitDualQuaternion {
+    type Target = DualQuaternion;
+
+    fn deref(&self) -> &Self::Target {
+        &self.dual_quat
+    }
+}
+// Deref ------------------------------------------------------------------------------------------
+
+// Transform3 -------------------------------------------------------------------------------------
+impl Transform3 for UnitDualQuaternion {
+    fn transform_point(&self, point: &Vec3) -> Vec3 {
+        UnitDualQuaternion::transform_point(self, point)
+    }
+
+    fn transform_vector(&self, vector: &Vec3) -> Vec3 {
+        UnitDualQuaternion::transform_vector(self, vector)
+    }
+
+    fn to_homogeneous(&self) -> Matrix<4, 4> {
+        self.homogeneous_matrix()
+    }
+
+    fn try_inverse(&self) -> Option<Self> {
+        Some(self.inverse())
+    }
+}
+// Transform3 -------------------------------------------------------------------------------------
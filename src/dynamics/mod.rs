@@ -0,0 +1,2 @@
+pub mod ode;
+pub mod rigid_body;
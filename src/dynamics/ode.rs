@@ -0,0 +1,384 @@
+//! Explicit integrators for ordinary differential equations over fixed-size state vectors.
+//!
+//! Every integrator takes a `derivative` closure that evaluates `dy/dt = f(t, y)` for a time and
+//! a state. The fixed-step methods advance the state by a single step; [`Rk45`] adapts its step
+//! size to keep the estimated error within a tolerance.
+//!
+//! # Example
+//! ```
+//! use vectorama::dynamics::ode;
+//! use vectorama::vector::Vector;
+//!
+//! // Exponential decay: dy/dt = -y.
+//! let mut y = Vector::<1>::from([1.0]);
+//! for step in 0..100 {
+//!     y = ode::rk4(|_, y| -*y, step as f32 * 0.01, &y, 0.01);
+//! }
+//! assert!((y[0] - (-1.0f32).exp()).abs() < 1e-5);
+//! ```
+
+use crate::vector::Vector;
+
+/// Advances the state by one explicit (forward) Euler step.
+///
+/// # Parameters
+/// - `derivative`: Evaluates `dy/dt` for a time and a state.
+/// - `t`: The time at the start of the step.
+/// - `state`: The state at the start of the step.
+/// - `dt`: The step size.
+///
+/// # Returns
+/// The state at `t + dt`, accurate to first order.
+pub fn euler<const M: usize, F>(derivative: F, t: f32, state: &Vector<M>, dt: f32) -> Vector<M>
+where
+    F: Fn(f32, &Vector<M>) -> Vector<M>,
+{
+    state + derivative(t, state) * dt
+}
+
+/// Advances the state by one explicit midpoint step.
+///
+/// # Parameters
+/// - `derivative`: Evaluates `dy/dt` for a time and a state.
+/// - `t`: The time at the start of the step.
+/// - `state`: The state at the start of the step.
+/// - `dt`: The step size.
+///
+/// # Returns
+/// The state at `t + dt`, accurate to second order.
+pub fn midpoint<const M: usize, F>(derivative: F, t: f32, state: &Vector<M>, dt: f32) -> Vector<M>
+where
+    F: Fn(f32, &Vector<M>) -> Vector<M>,
+{
+    let half_dt = 0.5 * dt;
+    let midpoint = state + derivative(t, state) * half_dt;
+    state + derivative(t + half_dt, &midpoint) * dt
+}
+
+/// Advances the state by one step of the classic fourth-order Runge-Kutta method.
+///
+/// # Parameters
+/// - `derivative`: Evaluates `dy/dt` for a time and a state.
+/// - `t`: The time at the start of the step.
+/// - `state`: The state at the start of the step.
+/// - `dt`: The step size.
+///
+/// # Returns
+/// The state at `t + dt`, accurate to fourth order.
+pub fn rk4<const M: usize, F>(derivative: F, t: f32, state: &Vector<M>, dt: f32) -> Vector<M>
+where
+    F: Fn(f32, &Vector<M>) -> Vector<M>,
+{
+    let half_dt = 0.5 * dt;
+    let k1 = derivative(t, state);
+    let k2 = derivative(t + half_dt, &(state + k1 * half_dt));
+    let k3 = derivative(t + half_dt, &(state + k2 * half_dt));
+    let k4 = derivative(t + dt, &(state + k3 * dt));
+    state + (k1 + (k2 + k3) * 2.0 + k4) * (dt / 6.0)
+}
+
+/// Adaptive Runge-Kutta integrator using the Dormand-Prince 5(4) pair.
+///
+/// Each step computes a fifth-order solution and a fourth-order one from the same seven
+/// derivative evaluations; their difference estimates the local error. Steps whose error
+/// exceeds the tolerance are retried with a smaller step, and the step size grows again once
+/// the solution is smooth.
+///
+/// # Example
+/// ```
+/// use vectorama::dynamics::ode::Rk45;
+/// use vectorama::vector::Vector;
+///
+/// // Harmonic oscillator: x'' = -x.
+/// let oscillator = |_: f32, y: &Vector<2>| Vector::from([y[1], -y[0]]);
+/// let (y, report) = Rk45::default().integrate(oscillator, 0.0, &Vector::from([1.0, 0.0]), 3.0);
+/// assert!((y[0] - 3.0f32.cos()).abs() < 1e-3);
+/// assert!(report.accepted_steps > 0);
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct Rk45 {
+    /// Error allowed relative to the magnitude of each state component.
+    pub relative_tolerance: f32,
+    /// Error allowed regardless of the magnitude of each state component.
+    pub absolute_tolerance: f32,
+    /// The smallest step size; steps at this size are accepted even if they miss the tolerance.
+    /// A negative or NaN value counts as zero.
+    pub min_step: f32,
+    /// The largest step size. A value below `min_step` counts as `min_step`, and NaN counts as
+    /// no limit.
+    pub max_step: f32,
+}
+
+/// The outcome of a single [`Rk45::step`] attempt.
+#[derive(Debug, Clone, Copy)]
+pub struct Rk45Step<const M: usize> {
+    /// The fifth-order solution at the end of the step.
+    pub state: Vector<M>,
+    /// The estimated local error, scaled so that `1.0` is exactly on the tolerance.
+    pub error: f32,
+    /// Whether the error is within the tolerance.
+    pub accepted: bool,
+    /// The step size suggested for the next attempt.
+    pub next_dt: f32,
+}
+
+/// Statistics gathered by [`Rk45::integrate`].
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Rk45Report {
+    /// The number of steps that met the tolerance.
+    pub accepted_steps: usize,
+    /// The number of attempts that were retried with a smaller step.
+    pub rejected_steps: usize,
+    /// The number of steps accepted at `min_step` although they missed the tolerance.
+    pub forced_steps: usize,
+    /// The number of derivative evaluations.
+    pub evaluations: usize,
+    /// The smallest step size that was accepted.
+    pub min_dt: f32,
+    /// The largest step size that was accepted.
+    pub max_dt: f32,
+    /// The step size suggested for continuing past the end time.
+    pub next_dt: f32,
+    /// The time the integration stopped at; `end` unless it stalled.
+    pub reached: f32,
+    /// Whether the integration stopped early, either because the error of a step at `min_step`
+    /// was not finite or because the step was too small to advance the time.
+    pub stalled: bool,
+}
+
+impl Default for Rk45 {
+    fn default() -> Self {
+        Self::new(1e-4, 1e-6)
+    }
+}
+
+impl Rk45 {
+    /// Factor applied to the optimal step size to make the next attempt likely to succeed.
+    const SAFETY: f32 = 0.9;
+    /// Bounds on how much the step size may change between attempts.
+    const MIN_SCALE: f32 = 0.2;
+    const MAX_SCALE: f32 = 5.0;
+
+    /// Creates an integrator with the given tolerances, a minimum step of `1e-6` and no maximum.
+    ///
+    /// # Parameters
+    /// - `relative_tolerance`: Error allowed relative to the magnitude of each component.
+    /// - `absolute_tolerance`: Error allowed regardless of the magnitude of each component.
+    ///
+    /// # Returns
+    /// A new `Rk45` integrator.
+    pub fn new(relative_tolerance: f32, absolute_tolerance: f32) -> Self {
+        Self {
+            relative_tolerance,
+            absolute_tolerance,
+            min_step: 1e-6,
+            max_step: f32::INFINITY,
+        }
+    }
+
+    /// Attempts a single step of the given size.
+    ///
+    /// # Parameters
+    /// - `derivative`: Evaluates `dy/dt` for a time and a state.
+    /// - `t`: The time at the start of the step.
+    /// - `state`: The state at the start of the step.
+    /// - `dt`: The step size; negative values integrate backwards in time.
+    ///
+    /// # Returns
+    /// The solution at `t + dt` with its error estimate and the suggested next step size. The
+    /// caller should discard the solution and retry with `next_dt` if it was not accepted.
+    pub fn step<const M: usize, F>(
+        &self,
+        derivative: F,
+        t: f32,
+        state: &Vector<M>,
+        dt: f32,
+    ) -> Rk45Step<M>
+    where
+        F: Fn(f32, &Vector<M>) -> Vector<M>,
+    {
+        let k1 = derivative(t, state);
+        let k2 = derivative(t + dt / 5.0, &(state + k1 * (dt / 5.0)));
+        let k3 = derivative(
+            t + dt * 3.0 / 10.0,
+            &(state + (k1 * (3.0 / 40.0) + k2 * (9.0 / 40.0)) * dt),
+        );
+        let k4 = derivative(
+            t + dt * 4.0 / 5.0,
+            &(state + (k1 * (44.0 / 45.0) - k2 * (56.0 / 15.0) + k3 * (32.0 / 9.0)) * dt),
+        );
+        let k5 = derivative(
+            t + dt * 8.0 / 9.0,
+            &(state
+                + (k1 * (19372.0 / 6561.0) - k2 * (25360.0 / 2187.0) + k3 * (64448.0 / 6561.0)
+                    - k4 * (212.0 / 729.0))
+                    * dt),
+        );
+        let k6 = derivative(
+            t + dt,
+            &(state
+                + (k1 * (9017.0 / 3168.0) - k2 * (355.0 / 33.0)
+                    + k3 * (46732.0 / 5247.0)
+                    + k4 * (49.0 / 176.0)
+                    - k5 * (5103.0 / 18656.0))
+                    * dt),
+        );
+        let solution = state
+            + (k1 * (35.0 / 384.0) + k3 * (500.0 / 1113.0) + k4 * (125.0 / 192.0)
+                - k5 * (2187.0 / 6784.0)
+                + k6 * (11.0 / 84.0))
+                * dt;
+        let k7 = derivative(t + dt, &solution);
+
+        // Difference between the fifth- and fourth-order solutions.
+        let difference = (k1 * (71.0 / 57600.0) - k3 * (71.0 / 16695.0) + k4 * (71.0 / 1920.0)
+            - k5 * (17253.0 / 339200.0)
+            + k6 * (22.0 / 525.0)
+            - k7 * (1.0 / 40.0))
+            * dt;
+
+        let mut sum = 0.0;
+        for m in 0..M {
+            let magnitude = state[m].abs().max(solution[m].abs());
+            let scale = self.absolute_tolerance + self.relative_tolerance * magnitude;
+            sum += (difference[m] / scale).powi(2);
+        }
+        let error = (sum / M.max(1) as f32).sqrt();
+
+        // A non-finite error usually means the step left the region where the derivative is
+        // defined, so shrink it as much as allowed.
+        let scale = if !error.is_finite() {
+            Self::MIN_SCALE
+        } else if error > 0.0 {
+            (Self::SAFETY * error.powf(-0.2)).clamp(Self::MIN_SCALE, Self::MAX_SCALE)
+        } else {
+            Self::MAX_SCALE
+        };
+        let next_dt = self.clamp_step((dt * scale).abs()) * dt.signum();
+        Rk45Step {
+            state: solution,
+            error,
+            accepted: error <= 1.0,
+            next_dt,
+        }
+    }
+
+    /// Integrates from `start` to `end`, choosing the step sizes automatically.
+    ///
+    /// The first attempt uses a step of a hundredth of the interval, clamped to the step size
+    /// limits. Use [`Rk45::integrate_with_step`] to continue from a previous `next_dt`.
+    ///
+    /// # Parameters
+    /// - `derivative`: Evaluates `dy/dt` for a time and a state.
+    /// - `start`: The initial time.
+    /// - `state`: The state at `start`.
+    /// - `end`: The final time; it may be earlier than `start`.
+    ///
+    /// # Returns
+    /// A tuple `(state, report)` with the state at `end` and the step statistics. If the
+    /// integration stalls, the state is the last one reached and `report.stalled` is set.
+    pub fn integrate<const M: usize, F>(
+        &self,
+        derivative: F,
+        start: f32,
+        state: &Vector<M>,
+        end: f32,
+    ) -> (Vector<M>, Rk45Report)
+    where
+        F: Fn(f32, &Vector<M>) -> Vector<M>,
+    {
+        self.integrate_with_step(derivative, start, state, end, (end - start) / 100.0)
+    }
+
+    /// Integrates from `start` to `end`, starting with the given step size.
+    ///
+    /// # Parameters
+    /// - `derivative`: Evaluates `dy/dt` for a time and a state.
+    /// - `start`: The initial time.
+    /// - `state`: The state at `start`.
+    /// - `end`: The final time; it may be earlier than `start`.
+    /// - `initial_dt`: The size of the first attempted step; its sign is ignored.
+    ///
+    /// # Returns
+    /// A tuple `(state, report)` with the state at `end` and the step statistics. If the
+    /// integration stalls, the state is the last one reached and `report.stalled` is set.
+    pub fn integrate_with_step<const M: usize, F>(
+        &self,
+        derivative: F,
+        start: f32,
+        state: &Vector<M>,
+        end: f32,
+        initial_dt: f32,
+    ) -> (Vector<M>, Rk45Report)
+    where
+        F: Fn(f32, &Vector<M>) -> Vector<M>,
+    {
+        let direction = if end < start { -1.0 } else { 1.0 };
+        let mut report = Rk45Report {
+            min_dt: f32::INFINITY,
+            next_dt: initial_dt.abs() * direction,
+            ..Default::default()
+        };
+        let mut t = start;
+        let mut state = *state;
+        while (end - t) * direction > 0.0 {
+            let remaining = (end - t).abs();
+            let size = self.clamp_step(report.next_dt.abs());
+            let dt = size.min(remaining) * direction;
+            if t + dt == t {
+                report.stalled = true;
+                break;
+            }
+
+            let step = self.step(&derivative, t, &state, dt);
+            report.evaluations += 7;
+            let at_min_step = dt.abs() <= self.step_limits().0;
+            if at_min_step && !step.error.is_finite() {
+                report.stalled = true;
+                break;
+            }
+            if !step.accepted && !at_min_step {
+                report.rejected_steps += 1;
+                report.next_dt = step.next_dt;
+                continue;
+            }
+
+            if step.accepted {
+                report.accepted_steps += 1;
+            } else {
+                report.forced_steps += 1;
+            }
+            report.min_dt = report.min_dt.min(dt.abs());
+            report.max_dt = report.max_dt.max(dt.abs());
+            // A step cut short to land on `end` says little about the step size to continue with.
+            if dt.abs() >= size {
+                report.next_dt = step.next_dt;
+            }
+            state = step.state;
+            t = if dt.abs() < remaining { t + dt } else { end };
+        }
+        if report.min_dt == f32::INFINITY {
+            report.min_dt = 0.0;
+        }
+        report.reached = t;
+        (state, report)
+    }
+
+    /// Returns the usable `(min_step, max_step)` limits, see the field documentation.
+    fn step_limits(&self) -> (f32, f32) {
+        // `f32::max` returns the other operand when one of them is NaN.
+        let min = self.min_step.max(0.0);
+        let max = if self.max_step.is_nan() {
+            f32::INFINITY
+        } else {
+            self.max_step.max(min)
+        };
+        (min, max)
+    }
+
+    /// Clamps a step size to the step size limits.
+    fn clamp_step(&self, size: f32) -> f32 {
+        let (min, max) = self.step_limits();
+        size.clamp(min, max)
+    }
+}
@@ -1,6 +1,12 @@
+pub mod animation;
+pub mod curve;
+pub mod dual_quaternion;
+pub mod dynamics;
 pub mod matrix;
 pub mod quaternion;
+pub mod rotation;
 pub mod scale;
+pub mod transform;
 pub mod translation;
 pub mod vector;
 
@@ -26,14 +32,38 @@ pub type Mat3 = Matrix<3, 3>;
 pub type Mat4 = Matrix<4, 4>;
 
 pub use vector::Vector;
+pub use vector::bvec::{BVec2, BVec3, BVec4};
+pub use vector::ivec::{IVec2, IVec3, IVec4};
+pub use vector::uvec::{UVec2, UVec3, UVec4};
 pub use vector::vec2::Vec2;
 pub use vector::vec3::Vec3;
 pub use vector::vec4::Vec4;
 
+pub use dual_quaternion::DualQuaternion;
+pub use dual_quaternion::unit::UnitDualQuaternion;
 pub use quaternion::Quaternion;
 pub use quaternion::unit::UnitQuaternion;
+pub use rotation::rotation2::Rotation2;
 
 pub use scale::scale2::Scale2;
 pub use scale::scale3::Scale3;
+pub use transform::affine2::Affine2;
+pub use transform::isometry2::Isometry2;
+pub use transform::similarity2::Similarity2;
+pub use transform::{Transform2, Transform3};
 pub use translation::translation2::Translation2;
 pub use translation::translation3::Translation3;
+
+pub use dynamics::rigid_body::RigidBody;
+
+pub use animation::easing::{CubicBezier, Easing};
+pub use animation::tween::Tween;
+pub use animation::{Animatable, VectorSpace};
+
+pub use curve::b_spline::BSpline;
+pub use curve::bezier::Bezier;
+pub use curve::catmull_rom::CatmullRom;
+pub use curve::frame::Frame;
+pub use curve::hermite::{Hermite, HermiteSpline};
+pub use curve::nurbs::Nurbs;
+pub use curve::{Curve, CurvePoint};
@@ -1,4 +1,7 @@
-use std::ops::{Add, AddAssign};
+use std::{
+    iter::Sum,
+    ops::{Add, AddAssign},
+};
 
 use crate::matrix::Matrix;
 
@@ -59,3 +62,17 @@ impl<const M: usize, const N: usize> AddAssign<Matrix<M, N>> for Matrix<M, N> {
     }
 }
 // Matrix -----------------------------------------------------------------------------------------
+
+// Sum --------------------------------------------------------------------------------------------
+impl<const M: usize, const N: usize> Sum for Matrix<M, N> {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::zeros(), |acc, matrix| acc + matrix)
+    }
+}
+
+impl<'a, const M: usize, const N: usize> Sum<&'a Matrix<M, N>> for Matrix<M, N> {
+    fn sum<I: Iterator<Item = &'a Matrix<M, N>>>(iter: I) -> Self {
+        iter.fold(Self::zeros(), |acc, matrix| acc + matrix)
+    }
+}
+// Sum --------------------------------------------------------------------------------------------
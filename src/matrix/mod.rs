@@ -64,6 +64,40 @@ impl<const M: usize, const N: usize> Matrix<M, N> {
     }
 }
 
+impl<const M: usize, const N: usize> Matrix<M, N> {
+    /// Creates a matrix from an array of columns.
+    ///
+    /// # Parameters
+    /// - `cols`: The columns of the matrix, each holding `M` elements.
+    ///
+    /// # Returns
+    /// A new matrix whose `n`-th column is `cols[n]`.
+    pub const fn from_cols(cols: [[f32; M]; N]) -> Self {
+        Self { data: cols }
+    }
+
+    /// Creates a matrix from an array of rows.
+    ///
+    /// # Parameters
+    /// - `rows`: The rows of the matrix, each holding `N` elements.
+    ///
+    /// # Returns
+    /// A new matrix whose `m`-th row is `rows[m]`.
+    pub const fn from_rows(rows: [[f32; N]; M]) -> Self {
+        let mut data = [[0.0; M]; N];
+        let mut m = 0;
+        while m < M {
+            let mut n = 0;
+            while n < N {
+                data[n][m] = rows[m][n];
+                n += 1;
+            }
+            m += 1;
+        }
+        Self { data }
+    }
+}
+
 impl<const M: usize, const N: usize> From<[[f32; M]; N]> for Matrix<M, N> {
     fn from(data: [[f32; M]; N]) -> Self {
         Self { data }
@@ -86,6 +120,17 @@ impl<const M: usize, const N: usize> AsRef<[[f32; M]; N]> for Matrix<M, N> {
 // AsRef ------------------------------------------------------------------------------------------
 
 impl<const M: usize, const N: usize> Matrix<M, N> {
+    /// A matrix with every element set to zero.
+    pub const ZERO: Self = Self::zeros();
+
+    /// A matrix with every element set to one.
+    pub const ONE: Self = Self::ones();
+
+    /// A matrix with every element set to `f32::NAN`.
+    pub const NAN: Self = Self {
+        data: [[f32::NAN; M]; N],
+    };
+
     /// Creates a matrix filled with zeros.
     ///
     /// # Returns
@@ -110,7 +155,7 @@ impl<const M: usize, const N: usize> Matrix<M, N> {
     ///
     /// # Returns
     /// A reference to the underlying data as a contiguous slice.
-    pub fn as_flattened(&self) -> &[f32] {
+    pub const fn as_flattened(&self) -> &[f32] {
         self.data.as_flattened()
     }
 
@@ -167,7 +212,7 @@ impl<const M: usize, const N: usize> Matrix<M, N> {
     ///
     /// # Panics
     /// Panics if `index` is out of bounds.
-    pub fn column(&self, index: usize) -> [f32; M] {
+    pub const fn column(&self, index: usize) -> [f32; M] {
         assert!(index < N, "Index out of bounds for column access");
         self.data[index]
     }
@@ -176,7 +221,7 @@ impl<const M: usize, const N: usize> Matrix<M, N> {
     ///
     /// # Returns
     /// A pointer to the first element of the matrix data.
-    pub fn as_ptr(&self) -> *const f32 {
+    pub const fn as_ptr(&self) -> *const f32 {
         self.data.as_ptr() as *const f32
     }
 }
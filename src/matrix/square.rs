@@ -1,4 +1,11 @@
-use crate::{matrix::Matrix, vector::vec3::Vec3};
+use crate::{
+    matrix::Matrix,
+    quaternion::unit::UnitQuaternion,
+    scale::scale3::Scale3,
+    transform::{Transform2, Transform3},
+    translation::translation3::Translation3,
+    vector::{vec2::Vec2, vec3::Vec3, vec4::Vec4},
+};
 
 impl<const M: usize> Default for Matrix<M, M> {
     fn default() -> Self {
@@ -7,16 +14,21 @@ impl<const M: usize> Default for Matrix<M, M> {
 }
 
 impl<const M: usize> Matrix<M, M> {
+    /// The identity matrix of size `M x M`.
+    pub const IDENTITY: Self = Self::identity();
+
     /// Returns the identity matrix of size `M x M`.
     ///
     /// # Returns
     /// A matrix with ones on the diagonal and zeros elsewhere.
-    pub fn identity() -> Self {
-        let mut identity = Self::zeros();
-        for m in 0..M {
-            identity[(m, m)] = 1.0;
+    pub const fn identity() -> Self {
+        let mut data = [[0.0; M]; M];
+        let mut m = 0;
+        while m < M {
+            data[m][m] = 1.0;
+            m += 1;
         }
-        identity
+        Self::from_cols(data)
     }
 
     /// Attempts to compute the inverse of the matrix using Gauss-Jordan elimination.
@@ -56,21 +68,22 @@ impl<const M: usize> Matrix<M, M> {
             }
 
             // Normalize pivot row (make pivot element 1.0)
-            for n in d..M * 2 {
-                extended[d][n] /= factor;
+            for value in &mut extended[d][d..] {
+                *value /= factor;
             }
 
             // Eliminate other entries in pivot column
-            for m in 0..M {
+            let pivot_row = extended[d].clone();
+            for (m, row) in extended.iter_mut().enumerate() {
                 if m == d {
                     continue;
                 }
-                let value = extended[m][d];
+                let value = row[d];
                 if value.abs() < f32::EPSILON {
                     continue;
                 }
-                for n in d..M * 2 {
-                    extended[m][n] -= extended[d][n] * value;
+                for (element, pivot) in row[d..].iter_mut().zip(&pivot_row[d..]) {
+                    *element -= pivot * value;
                 }
             }
         }
@@ -108,6 +121,47 @@ impl Matrix<3, 3> {
             - self[(0, 1)] * (self[(1, 0)] * self[(2, 2)] - self[(1, 2)] * self[(2, 0)])
             + self[(0, 2)] * (self[(1, 0)] * self[(2, 1)] - self[(1, 1)] * self[(2, 0)])
     }
+
+    /// Inverts the matrix in closed form, without allocating.
+    ///
+    /// Unlike [`Matrix::try_inverse`], the singularity test is relative: the matrix counts as
+    /// singular when its determinant is negligible compared to the cube of its norm, so uniformly
+    /// scaling the matrix never changes the outcome.
+    pub(crate) fn try_inverse_3x3(&self) -> Option<Self> {
+        let cofactor = self.cofactor_matrix();
+        let determinant = self.invertible_determinant(&cofactor)?;
+        Some(cofactor.transpose() / determinant)
+    }
+
+    /// Computes the cofactor matrix, the transpose of the adjugate.
+    pub(crate) fn cofactor_matrix(&self) -> Self {
+        let mut cofactor = Self::zeros();
+        for n in 0..3 {
+            for m in 0..3 {
+                let (m1, m2) = ((m + 1) % 3, (m + 2) % 3);
+                let (n1, n2) = ((n + 1) % 3, (n + 2) % 3);
+                cofactor[(m, n)] =
+                    self[(m1, n1)] * self[(m2, n2)] - self[(m1, n2)] * self[(m2, n1)];
+            }
+        }
+        cofactor
+    }
+
+    /// Returns the determinant from the cofactor matrix, or `None` if the matrix is singular
+    /// relative to its norm or not finite.
+    fn invertible_determinant(&self, cofactor: &Self) -> Option<f32> {
+        let determinant = (0..3).map(|n| self[(0, n)] * cofactor[(0, n)]).sum::<f32>();
+        let norm = self
+            .as_flattened()
+            .iter()
+            .map(|value| value * value)
+            .sum::<f32>()
+            .sqrt();
+        if !determinant.is_finite() || determinant.abs() <= f32::EPSILON * norm * norm * norm {
+            return None;
+        }
+        Some(determinant)
+    }
 }
 
 impl Matrix<4, 4> {
@@ -225,4 +279,224 @@ impl Matrix<4, 4> {
         ortho[(3, 3)] = 1.0;
         ortho
     }
+
+    /// Creates an affine transformation matrix from a translation.
+    ///
+    /// # Parameters
+    /// - `translation`: The translation vector.
+    ///
+    /// # Returns
+    /// A 4x4 homogeneous translation matrix.
+    pub fn from_translation(translation: impl Into<Vec3>) -> Self {
+        Translation3::from(translation.into()).homogeneous_matrix()
+    }
+
+    /// Creates an affine transformation matrix from a non-uniform scale.
+    ///
+    /// # Parameters
+    /// - `scale`: The scale factor along each axis.
+    ///
+    /// # Returns
+    /// A 4x4 homogeneous scale matrix.
+    pub fn from_scale(scale: impl Into<Vec3>) -> Self {
+        Scale3::from(scale.into()).homogeneous_matrix()
+    }
+
+    /// Creates an affine transformation matrix from a rotation.
+    ///
+    /// # Parameters
+    /// - `rotation`: The rotation.
+    ///
+    /// # Returns
+    /// A 4x4 homogeneous rotation matrix.
+    pub fn from_rotation(rotation: UnitQuaternion) -> Self {
+        rotation.homogeneous_matrix()
+    }
+
+    /// Creates an affine transformation matrix from an axis and an angle.
+    ///
+    /// # Parameters
+    /// - `axis`: The axis of rotation (will be normalized).
+    /// - `angle`: The rotation angle in radians.
+    ///
+    /// # Returns
+    /// A 4x4 homogeneous rotation matrix.
+    pub fn from_axis_angle(axis: impl Into<Vec3>, angle: f32) -> Self {
+        Self::from_rotation(UnitQuaternion::from_axis_angle(axis.into(), angle))
+    }
+
+    /// Creates an affine transformation matrix that rotates, then translates.
+    ///
+    /// # Parameters
+    /// - `rotation`: The rotation.
+    /// - `translation`: The translation applied after the rotation.
+    ///
+    /// # Returns
+    /// A 4x4 homogeneous matrix.
+    pub fn from_rotation_translation(
+        rotation: UnitQuaternion,
+        translation: impl Into<Vec3>,
+    ) -> Self {
+        Self::from_scale_rotation_translation(Vec3::ONE, rotation, translation)
+    }
+
+    /// Creates an affine transformation matrix that scales, then rotates, then translates.
+    ///
+    /// # Parameters
+    /// - `scale`: The scale factor along each axis.
+    /// - `rotation`: The rotation.
+    /// - `translation`: The translation applied last.
+    ///
+    /// # Returns
+    /// A 4x4 homogeneous matrix.
+    pub fn from_scale_rotation_translation(
+        scale: impl Into<Vec3>,
+        rotation: UnitQuaternion,
+        translation: impl Into<Vec3>,
+    ) -> Self {
+        let scale: Vec3 = scale.into();
+        let translation: Vec3 = translation.into();
+        let mut matrix = rotation.homogeneous_matrix();
+        for n in 0..3 {
+            for m in 0..3 {
+                matrix[(m, n)] *= scale.as_array()[n];
+            }
+            matrix[(n, 3)] = translation.as_array()[n];
+        }
+        matrix
+    }
+
+    /// Decomposes an affine transformation matrix into scale, rotation and translation.
+    ///
+    /// The matrix is assumed to be affine, with non-zero scale and no shear. A reflection is
+    /// represented by a negative scale along X.
+    ///
+    /// # Returns
+    /// A tuple `(scale, rotation, translation)`.
+    pub fn to_scale_rotation_translation(&self) -> (Scale3, UnitQuaternion, Translation3) {
+        let linear = self.view::<3, 3>(0, 0);
+        let mut scale = Vec3::new(
+            Vec3::from(linear.column(0)).magnitude(),
+            Vec3::from(linear.column(1)).magnitude(),
+            Vec3::from(linear.column(2)).magnitude(),
+        );
+        if linear.determinant() < 0.0 {
+            scale.x = -scale.x;
+        }
+
+        let mut rotation = linear;
+        for n in 0..3 {
+            for m in 0..3 {
+                rotation[(m, n)] /= scale.as_array()[n];
+            }
+        }
+        let translation = Vec4::from(self.column(3)).xyz();
+        (
+            scale.into(),
+            UnitQuaternion::from_rotation_matrix(&rotation),
+            translation.into(),
+        )
+    }
+
+    /// Returns `true` if the last row is `[0, 0, 0, 1]`, i.e. the matrix has no projective part.
+    pub fn is_affine(&self) -> bool {
+        (0..3).all(|n| self[(3, n)].abs() <= f32::EPSILON)
+            && (self[(3, 3)] - 1.0).abs() <= f32::EPSILON
+    }
+
+    /// Transforms a point, treating it as `(x, y, z, 1)` and ignoring the resulting `w`.
+    ///
+    /// This is the fast path for affine matrices; use [`Matrix::project_point3`] for projections.
+    ///
+    /// # Parameters
+    /// - `point`: The point to transform.
+    ///
+    /// # Returns
+    /// The transformed point.
+    pub fn transform_point3(&self, point: impl Into<Vec3>) -> Vec3 {
+        (self * point.into().xyzw(1.0)).xyz()
+    }
+
+    /// Transforms a point, treating it as `(x, y, z, 1)` and dividing by the resulting `w`.
+    ///
+    /// # Parameters
+    /// - `point`: The point to transform.
+    ///
+    /// # Returns
+    /// The projected point.
+    pub fn project_point3(&self, point: impl Into<Vec3>) -> Vec3 {
+        let result = self * point.into().xyzw(1.0);
+        result.xyz() / result.w
+    }
+
+    /// Transforms a direction vector, treating it as `(x, y, z, 0)` so translation is ignored.
+    ///
+    /// # Parameters
+    /// - `vector`: The vector to transform.
+    ///
+    /// # Returns
+    /// The transformed vector.
+    pub fn transform_vector3(&self, vector: impl Into<Vec3>) -> Vec3 {
+        (self * vector.into().xyzw(0.0)).xyz()
+    }
+
+    /// Computes the matrix that transforms surface normals: the inverse-transpose of the upper
+    /// 3x3 block.
+    ///
+    /// For a singular upper block the cofactor matrix is returned instead, which still maps
+    /// normals to the correct directions up to scale.
+    ///
+    /// # Returns
+    /// A 3x3 normal matrix.
+    pub fn normal_matrix(&self) -> Matrix<3, 3> {
+        let linear = self.view::<3, 3>(0, 0);
+        let cofactor = linear.cofactor_matrix();
+        match linear.invertible_determinant(&cofactor) {
+            Some(determinant) => cofactor / determinant,
+            None => cofactor,
+        }
+    }
+}
+
+// Transform3 -------------------------------------------------------------------------------------
+impl Transform3 for Matrix<4, 4> {
+    /// Transforms a point, dividing by the resulting `w` so projective matrices are supported.
+    fn transform_point(&self, point: &Vec3) -> Vec3 {
+        self.project_point3(*point)
+    }
+
+    fn transform_vector(&self, vector: &Vec3) -> Vec3 {
+        self.transform_vector3(*vector)
+    }
+
+    fn to_homogeneous(&self) -> Matrix<4, 4> {
+        *self
+    }
+
+    fn try_inverse(&self) -> Option<Self> {
+        Matrix::try_inverse(self)
+    }
+}
+// Transform3 -------------------------------------------------------------------------------------
+
+// Transform2 -------------------------------------------------------------------------------------
+impl Transform2 for Matrix<3, 3> {
+    /// Transforms a point, dividing by the resulting `z` so projective matrices are supported.
+    fn transform_point(&self, point: &Vec2) -> Vec2 {
+        let result = self * point.xyz(1.0);
+        result.xy() / result.z
+    }
+
+    fn transform_vector(&self, vector: &Vec2) -> Vec2 {
+        (self * vector.xyz(0.0)).xy()
+    }
+
+    fn to_homogeneous(&self) -> Matrix<3, 3> {
+        *self
+    }
+
+    fn try_inverse(&self) -> Option<Self> {
+        Matrix::try_inverse(self)
+    }
 }
+// Transform2 -------------------------------------------------------------------------------------
@@ -0,0 +1,54 @@
+use std::ops::{Add, AddAssign};
+
+use crate::Quaternion;
+
+// Quaternion -------------------------------------------------------------------------------------
+impl Add<&Quaternion> for &Quaternion {
+    type Output = Quaternion;
+
+    fn add(self, rhs: &Quaternion) -> Self::Output {
+        Quaternion {
+            vector: self.vector + rhs.vector,
+            scalar: self.scalar + rhs.scalar,
+        }
+    }
+}
+
+#[allow(clippy::op_ref)]
+impl Add<Quaternion> for &Quaternion {
+    type Output = Quaternion;
+
+    fn add(self, rhs: Quaternion) -> Self::Output {
+        self + &rhs
+    }
+}
+
+#[allow(clippy::op_ref)]
+impl Add<&Quaternion> for Quaternion {
+    type Output = Quaternion;
+
+    fn add(self, rhs: &Quaternion) -> Self::Output {
+        &self + rhs
+    }
+}
+
+impl Add<Quaternion> for Quaternion {
+    type Output = Quaternion;
+
+    fn add(self, rhs: Quaternion) -> Self::Output {
+        &self + &rhs
+    }
+}
+
+impl AddAssign<&Quaternion> for Quaternion {
+    fn add_assign(&mut self, rhs: &Quaternion) {
+        *self = *self + rhs;
+    }
+}
+
+impl AddAssign<Quaternion> for Quaternion {
+    fn add_assign(&mut self, rhs: Quaternion) {
+        *self = *self + rhs;
+    }
+}
+// Quaternion -------------------------------------------------------------------------------------
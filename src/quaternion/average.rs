@@ -0,0 +1,227 @@
+//! Averaging and rotation statistics for sets of [`UnitQuaternion`]s.
+
+use crate::{
+    matrix::Matrix,
+    quaternion::{Quaternion, unit::UnitQuaternion},
+    vector::vec3::Vec3,
+};
+
+impl UnitQuaternion {
+    /// Computes the weighted average rotation using Markley's method.
+    ///
+    /// The result is the eigenvector with the largest eigenvalue of `Σ wᵢ qᵢ qᵢᵀ`, which is the
+    /// rotation minimizing the weighted sum of squared chordal distances. It does not depend on
+    /// the order of the inputs or on the sign of each quaternion.
+    ///
+    /// # Parameters
+    /// - `rotations`: The rotations to average.
+    /// - `weights`: One non-negative weight per rotation.
+    ///
+    /// # Returns
+    /// `Some(average)`, or `None` if `rotations` is empty or the weights sum to zero.
+    ///
+    /// # Panics
+    /// Panics if `rotations` and `weights` have different lengths.
+    pub fn weighted_average(rotations: &[Self], weights: &[f32]) -> Option<Self> {
+        assert_eq!(
+            rotations.len(),
+            weights.len(),
+            "every rotation needs exactly one weight"
+        );
+        let total: f32 = weights.iter().sum();
+        if rotations.is_empty() || total <= 0.0 {
+            return None;
+        }
+
+        let mut accumulator = Matrix::<4, 4>::zeros();
+        for (rotation, weight) in rotations.iter().zip(weights) {
+            let components = to_components(rotation);
+            for row in 0..4 {
+                for col in 0..4 {
+                    accumulator[(row, col)] += weight * components[row] * components[col];
+                }
+            }
+        }
+
+        let (eigenvalues, eigenvectors) = symmetric_eigen(accumulator);
+        let largest = (0..4)
+            .max_by(|&a, &b| eigenvalues[a].total_cmp(&eigenvalues[b]))
+            .unwrap_or(0);
+        let [x, y, z, w] = eigenvectors.column(largest);
+        Some(Self::new_normalized(Vec3::new(x, y, z), w))
+    }
+
+    /// Computes the average rotation with equal weights using Markley's method.
+    ///
+    /// # Parameters
+    /// - `rotations`: The rotations to average.
+    ///
+    /// # Returns
+    /// `Some(average)`, or `None` if `rotations` is empty.
+    pub fn average(rotations: &[Self]) -> Option<Self> {
+        Self::weighted_average(rotations, &vec![1.0; rotations.len()])
+    }
+
+    /// Approximates the average rotation by summing the quaternions on the hemisphere of the
+    /// first one and normalizing the result.
+    ///
+    /// Much cheaper than [`UnitQuaternion::average`] and close to it when the rotations are
+    /// clustered within a few tens of degrees; the error grows with the spread.
+    ///
+    /// # Parameters
+    /// - `rotations`: The rotations to average.
+    ///
+    /// # Returns
+    /// `Some(average)`, or `None` if `rotations` is empty or the aligned sum vanishes.
+    pub fn approximate_average(rotations: &[Self]) -> Option<Self> {
+        let reference = rotations.first()?;
+        let sum = rotations.iter().fold(Quaternion::ZERO, |sum, rotation| {
+            let sign = if reference.dot(rotation) < 0.0 {
+                -1.0
+            } else {
+                1.0
+            };
+            sum + **rotation * sign
+        });
+        if sum.magnitude() < f32::EPSILON {
+            return None;
+        }
+        Some(sum.into())
+    }
+
+    /// Computes the geodesic distance to another rotation on the rotation group.
+    ///
+    /// This is the angle of the shortest rotation between the two, so `q` and `-q` are at
+    /// distance zero.
+    ///
+    /// # Parameters
+    /// - `other`: The other rotation.
+    ///
+    /// # Returns
+    /// The distance in radians, in `[0, π]`.
+    pub fn geodesic_distance(&self, other: &Self) -> f32 {
+        self.angle_to(other)
+    }
+
+    /// Computes the angular variance of a set of rotations around a mean rotation.
+    ///
+    /// # Parameters
+    /// - `rotations`: The rotations to measure.
+    /// - `mean`: The reference rotation, usually [`UnitQuaternion::average`] of `rotations`.
+    ///
+    /// # Returns
+    /// `Some(variance)` as the mean squared geodesic distance in radians², or `None` if
+    /// `rotations` is empty.
+    pub fn angular_variance(rotations: &[Self], mean: &Self) -> Option<f32> {
+        if rotations.is_empty() {
+            return None;
+        }
+        let sum: f32 = rotations
+            .iter()
+            .map(|rotation| mean.geodesic_distance(rotation).powi(2))
+            .sum();
+        Some(sum / rotations.len() as f32)
+    }
+
+    /// Computes the angular standard deviation of a set of rotations around a mean rotation.
+    ///
+    /// # Parameters
+    /// - `rotations`: The rotations to measure.
+    /// - `mean`: The reference rotation, usually [`UnitQuaternion::average`] of `rotations`.
+    ///
+    /// # Returns
+    /// `Some(deviation)` as the root mean squared geodesic distance in radians, or `None` if
+    /// `rotations` is empty.
+    pub fn angular_deviation(rotations: &[Self], mean: &Self) -> Option<f32> {
+        Self::angular_variance(rotations, mean).map(f32::sqrt)
+    }
+
+    /// Computes the angular spread of a set of rotations around a mean rotation.
+    ///
+    /// # Parameters
+    /// - `rotations`: The rotations to measure.
+    /// - `mean`: The reference rotation.
+    ///
+    /// # Returns
+    /// `Some(spread)` as the largest geodesic distance to `mean` in radians, or `None` if
+    /// `rotations` is empty.
+    pub fn angular_spread(rotations: &[Self], mean: &Self) -> Option<f32> {
+        rotations
+            .iter()
+            .map(|rotation| mean.geodesic_distance(rotation))
+            .max_by(f32::total_cmp)
+    }
+}
+
+/// Returns the components of a quaternion in `[x, y, z, w]` order.
+fn to_components(rotation: &UnitQuaternion) -> [f32; 4] {
+    [
+        rotation.vector.x,
+        rotation.vector.y,
+        rotation.vector.z,
+        rotation.scalar,
+    ]
+}
+
+/// Diagonalizes a symmetric 4x4 matrix with cyclic Jacobi rotations.
+///
+/// Returns the eigenvalues and a matrix whose columns are the matching unit eigenvectors.
+fn symmetric_eigen(mut matrix: Matrix<4, 4>) -> ([f32; 4], Matrix<4, 4>) {
+    const MAX_SWEEPS: usize = 32;
+
+    let mut eigenvectors = Matrix::<4, 4>::identity();
+    for _ in 0..MAX_SWEEPS {
+        let mut off_diagonal = 0.0;
+        let mut diagonal = 0.0;
+        for row in 0..4 {
+            diagonal += matrix[(row, row)].powi(2);
+            for col in (row + 1)..4 {
+                off_diagonal += matrix[(row, col)].powi(2);
+            }
+        }
+        if off_diagonal <= diagonal * f32::EPSILON * f32::EPSILON {
+            break;
+        }
+
+        for p in 0..3 {
+            for q in (p + 1)..4 {
+                let apq = matrix[(p, q)];
+                if apq == 0.0 {
+                    continue;
+                }
+                // Rotation angle that zeroes the (p, q) entry.
+                let theta = (matrix[(q, q)] - matrix[(p, p)]) / (2.0 * apq);
+                let t = theta.signum() / (theta.abs() + (theta * theta + 1.0).sqrt());
+                let c = 1.0 / (t * t + 1.0).sqrt();
+                let s = t * c;
+
+                for k in 0..4 {
+                    let akp = matrix[(k, p)];
+                    let akq = matrix[(k, q)];
+                    matrix[(k, p)] = c * akp - s * akq;
+                    matrix[(k, q)] = s * akp + c * akq;
+                }
+                for k in 0..4 {
+                    let apk = matrix[(p, k)];
+                    let aqk = matrix[(q, k)];
+                    matrix[(p, k)] = c * apk - s * aqk;
+                    matrix[(q, k)] = s * apk + c * aqk;
+                }
+                for k in 0..4 {
+                    let vkp = eigenvectors[(k, p)];
+                    let vkq = eigenvectors[(k, q)];
+                    eigenvectors[(k, p)] = c * vkp - s * vkq;
+                    eigenvectors[(k, q)] = s * vkp + c * vkq;
+                }
+            }
+        }
+    }
+
+    let eigenvalues = [
+        matrix[(0, 0)],
+        matrix[(1, 1)],
+        matrix[(2, 2)],
+        matrix[(3, 3)],
+    ];
+    (eigenvalues, eigenvectors)
+}
@@ -1,8 +1,58 @@
-use std::ops::Div;
+use std::ops::{Div, DivAssign};
 
 use crate::Quaternion;
 
 // Quaternion -------------------------------------------------------------------------------------
+#[allow(clippy::suspicious_arithmetic_impl)]
+impl Div<&Quaternion> for &Quaternion {
+    type Output = Quaternion;
+
+    /// Multiplies by the inverse of `rhs` on the right, so `(a / b) * b == a`.
+    fn div(self, rhs: &Quaternion) -> Self::Output {
+        self * rhs.inverse()
+    }
+}
+
+#[allow(clippy::op_ref)]
+impl Div<Quaternion> for &Quaternion {
+    type Output = Quaternion;
+
+    fn div(self, rhs: Quaternion) -> Self::Output {
+        self / &rhs
+    }
+}
+
+#[allow(clippy::op_ref)]
+impl Div<&Quaternion> for Quaternion {
+    type Output = Quaternion;
+
+    fn div(self, rhs: &Quaternion) -> Self::Output {
+        &self / rhs
+    }
+}
+
+impl Div<Quaternion> for Quaternion {
+    type Output = Quaternion;
+
+    fn div(self, rhs: Quaternion) -> Self::Output {
+        &self / &rhs
+    }
+}
+
+impl DivAssign<&Quaternion> for Quaternion {
+    fn div_assign(&mut self, rhs: &Quaternion) {
+        *self = *self / rhs;
+    }
+}
+
+impl DivAssign<Quaternion> for Quaternion {
+    fn div_assign(&mut self, rhs: Quaternion) {
+        *self = *self / rhs;
+    }
+}
+// Quaternion -------------------------------------------------------------------------------------
+
+// Scalar -----------------------------------------------------------------------------------------
 impl Div<f32> for &Quaternion {
     type Output = Quaternion;
 
@@ -22,4 +72,10 @@ impl Div<f32> for Quaternion {
         &self / rhs
     }
 }
-// Quaternion -------------------------------------------------------------------------------------
+
+impl DivAssign<f32> for Quaternion {
+    fn div_assign(&mut self, rhs: f32) {
+        *self = *self / rhs;
+    }
+}
+// Scalar -----------------------------------------------------------------------------------------
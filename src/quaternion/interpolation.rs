@@ -0,0 +1,129 @@
+//! Interpolation schemes for [`UnitQuaternion`] beyond plain `slerp`.
+
+use crate::quaternion::unit::UnitQuaternion;
+
+impl UnitQuaternion {
+    /// Performs normalized linear interpolation (nlerp) along the shorter arc.
+    ///
+    /// Cheaper than `slerp` and commutative, but the angular velocity is not constant.
+    ///
+    /// # Parameters
+    /// - `other`: The target unit quaternion.
+    /// - `t`: Interpolation factor in [0, 1].
+    ///
+    /// # Returns
+    /// The interpolated unit quaternion.
+    pub fn nlerp(&self, other: &Self, t: f32) -> Self {
+        let sign = if self.dot(other) < 0.0 { -1.0 } else { 1.0 };
+        (**self * (1.0 - t) + **other * (sign * t)).into()
+    }
+
+    /// Performs spherical linear interpolation along the shorter arc without restricting `t`.
+    ///
+    /// Values of `t` outside [0, 1] keep rotating at the same constant angular velocity, which
+    /// is useful for extrapolating animation past its last keyframe.
+    ///
+    /// # Parameters
+    /// - `other`: The target unit quaternion.
+    /// - `t`: Interpolation factor; `0` gives `self` and `1` gives `other`.
+    ///
+    /// # Returns
+    /// The interpolated unit quaternion.
+    pub fn slerp_unclamped(&self, other: &Self, t: f32) -> Self {
+        let delta = self.inverse() * other;
+        self * Self::from_scaled_axis(delta.log() * t)
+    }
+
+    /// Computes the inner control point used by [`UnitQuaternion::squad`] for a keyframe.
+    ///
+    /// # Parameters
+    /// - `previous`: The keyframe before `current`.
+    /// - `current`: The keyframe the control point belongs to.
+    /// - `next`: The keyframe after `current`.
+    ///
+    /// # Returns
+    /// The control point `current * exp(-(log(current⁻¹ next) + log(current⁻¹ previous)) / 4)`.
+    pub fn squad_control_point(previous: &Self, current: &Self, next: &Self) -> Self {
+        let inverse = current.inverse();
+        let to_next = (inverse * next).log();
+        let to_previous = (inverse * previous).log();
+        current * Self::from_scaled_axis((to_next + to_previous) * -0.25)
+    }
+
+    /// Performs spherical quadrangle interpolation (squad) between this and another keyframe.
+    ///
+    /// With control points from [`UnitQuaternion::squad_control_point`], consecutive segments join
+    /// with continuous angular velocity.
+    ///
+    /// # Parameters
+    /// - `other`: The keyframe at `t = 1`.
+    /// - `control_a`: The inner control point of `self`.
+    /// - `control_b`: The inner control point of `other`.
+    /// - `t`: Interpolation factor in [0, 1].
+    ///
+    /// # Returns
+    /// The interpolated unit quaternion.
+    pub fn squad(&self, other: &Self, control_a: &Self, control_b: &Self, t: f32) -> Self {
+        let outer = self.slerp_unclamped(other, t);
+        let inner = control_a.slerp_unclamped(control_b, t);
+        outer.slerp_unclamped(&inner, 2.0 * t * (1.0 - t))
+    }
+
+    /// Performs squad between `from` and `to`, computing the control points from the
+    /// neighbouring keyframes.
+    ///
+    /// # Parameters
+    /// - `previous`: The keyframe before `from`; pass `from` at the start of a sequence.
+    /// - `from`: The keyframe at `t = 0`.
+    /// - `to`: The keyframe at `t = 1`.
+    /// - `next`: The keyframe after `to`; pass `to` at the end of a sequence.
+    /// - `t`: Interpolation factor in [0, 1].
+    ///
+    /// # Returns
+    /// The interpolated unit quaternion.
+    pub fn squad_spline(previous: &Self, from: &Self, to: &Self, next: &Self, t: f32) -> Self {
+        let control_a = Self::squad_control_point(previous, from, to);
+        let control_b = Self::squad_control_point(from, to, next);
+        from.squad(to, &control_a, &control_b, t)
+    }
+
+    /// Evaluates a spherical Bézier curve with De Casteljau's algorithm, replacing every linear
+    /// interpolation with `slerp`.
+    ///
+    /// # Parameters
+    /// - `control_points`: The control points; the curve starts at the first and ends at the last.
+    /// - `t`: Curve parameter in [0, 1].
+    ///
+    /// # Returns
+    /// The point on the curve.
+    ///
+    /// # Panics
+    /// Panics if `control_points` is empty.
+    pub fn bezier(control_points: &[Self], t: f32) -> Self {
+        assert!(
+            !control_points.is_empty(),
+            "Bezier curve needs at least one control point"
+        );
+        let mut points = control_points.to_vec();
+        for level in (1..points.len()).rev() {
+            for i in 0..level {
+                points[i] = points[i].slerp_unclamped(&points[i + 1], t);
+            }
+        }
+        points[0]
+    }
+
+    /// Evaluates a cubic spherical Bézier curve.
+    ///
+    /// # Parameters
+    /// - `control_a`: The first inner control point.
+    /// - `control_b`: The second inner control point.
+    /// - `other`: The end point of the curve.
+    /// - `t`: Curve parameter in [0, 1].
+    ///
+    /// # Returns
+    /// The point on the curve.
+    pub fn cubic_bezier(&self, control_a: &Self, control_b: &Self, other: &Self, t: f32) -> Self {
+        Self::bezier(&[*self, *control_a, *control_b, *other], t)
+    }
+}
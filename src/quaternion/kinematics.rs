@@ -0,0 +1,90 @@
+//! Angular velocity integration and time derivatives for [`UnitQuaternion`] orientations.
+//!
+//! Angular velocities are expressed in the world frame, as physics engines usually store them.
+//! An angular velocity `ω_body` measured in the body frame, such as a gyroscope reading, becomes
+//! `orientation.rotate_vector(ω_body)` in the world frame.
+
+use crate::{
+    matrix::Matrix,
+    quaternion::{Quaternion, unit::UnitQuaternion},
+    vector::vec3::Vec3,
+};
+
+impl UnitQuaternion {
+    /// Integrates the orientation over a time step with a constant angular velocity, using the
+    /// exact exponential map.
+    ///
+    /// # Parameters
+    /// - `angular_velocity`: The world-frame angular velocity in radians per unit of time.
+    /// - `dt`: The time step.
+    ///
+    /// # Returns
+    /// The orientation `exp(angular_velocity * dt) * self`.
+    pub fn integrate(&self, angular_velocity: Vec3, dt: f32) -> Self {
+        Self::from_scaled_axis(angular_velocity * dt) * self
+    }
+
+    /// Integrates the orientation over a time step with a first-order (explicit Euler) update,
+    /// renormalizing the result.
+    ///
+    /// Cheaper than [`UnitQuaternion::integrate`] and accurate for small `|angular_velocity| * dt`;
+    /// the error grows quadratically with the step.
+    ///
+    /// # Parameters
+    /// - `angular_velocity`: The world-frame angular velocity in radians per unit of time.
+    /// - `dt`: The time step.
+    ///
+    /// # Returns
+    /// The orientation `normalize(self + derivative * dt)`.
+    pub fn integrate_first_order(&self, angular_velocity: Vec3, dt: f32) -> Self {
+        (**self + self.derivative(angular_velocity) * dt).into()
+    }
+
+    /// Computes the constant angular velocity that rotates one orientation into another.
+    ///
+    /// # Parameters
+    /// - `from`: The orientation at the start of the interval.
+    /// - `to`: The orientation at the end of the interval.
+    /// - `dt`: The length of the interval; it must not be zero.
+    ///
+    /// # Returns
+    /// The world-frame angular velocity along the shortest arc, such that
+    /// `from.integrate(velocity, dt)` equals `to`.
+    pub fn angular_velocity_between(from: &Self, to: &Self, dt: f32) -> Vec3 {
+        (to * from.inverse()).log() / dt
+    }
+
+    /// Computes the time derivative of the orientation for an angular velocity.
+    ///
+    /// # Parameters
+    /// - `angular_velocity`: The world-frame angular velocity in radians per unit of time.
+    ///
+    /// # Returns
+    /// The derivative `0.5 * ω * self`, where `ω` is the angular velocity as a pure quaternion.
+    pub fn derivative(&self, angular_velocity: Vec3) -> Quaternion {
+        Quaternion::from(angular_velocity) * **self * 0.5
+    }
+
+    /// Recovers the angular velocity from a time derivative of the orientation.
+    ///
+    /// # Parameters
+    /// - `derivative`: The time derivative of the orientation.
+    ///
+    /// # Returns
+    /// The world-frame angular velocity `2 * derivative * self⁻¹`.
+    pub fn angular_velocity_from_derivative(&self, derivative: &Quaternion) -> Vec3 {
+        (derivative * *self.conjugate() * 2.0).vector
+    }
+
+    /// Rotates an inertia tensor from the body frame into the world frame.
+    ///
+    /// # Parameters
+    /// - `inertia`: The inertia tensor in the body frame.
+    ///
+    /// # Returns
+    /// The world-frame inertia tensor `R I Rᵀ`, where `R` is the rotation matrix.
+    pub fn rotate_inertia(&self, inertia: &Matrix<3, 3>) -> Matrix<3, 3> {
+        let rotation = self.rotation_matrix();
+        rotation * inertia * rotation.transpose()
+    }
+}
@@ -1,7 +1,14 @@
 use crate::vector::vec3::Vec3;
 
+pub mod add;
+mod average;
 pub mod div;
+mod interpolation;
+mod kinematics;
 pub mod mul;
+pub mod neg;
+pub mod sub;
+mod swing_twist;
 pub mod unit;
 
 ///
@@ -31,6 +38,12 @@ impl Default for Quaternion {
 }
 
 impl Quaternion {
+    /// The identity quaternion (no rotation): (0, 0, 0, 1).
+    pub const IDENTITY: Self = Self::identity();
+
+    /// The zero quaternion: (0, 0, 0, 0).
+    pub const ZERO: Self = Self::new(Vec3::ZERO, 0.0);
+
     /// Creates a new quaternion from a vector part and a scalar part.
     ///
     /// # Parameters
@@ -39,7 +52,7 @@ impl Quaternion {
     ///
     /// # Returns
     /// A new quaternion with the specified components.
-    pub fn new(vector: Vec3, scalar: f32) -> Self {
+    pub const fn new(vector: Vec3, scalar: f32) -> Self {
         Quaternion { vector, scalar }
     }
 
@@ -47,9 +60,9 @@ impl Quaternion {
     ///
     /// # Returns
     /// A quaternion representing no rotation: (0, 0, 0, 1).
-    pub fn identity() -> Self {
+    pub const fn identity() -> Self {
         Quaternion {
-            vector: Vec3::zeros(),
+            vector: Vec3::ZERO,
             scalar: 1.0,
         }
     }
@@ -188,6 +201,61 @@ impl Quaternion {
 
         qy * qx * qz
     }
+
+    /// Computes the quaternion exponential.
+    ///
+    /// # Returns
+    /// `exp(q) = e^w * (cos|v| + v / |v| * sin|v|)`, where `w` is the scalar part and `v` the
+    /// vector part.
+    pub fn exp(&self) -> Self {
+        let exp_scalar = self.scalar.exp();
+        let angle = self.vector.magnitude();
+        // sin(angle) / angle tends to 1, so a pure-real quaternion keeps its vector part at zero.
+        let sinc = if angle < f32::EPSILON {
+            1.0
+        } else {
+            angle.sin() / angle
+        };
+        Quaternion {
+            vector: self.vector * (exp_scalar * sinc),
+            scalar: exp_scalar * angle.cos(),
+        }
+    }
+
+    /// Computes the natural logarithm of the quaternion (principal branch).
+    ///
+    /// # Returns
+    /// `ln(q) = ln|q| + v / |v| * atan2(|v|, w)`. Negative real quaternions use the X axis for the
+    /// otherwise arbitrary imaginary direction, and the zero quaternion yields a scalar of `-∞`.
+    pub fn ln(&self) -> Self {
+        let vector_mag = self.vector.magnitude();
+        let angle = vector_mag.atan2(self.scalar);
+        let vector = if vector_mag > 0.0 {
+            self.vector * (angle / vector_mag)
+        } else if self.scalar < 0.0 {
+            Vec3::X * angle
+        } else {
+            Vec3::ZERO
+        };
+        Quaternion {
+            vector,
+            scalar: self.magnitude().ln(),
+        }
+    }
+
+    /// Raises the quaternion to a real power.
+    ///
+    /// # Parameters
+    /// - `exponent`: The power to raise to.
+    ///
+    /// # Returns
+    /// `exp(exponent * ln(q))`, or the zero quaternion if `q` is zero.
+    pub fn powf(&self, exponent: f32) -> Self {
+        if self.magnitude() < f32::EPSILON {
+            return Self::ZERO;
+        }
+        (self.ln() * exponent).exp()
+    }
 }
 
 // From -------------------------------------------------------------------------------------------
@@ -1,6 +1,9 @@
 use std::ops::{Mul, MulAssign};
 
-use crate::quaternion::{Quaternion, unit::UnitQuaternion};
+use crate::{
+    quaternion::{Quaternion, unit::UnitQuaternion},
+    vector::vec3::Vec3,
+};
 
 // Quaternion -------------------------------------------------------------------------------------
 impl Mul<&Quaternion> for &Quaternion {
@@ -43,6 +46,50 @@ impl Mul<Quaternion> for Quaternion {
 }
 // Quaternion -------------------------------------------------------------------------------------
 
+// Scalar -----------------------------------------------------------------------------------------
+impl Mul<f32> for &Quaternion {
+    type Output = Quaternion;
+
+    fn mul(self, rhs: f32) -> Self::Output {
+        Quaternion {
+            vector: self.vector * rhs,
+            scalar: self.scalar * rhs,
+        }
+    }
+}
+
+#[allow(clippy::op_ref)]
+impl Mul<f32> for Quaternion {
+    type Output = Quaternion;
+
+    fn mul(self, rhs: f32) -> Self::Output {
+        &self * rhs
+    }
+}
+
+impl Mul<&Quaternion> for f32 {
+    type Output = Quaternion;
+
+    fn mul(self, rhs: &Quaternion) -> Self::Output {
+        rhs * self
+    }
+}
+
+impl Mul<Quaternion> for f32 {
+    type Output = Quaternion;
+
+    fn mul(self, rhs: Quaternion) -> Self::Output {
+        rhs * self
+    }
+}
+
+impl MulAssign<f32> for Quaternion {
+    fn mul_assign(&mut self, rhs: f32) {
+        *self = *self * rhs;
+    }
+}
+// Scalar -----------------------------------------------------------------------------------------
+
 // UnitQuaternion ---------------------------------------------------------------------------------
 impl Mul<&UnitQuaternion> for &UnitQuaternion {
     type Output = UnitQuaternion;
@@ -90,3 +137,39 @@ impl MulAssign<UnitQuaternion> for UnitQuaternion {
     }
 }
 // UnitQuaternion ---------------------------------------------------------------------------------
+
+// Vector -----------------------------------------------------------------------------------------
+impl Mul<&Vec3> for &UnitQuaternion {
+    type Output = Vec3;
+
+    fn mul(self, vector: &Vec3) -> Self::Output {
+        self.rotate_vector(*vector)
+    }
+}
+
+#[allow(clippy::op_ref)]
+impl Mul<Vec3> for &UnitQuaternion {
+    type Output = Vec3;
+
+    fn mul(self, vector: Vec3) -> Self::Output {
+        self * &vector
+    }
+}
+
+#[allow(clippy::op_ref)]
+impl Mul<&Vec3> for UnitQuaternion {
+    type Output = Vec3;
+
+    fn mul(self, vector: &Vec3) -> Self::Output {
+        &self * vector
+    }
+}
+
+impl Mul<Vec3> for UnitQuaternion {
+    type Output = Vec3;
+
+    fn mul(self, vector: Vec3) -> Self::Output {
+        &self * &vector
+    }
+}
+// Vector -----------------------------------------------------------------------------------------
@@ -0,0 +1,24 @@
+use std::ops::Neg;
+
+use crate::Quaternion;
+
+// Quaternion -------------------------------------------------------------------------------------
+impl Neg for &Quaternion {
+    type Output = Quaternion;
+
+    fn neg(self) -> Self::Output {
+        Quaternion {
+            vector: -self.vector,
+            scalar: -self.scalar,
+        }
+    }
+}
+
+impl Neg for Quaternion {
+    type Output = Quaternion;
+
+    fn neg(self) -> Self::Output {
+        -&self
+    }
+}
+// Quaternion -------------------------------------------------------------------------------------
@@ -0,0 +1,54 @@
+use std::ops::{Sub, SubAssign};
+
+use crate::Quaternion;
+
+// Quaternion -------------------------------------------------------------------------------------
+impl Sub<&Quaternion> for &Quaternion {
+    type Output = Quaternion;
+
+    fn sub(self, rhs: &Quaternion) -> Self::Output {
+        Quaternion {
+            vector: self.vector - rhs.vector,
+            scalar: self.scalar - rhs.scalar,
+        }
+    }
+}
+
+#[allow(clippy::op_ref)]
+impl Sub<Quaternion> for &Quaternion {
+    type Output = Quaternion;
+
+    fn sub(self, rhs: Quaternion) -> Self::Output {
+        self - &rhs
+    }
+}
+
+#[allow(clippy::op_ref)]
+impl Sub<&Quaternion> for Quaternion {
+    type Output = Quaternion;
+
+    fn sub(self, rhs: &Quaternion) -> Self::Output {
+        &self - rhs
+    }
+}
+
+impl Sub<Quaternion> for Quaternion {
+    type Output = Quaternion;
+
+    fn sub(self, rhs: Quaternion) -> Self::Output {
+        &self - &rhs
+    }
+}
+
+impl SubAssign<&Quaternion> for Quaternion {
+    fn sub_assign(&mut self, rhs: &Quaternion) {
+        *self = *self - rhs;
+    }
+}
+
+impl SubAssign<Quaternion> for Quaternion {
+    fn sub_assign(&mut self, rhs: Quaternion) {
+        *self = *self - rhs;
+    }
+}
+// Quaternion -------------------------------------------------------------------------------------
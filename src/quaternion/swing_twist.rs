@@ -0,0 +1,106 @@
+//! Swing-twist decomposition and joint limit clamping for [`UnitQuaternion`].
+
+use crate::{
+    quaternion::{Quaternion, unit::UnitQuaternion},
+    vector::vec3::Vec3,
+};
+
+impl UnitQuaternion {
+    /// Splits the rotation into a swing and a twist around `axis`, such that
+    /// `self == swing * twist`.
+    ///
+    /// The twist rotates around `axis` only and the swing rotates around an axis perpendicular
+    /// to it. The twist is returned on the hemisphere with a non-negative scalar part, so its
+    /// angle lies in `[-π, π]`. When the rotation swings `axis` by exactly 180 degrees the twist
+    /// is undefined; the identity twist is returned and the whole rotation becomes swing.
+    ///
+    /// # Parameters
+    /// - `axis`: The twist axis (will be normalized).
+    ///
+    /// # Returns
+    /// A tuple `(swing, twist)`.
+    pub fn swing_twist(&self, axis: Vec3) -> (Self, Self) {
+        let axis = axis.normalize();
+        let projection = axis * self.vector.dot(&axis);
+        let mut twist = Quaternion::new(projection, self.scalar);
+        if twist.magnitude() < 1e-6 {
+            return (*self, Self::identity());
+        }
+        if twist.scalar < 0.0 {
+            twist = -twist;
+        }
+        let twist = Self::from(twist);
+        (self * twist.inverse(), twist)
+    }
+
+    /// Returns the signed twist angle around `axis`.
+    ///
+    /// # Parameters
+    /// - `axis`: The twist axis (will be normalized).
+    ///
+    /// # Returns
+    /// The twist angle in radians, in `[-π, π]`, positive for counter-clockwise twists.
+    pub fn twist_angle(&self, axis: Vec3) -> f32 {
+        let (_, twist) = self.swing_twist(axis);
+        2.0 * twist.vector.dot(&axis.normalize()).atan2(twist.scalar)
+    }
+
+    /// Limits the twist around `axis` to the range `[min_angle, max_angle]`, keeping the swing.
+    ///
+    /// # Parameters
+    /// - `axis`: The twist axis (will be normalized).
+    /// - `min_angle`: The smallest allowed twist angle in radians, in `[-π, max_angle]`.
+    /// - `max_angle`: The largest allowed twist angle in radians, in `[min_angle, π]`.
+    ///
+    /// # Returns
+    /// The clamped rotation.
+    pub fn clamp_twist(&self, axis: Vec3, min_angle: f32, max_angle: f32) -> Self {
+        let (swing, _) = self.swing_twist(axis);
+        let angle = self.twist_angle(axis).clamp(min_angle, max_angle);
+        swing * Self::from_axis_angle(axis, angle)
+    }
+
+    /// Limits the swing away from `axis` to a cone with the given half-angle, keeping the twist.
+    ///
+    /// # Parameters
+    /// - `axis`: The twist axis, i.e. the centre of the cone (will be normalized).
+    /// - `max_angle`: The cone half-angle in radians, in `[0, π]`.
+    ///
+    /// # Returns
+    /// The clamped rotation.
+    pub fn clamp_swing(&self, axis: Vec3, max_angle: f32) -> Self {
+        let (swing, twist) = self.swing_twist(axis);
+        Self::clamp_swing_angle(&swing, max_angle) * twist
+    }
+
+    /// Applies both a swing cone limit and a twist range limit, as used for ball-and-socket joints.
+    ///
+    /// # Parameters
+    /// - `axis`: The twist axis (will be normalized).
+    /// - `max_swing`: The cone half-angle in radians, in `[0, π]`.
+    /// - `min_twist`: The smallest allowed twist angle in radians.
+    /// - `max_twist`: The largest allowed twist angle in radians.
+    ///
+    /// # Returns
+    /// The clamped rotation.
+    pub fn clamp_swing_twist(
+        &self,
+        axis: Vec3,
+        max_swing: f32,
+        min_twist: f32,
+        max_twist: f32,
+    ) -> Self {
+        let (swing, _) = self.swing_twist(axis);
+        let twist_angle = self.twist_angle(axis).clamp(min_twist, max_twist);
+        Self::clamp_swing_angle(&swing, max_swing) * Self::from_axis_angle(axis, twist_angle)
+    }
+
+    fn clamp_swing_angle(swing: &Self, max_angle: f32) -> Self {
+        match swing.axis() {
+            Some(swing_axis) if swing.angle() > max_angle => {
+                Self::from_axis_angle(swing_axis, max_angle)
+            }
+            _ => *swing,
+        }
+    }
+}
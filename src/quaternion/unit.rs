@@ -1,6 +1,6 @@
 use std::ops::Deref;
 
-use crate::{matrix::Matrix, quaternion::Quaternion, vector::vec3::Vec3};
+use crate::{matrix::Matrix, quaternion::Quaternion, transform::Transform3, vector::vec3::Vec3};
 
 /// A quaternion that is guaranteed to be normalized (unit length).
 ///
@@ -25,6 +25,9 @@ impl Default for UnitQuaternion {
 }
 
 impl UnitQuaternion {
+    /// The identity unit quaternion (no rotation).
+    pub const IDENTITY: Self = Self::identity();
+
     /// Creates a new unit quaternion from a vector and scalar, normalizing the result.
     ///
     /// # Parameters
@@ -42,9 +45,9 @@ impl UnitQuaternion {
     ///
     /// # Returns
     /// The identity unit quaternion.
-    pub fn identity() -> Self {
+    pub const fn identity() -> Self {
         Self {
-            quat: Quaternion::identity(),
+            quat: Quaternion::IDENTITY,
         }
     }
 
@@ -109,6 +112,76 @@ impl UnitQuaternion {
         Quaternion::from_axis_angle(axis, angle).into()
     }
 
+    /// Creates a unit quaternion from a rotation vector (axis scaled by angle).
+    ///
+    /// This is the exponential map of SO(3) and the inverse of [`UnitQuaternion::log`].
+    ///
+    /// # Parameters
+    /// - `scaled_axis`: The rotation axis scaled by the rotation angle in radians.
+    ///
+    /// # Returns
+    /// The unit quaternion representing the rotation; a zero vector gives the identity.
+    pub fn from_scaled_axis(scaled_axis: Vec3) -> Self {
+        Quaternion::from(scaled_axis * 0.5).exp().into()
+    }
+
+    /// Creates the shortest rotation that turns the direction of `from` into the direction of `to`.
+    ///
+    /// Antiparallel vectors are rotated by 180 degrees around an arbitrary axis perpendicular to
+    /// `from`, so the result is always well defined.
+    ///
+    /// # Parameters
+    /// - `from`: The start direction; it does not need to be normalized.
+    /// - `to`: The target direction; it does not need to be normalized.
+    ///
+    /// # Returns
+    /// The rotation, or the identity if either vector is zero or not finite.
+    pub fn rotation_between(from: Vec3, to: Vec3) -> Self {
+        // Divide by the largest component before normalizing so that squaring tiny or huge
+        // components cannot underflow or overflow; a zero vector turns into NaN.
+        let direction = |vector: Vec3| {
+            let vector = vector / vector.x.abs().max(vector.y.abs()).max(vector.z.abs());
+            vector / vector.magnitude()
+        };
+        let (from, to) = (direction(from), direction(to));
+        let dot = from.dot(&to);
+        if !dot.is_finite() {
+            return Self::identity();
+        }
+        if dot < -1.0 + 1e-6 {
+            let (axis, _) = from.any_orthonormal_basis();
+            return Self {
+                quat: Quaternion::new(axis, 0.0),
+            };
+        }
+        // Half-way quaternion: (from x to, 1 + from . to) normalizes to half the angle.
+        Self::new_normalized(from.cross(&to), 1.0 + dot)
+    }
+
+    /// Creates the orientation whose local `-Z` axis points along `forward` and whose local `Y`
+    /// axis is as close as possible to `up`.
+    ///
+    /// This is the camera orientation matching [`Matrix::look_at`]: for a camera at `eye`, the
+    /// rotation part of `Matrix::look_at(eye, eye + forward, up)` is the inverse of this rotation.
+    ///
+    /// # Parameters
+    /// - `forward`: The viewing direction; it does not need to be normalized.
+    /// - `up`: The approximate up direction.
+    ///
+    /// # Returns
+    /// The orientation, with an arbitrary roll if `forward` is parallel to `up`.
+    pub fn look_rotation(forward: Vec3, up: Vec3) -> Self {
+        let f = forward.normalize();
+        let mut s = f.cross(&up);
+        if s.magnitude() < f32::EPSILON {
+            s = f.any_orthonormal_basis().0;
+        }
+        let s = s.normalize();
+        let u = s.cross(&f);
+        let matrix = Matrix::from([*s.as_array(), *u.as_array(), *(-f).as_array()]);
+        Self::from_rotation_matrix(&matrix)
+    }
+
     /// Creates a unit quaternion from Euler angles using the YXZ order (glTF standard).
     ///
     /// # Parameters
@@ -164,6 +237,54 @@ impl UnitQuaternion {
         UnitQuaternion::new_normalized(Vec3::new(x, y, z), w)
     }
 
+    /// Returns the rotation angle of the shortest equivalent rotation.
+    ///
+    /// # Returns
+    /// The angle in radians, in `[0, π]`.
+    pub fn angle(&self) -> f32 {
+        2.0 * self.vector.magnitude().atan2(self.scalar.abs())
+    }
+
+    /// Returns the rotation axis of the shortest equivalent rotation.
+    ///
+    /// # Returns
+    /// `Some(axis)` as a normalized vector, or `None` if the rotation angle is zero.
+    pub fn axis(&self) -> Option<Vec3> {
+        let sin_half_angle = self.vector.magnitude();
+        if sin_half_angle < f32::EPSILON {
+            return None;
+        }
+        Some(self.vector * (self.scalar.signum() / sin_half_angle))
+    }
+
+    /// Computes the angle of the shortest rotation from this orientation to another.
+    ///
+    /// # Parameters
+    /// - `other`: The target orientation.
+    ///
+    /// # Returns
+    /// The angle in radians, in `[0, π]`.
+    pub fn angle_to(&self, other: &Self) -> f32 {
+        (self.inverse() * other).angle()
+    }
+
+    /// Rotates towards another orientation by at most `max_angle`.
+    ///
+    /// # Parameters
+    /// - `target`: The target orientation.
+    /// - `max_angle`: The largest angle to rotate by, in radians; it should not be negative.
+    ///
+    /// # Returns
+    /// `target` if it is within `max_angle`, otherwise the orientation `max_angle` along the
+    /// shortest arc towards it.
+    pub fn rotate_towards(&self, target: &Self, max_angle: f32) -> Self {
+        let angle = self.angle_to(target);
+        if angle <= max_angle {
+            return *target;
+        }
+        self.slerp(target, max_angle / angle)
+    }
+
     /// Rotates a vector by this unit quaternion.
     ///
     /// # Parameters
@@ -261,6 +382,38 @@ impl UnitQuaternion {
         ])
     }
 
+    /// Raises the rotation to a real power, scaling its angle around the same axis.
+    ///
+    /// # Parameters
+    /// - `exponent`: The power to raise to; `0.5` gives half the rotation, `-1.0` the inverse.
+    ///
+    /// # Returns
+    /// The rotation `self^exponent`.
+    pub fn powf(&self, exponent: f32) -> Self {
+        Self::from_scaled_axis(self.log() * exponent)
+    }
+
+    /// Maps the rotation to its rotation vector (the logarithm map of SO(3)).
+    ///
+    /// The quaternion is taken on the hemisphere with a non-negative scalar part, so the result
+    /// describes the shortest rotation.
+    ///
+    /// # Returns
+    /// The rotation axis scaled by the rotation angle in radians, with a magnitude in `[0, π]`.
+    pub fn log(&self) -> Vec3 {
+        let (vector, scalar) = if self.scalar < 0.0 {
+            (-self.vector, -self.scalar)
+        } else {
+            (self.vector, self.scalar)
+        };
+        let sin_half_angle = vector.magnitude();
+        if sin_half_angle < f32::EPSILON {
+            // First-order expansion of 2 * atan2(s, w) / s around s = 0.
+            return vector * (2.0 / scalar);
+        }
+        vector * (2.0 * sin_half_angle.atan2(scalar) / sin_half_angle)
+    }
+
     /// Performs spherical linear interpolation (slerp) between this and another unit quaternion.
     ///
     /// # Parameters
@@ -391,3 +544,23 @@ impl Deref for UnitQuaternion {
     }
 }
 // Deref and DerefMut -----------------------------------------------------------------------------
+
+// Transform3 -------------------------------------------------------------------------------------
+impl Transform3 for UnitQuaternion {
+    fn transform_point(&self, point: &Vec3) -> Vec3 {
+        self.rotate_vector(*point)
+    }
+
+    fn transform_vector(&self, vector: &Vec3) -> Vec3 {
+        self.rotate_vector(*vector)
+    }
+
+    fn to_homogeneous(&self) -> Matrix<4, 4> {
+        self.homogeneous_matrix()
+    }
+
+    fn try_inverse(&self) -> Option<Self> {
+        Some(self.inverse())
+    }
+}
+// Transform3 -------------------------------------------------------------------------------------
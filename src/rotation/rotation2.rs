@@ -0,0 +1,355 @@
+use std::ops::{Mul, MulAssign};
+
+use crate::{matrix::Matrix, transform::Transform2, vector::vec2::Vec2};
+
+/// A 2D rotation represented by a unit complex number.
+///
+/// `Rotation2` stores the cosine and sine of the rotation angle, which is the 2D counterpart of
+/// [`UnitQuaternion`](crate::UnitQuaternion): composition is a complex multiplication and rotating
+/// a vector needs no trigonometry. Positive angles rotate counter-clockwise.
+///
+/// # Example
+/// ```
+/// use vectorama::{Rotation2, Vec2};
+/// let r = Rotation2::from_angle(std::f32::consts::FRAC_PI_2);
+/// let v = r * Vec2::X;
+/// assert!((v.y - 1.0).abs() < 1e-6);
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct Rotation2 {
+    cos: f32,
+    sin: f32,
+}
+
+impl Default for Rotation2 {
+    fn default() -> Self {
+        Self::identity()
+    }
+}
+
+impl Rotation2 {
+    /// The identity rotation (no rotation).
+    pub const IDENTITY: Self = Self::identity();
+
+    /// Creates a new rotation from the real and imaginary parts of a complex number, normalizing
+    /// the result.
+    ///
+    /// # Parameters
+    /// - `cos`: The real part, the cosine of the angle once normalized.
+    /// - `sin`: The imaginary part, the sine of the angle once normalized.
+    ///
+    /// # Returns
+    /// A normalized rotation, or the identity if the complex number is zero or not finite.
+    pub fn new_normalized(cos: f32, sin: f32) -> Self {
+        // Divide by the larger part first so that squaring tiny or huge parts cannot underflow
+        // or overflow.
+        let largest = cos.abs().max(sin.abs());
+        if largest == 0.0 || !cos.is_finite() || !sin.is_finite() {
+            return Self::identity();
+        }
+        let (cos, sin) = (cos / largest, sin / largest);
+        let magnitude = (cos * cos + sin * sin).sqrt();
+        Self {
+            cos: cos / magnitude,
+            sin: sin / magnitude,
+        }
+    }
+
+    /// Returns the identity rotation (no rotation).
+    ///
+    /// # Returns
+    /// The identity rotation.
+    pub const fn identity() -> Self {
+        Self { cos: 1.0, sin: 0.0 }
+    }
+
+    /// Creates a rotation from an angle.
+    ///
+    /// # Parameters
+    /// - `angle`: The rotation angle in radians, counter-clockwise.
+    ///
+    /// # Returns
+    /// The rotation by `angle`.
+    pub fn from_angle(angle: f32) -> Self {
+        let (sin, cos) = angle.sin_cos();
+        Self { cos, sin }
+    }
+
+    /// Creates the shortest rotation that turns the direction of `from` into the direction of `to`.
+    ///
+    /// # Parameters
+    /// - `from`: The start direction; it does not need to be normalized.
+    /// - `to`: The target direction; it does not need to be normalized.
+    ///
+    /// # Returns
+    /// The rotation, or the identity if either vector is zero.
+    pub fn rotation_between(from: Vec2, to: Vec2) -> Self {
+        // Rescale both vectors so their products stay in range; a zero vector turns into NaN,
+        // which `new_normalized` maps to the identity.
+        let from = from / from.x.abs().max(from.y.abs());
+        let to = to / to.x.abs().max(to.y.abs());
+        Self::new_normalized(from.dot(&to), from.x * to.y - from.y * to.x)
+    }
+
+    /// Creates a rotation from a 2x2 rotation matrix.
+    ///
+    /// The matrix is projected onto the closest rotation, so uniformly scaled or slightly
+    /// non-orthonormal matrices are accepted.
+    ///
+    /// # Parameters
+    /// - `matrix`: A 2x2 rotation matrix.
+    ///
+    /// # Returns
+    /// The rotation represented by the matrix.
+    pub fn from_rotation_matrix(matrix: &Matrix<2, 2>) -> Self {
+        let m = matrix;
+        Self::new_normalized(m[(0, 0)] + m[(1, 1)], m[(1, 0)] - m[(0, 1)])
+    }
+
+    /// Returns the rotation angle.
+    ///
+    /// # Returns
+    /// The angle in radians, in `(-π, π]`.
+    pub fn angle(&self) -> f32 {
+        self.sin.atan2(self.cos)
+    }
+
+    /// Returns the cosine of the rotation angle (the real part).
+    pub const fn cos(&self) -> f32 {
+        self.cos
+    }
+
+    /// Returns the sine of the rotation angle (the imaginary part).
+    pub const fn sin(&self) -> f32 {
+        self.sin
+    }
+
+    /// Returns the conjugate of the rotation.
+    ///
+    /// # Returns
+    /// The conjugate, which negates the imaginary part.
+    pub const fn conjugate(&self) -> Self {
+        Self {
+            cos: self.cos,
+            sin: -self.sin,
+        }
+    }
+
+    /// Returns the inverse of the rotation.
+    ///
+    /// # Returns
+    /// The inverse, which for unit complex numbers is the conjugate.
+    pub const fn inverse(&self) -> Self {
+        self.conjugate()
+    }
+
+    /// Computes the signed angle from this rotation to another rotation.
+    ///
+    /// # Parameters
+    /// - `other`: The target rotation.
+    ///
+    /// # Returns
+    /// The angle in radians, in `(-π, π]`, such that `self * from_angle(angle) == other`.
+    pub fn angle_to(&self, other: &Self) -> f32 {
+        (self.inverse() * other).angle()
+    }
+
+    /// Rotates a vector by this rotation.
+    ///
+    /// # Parameters
+    /// - `vector`: The vector to rotate.
+    ///
+    /// # Returns
+    /// The rotated vector.
+    pub fn rotate_vector(&self, vector: Vec2) -> Vec2 {
+        Vec2::new(
+            self.cos * vector.x - self.sin * vector.y,
+            self.sin * vector.x + self.cos * vector.y,
+        )
+    }
+
+    /// Rotates a vector by the inverse of this rotation.
+    ///
+    /// # Parameters
+    /// - `vector`: The vector to rotate.
+    ///
+    /// # Returns
+    /// The rotated vector.
+    pub fn inverse_rotate_vector(&self, vector: Vec2) -> Vec2 {
+        self.inverse().rotate_vector(vector)
+    }
+
+    /// Returns the 2x2 rotation matrix corresponding to this rotation.
+    ///
+    /// # Returns
+    /// A 2x2 rotation matrix.
+    pub fn rotation_matrix(&self) -> Matrix<2, 2> {
+        Matrix::from([[self.cos, self.sin], [-self.sin, self.cos]])
+    }
+
+    /// Returns the 3x3 homogeneous transformation matrix corresponding to this rotation.
+    ///
+    /// # Returns
+    /// A 3x3 homogeneous matrix.
+    pub fn homogeneous_matrix(&self) -> Matrix<3, 3> {
+        Matrix::from([
+            [self.cos, self.sin, 0.0],
+            [-self.sin, self.cos, 0.0],
+            [0.0, 0.0, 1.0],
+        ])
+    }
+
+    /// Performs spherical linear interpolation (slerp) between this and another rotation.
+    ///
+    /// The interpolation always follows the shorter arc.
+    ///
+    /// # Parameters
+    /// - `other`: The target rotation.
+    /// - `t`: Interpolation factor in [0, 1].
+    ///
+    /// # Returns
+    /// The interpolated rotation.
+    pub fn slerp(&self, other: &Self, t: f32) -> Self {
+        self * Self::from_angle(self.angle_to(other) * t)
+    }
+}
+
+// From -------------------------------------------------------------------------------------------
+impl From<&Matrix<2, 2>> for Rotation2 {
+    fn from(matrix: &Matrix<2, 2>) -> Self {
+        Self::from_rotation_matrix(matrix)
+    }
+}
+
+impl From<Matrix<2, 2>> for Rotation2 {
+    fn from(matrix: Matrix<2, 2>) -> Self {
+        Self::from(&matrix)
+    }
+}
+
+impl From<&Matrix<3, 3>> for Rotation2 {
+    fn from(matrix: &Matrix<3, 3>) -> Self {
+        Self::from_rotation_matrix(&matrix.view(0, 0))
+    }
+}
+
+impl From<Matrix<3, 3>> for Rotation2 {
+    fn from(matrix: Matrix<3, 3>) -> Self {
+        Self::from(&matrix)
+    }
+}
+
+impl From<Rotation2> for Matrix<2, 2> {
+    fn from(rotation: Rotation2) -> Self {
+        rotation.rotation_matrix()
+    }
+}
+// From -------------------------------------------------------------------------------------------
+
+// Mul Rotation2 ----------------------------------------------------------------------------------
+impl Mul<&Rotation2> for &Rotation2 {
+    type Output = Rotation2;
+
+    fn mul(self, rhs: &Rotation2) -> Self::Output {
+        // Renormalize so that long chains of compositions do not drift off the unit circle.
+        Rotation2::new_normalized(
+            self.cos * rhs.cos - self.sin * rhs.sin,
+            self.sin * rhs.cos + self.cos * rhs.sin,
+        )
+    }
+}
+
+#[allow(clippy::op_ref)]
+impl Mul<&Rotation2> for Rotation2 {
+    type Output = Rotation2;
+
+    fn mul(self, rhs: &Rotation2) -> Self::Output {
+        &self * rhs
+    }
+}
+
+#[allow(clippy::op_ref)]
+impl Mul<Rotation2> for &Rotation2 {
+    type Output = Rotation2;
+
+    fn mul(self, rhs: Rotation2) -> Self::Output {
+        self * &rhs
+    }
+}
+
+impl Mul<Rotation2> for Rotation2 {
+    type Output = Rotation2;
+
+    fn mul(self, rhs: Rotation2) -> Self::Output {
+        &self * &rhs
+    }
+}
+
+impl MulAssign<&Rotation2> for Rotation2 {
+    fn mul_assign(&mut self, rhs: &Rotation2) {
+        *self = *self * rhs;
+    }
+}
+
+impl MulAssign<Rotation2> for Rotation2 {
+    fn mul_assign(&mut self, rhs: Rotation2) {
+        *self *= &rhs;
+    }
+}
+// Mul Rotation2 ----------------------------------------------------------------------------------
+
+// Mul Vector -------------------------------------------------------------------------------------
+impl Mul<&Vec2> for &Rotation2 {
+    type Output = Vec2;
+
+    fn mul(self, vector: &Vec2) -> Self::Output {
+        self.rotate_vector(*vector)
+    }
+}
+
+#[allow(clippy::op_ref)]
+impl Mul<Vec2> for &Rotation2 {
+    type Output = Vec2;
+
+    fn mul(self, vector: Vec2) -> Self::Output {
+        self * &vector
+    }
+}
+
+#[allow(clippy::op_ref)]
+impl Mul<&Vec2> for Rotation2 {
+    type Output = Vec2;
+
+    fn mul(self, vector: &Vec2) -> Self::Output {
+        &self * vector
+    }
+}
+
+impl Mul<Vec2> for Rotation2 {
+    type Output = Vec2;
+
+    fn mul(self, vector: Vec2) -> Self::Output {
+        &self * &vector
+    }
+}
+// Mul Vector -------------------------------------------------------------------------------------
+
+// Transform2 -------------------------------------------------------------------------------------
+impl Transform2 for Rotation2 {
+    fn transform_point(&self, point: &Vec2) -> Vec2 {
+        self.rotate_vector(*point)
+    }
+
+    fn transform_vector(&self, vector: &Vec2) -> Vec2 {
+        self.rotate_vector(*vector)
+    }
+
+    fn to_homogeneous(&self) -> Matrix<3, 3> {
+        self.homogeneous_matrix()
+    }
+
+    fn try_inverse(&self) -> Option<Self> {
+        Some(self.inverse())
+    }
+}
+// Transform2 -------------------------------------------------------------------------------------
@@ -3,6 +3,7 @@ use std::ops::{Deref, DerefMut, Mul, MulAssign};
 use crate::{
     matrix::Matrix,
     scale::{from_homogeneous_matrix, scale_vector, to_homogeneous_matrix},
+    transform::Transform2,
     vector::{Vector, vec2::Vec2},
 };
 
@@ -13,14 +14,15 @@ pub struct Scale2 {
 
 impl Default for Scale2 {
     fn default() -> Self {
-        Scale2 {
-            vector: Vec2::ones(),
-        }
+        Self::IDENTITY
     }
 }
 
 impl Scale2 {
-    pub fn new(x: f32, y: f32) -> Self {
+    /// The identity scale (unit factor along every axis).
+    pub const IDENTITY: Self = Self { vector: Vec2::ONE };
+
+    pub const fn new(x: f32, y: f32) -> Self {
         Scale2 {
             vector: Vec2::new(x, y),
         }
@@ -33,6 +35,33 @@ impl Scale2 {
     pub fn scale(&self, vector: Vec2) -> Vec2 {
         scale_vector(vector, self.vector).into()
     }
+
+    /// Attempts to compute the inverse of this scale.
+    ///
+    /// # Returns
+    /// The scale by the reciprocal factors, or `None` if any factor is zero or so small that its
+    /// reciprocal overflows.
+    pub fn try_inverse(&self) -> Option<Self> {
+        let vector = self.vector.recip();
+        if vector.as_array().iter().any(|factor| !factor.is_finite()) {
+            return None;
+        }
+        Some(Scale2 { vector })
+    }
+
+    /// Linearly interpolates between this and another scale.
+    ///
+    /// # Parameters
+    /// - `other`: The target scale.
+    /// - `t`: Interpolation factor in [0, 1].
+    ///
+    /// # Returns
+    /// The interpolated scale.
+    pub fn lerp(&self, other: &Self, t: f32) -> Self {
+        Scale2 {
+            vector: self.vector.lerp(&other.vector, t),
+        }
+    }
 }
 
 // From -------------------------------------------------------------------------------------------
@@ -42,6 +71,12 @@ impl From<Vec2> for Scale2 {
     }
 }
 
+impl From<Scale2> for Vec2 {
+    fn from(scale: Scale2) -> Self {
+        scale.vector
+    }
+}
+
 impl From<[f32; 2]> for Scale2 {
     fn from(array: [f32; 2]) -> Self {
         Scale2::from(Vec2::from(array))
@@ -144,3 +179,73 @@ impl MulAssign<f32> for Scale2 {
     }
 }
 // Mul Scalar -------------------------------------------------------------------------------------
+
+// Mul Scale2 -------------------------------------------------------------------------------------
+impl Mul<&Scale2> for &Scale2 {
+    type Output = Scale2;
+
+    fn mul(self, rhs: &Scale2) -> Self::Output {
+        Scale2 {
+            vector: self.vector * rhs.vector,
+        }
+    }
+}
+
+#[allow(clippy::op_ref)]
+impl Mul<&Scale2> for Scale2 {
+    type Output = Scale2;
+
+    fn mul(self, rhs: &Scale2) -> Self::Output {
+        &self * rhs
+    }
+}
+
+#[allow(clippy::op_ref)]
+impl Mul<Scale2> for &Scale2 {
+    type Output = Scale2;
+
+    fn mul(self, rhs: Scale2) -> Self::Output {
+        self * &rhs
+    }
+}
+
+impl Mul<Scale2> for Scale2 {
+    type Output = Scale2;
+
+    fn mul(self, rhs: Scale2) -> Self::Output {
+        &self * &rhs
+    }
+}
+
+impl MulAssign<&Scale2> for Scale2 {
+    fn mul_assign(&mut self, rhs: &Scale2) {
+        self.vector *= rhs.vector;
+    }
+}
+
+impl MulAssign<Scale2> for Scale2 {
+    fn mul_assign(&mut self, rhs: Scale2) {
+        *self *= &rhs;
+    }
+}
+// Mul Scale2 -------------------------------------------------------------------------------------
+
+// Transform2 -------------------------------------------------------------------------------------
+impl Transform2 for Scale2 {
+    fn transform_point(&self, point: &Vec2) -> Vec2 {
+        self.scale(*point)
+    }
+
+    fn transform_vector(&self, vector: &Vec2) -> Vec2 {
+        self.scale(*vector)
+    }
+
+    fn to_homogeneous(&self) -> Matrix<3, 3> {
+        self.homogeneous_matrix()
+    }
+
+    fn try_inverse(&self) -> Option<Self> {
+        Scale2::try_inverse(self)
+    }
+}
+// Transform2 -------------------------------------------------------------------------------------
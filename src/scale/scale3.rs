@@ -3,6 +3,7 @@ use std::ops::{Deref, DerefMut, Mul, MulAssign};
 use crate::{
     matrix::Matrix,
     scale::{from_homogeneous_matrix, scale_vector, to_homogeneous_matrix},
+    transform::Transform3,
     vector::{Vector, vec3::Vec3},
 };
 
@@ -13,14 +14,15 @@ pub struct Scale3 {
 
 impl Default for Scale3 {
     fn default() -> Self {
-        Scale3 {
-            vector: Vec3::ones(),
-        }
+        Self::IDENTITY
     }
 }
 
 impl Scale3 {
-    pub fn new(x: f32, y: f32, z: f32) -> Self {
+    /// The identity scale (unit factor along every axis).
+    pub const IDENTITY: Self = Self { vector: Vec3::ONE };
+
+    pub const fn new(x: f32, y: f32, z: f32) -> Self {
         Scale3 {
             vector: Vec3::new(x, y, z),
         }
@@ -33,6 +35,33 @@ impl Scale3 {
     pub fn scale(&self, vector: Vec3) -> Vec3 {
         scale_vector(vector, self.vector).into()
     }
+
+    /// Attempts to compute the inverse of this scale.
+    ///
+    /// # Returns
+    /// The scale by the reciprocal factors, or `None` if any factor is zero or so small that its
+    /// reciprocal overflows.
+    pub fn try_inverse(&self) -> Option<Self> {
+        let vector = self.vector.recip();
+        if vector.as_array().iter().any(|factor| !factor.is_finite()) {
+            return None;
+        }
+        Some(Scale3 { vector })
+    }
+
+    /// Linearly interpolates between this and another scale.
+    ///
+    /// # Parameters
+    /// - `other`: The target scale.
+    /// - `t`: Interpolation factor in [0, 1].
+    ///
+    /// # Returns
+    /// The interpolated scale.
+    pub fn lerp(&self, other: &Self, t: f32) -> Self {
+        Scale3 {
+            vector: self.vector.lerp(&other.vector, t),
+        }
+    }
 }
 
 // From -------------------------------------------------------------------------------------------
@@ -42,6 +71,12 @@ impl From<Vec3> for Scale3 {
     }
 }
 
+impl From<Scale3> for Vec3 {
+    fn from(scale: Scale3) -> Self {
+        scale.vector
+    }
+}
+
 impl From<[f32; 3]> for Scale3 {
     fn from(array: [f32; 3]) -> Self {
         Scale3::from(Vec3::from(array))
@@ -144,3 +179,73 @@ impl MulAssign<f32> for Scale3 {
     }
 }
 // Mul Scalar -------------------------------------------------------------------------------------
+
+// Mul Scale3 -------------------------------------------------------------------------------------
+impl Mul<&Scale3> for &Scale3 {
+    type Output = Scale3;
+
+    fn mul(self, rhs: &Scale3) -> Self::Output {
+        Scale3 {
+            vector: self.vector * rhs.vector,
+        }
+    }
+}
+
+#[allow(clippy::op_ref)]
+impl Mul<&Scale3> for Scale3 {
+    type Output = Scale3;
+
+    fn mul(self, rhs: &Scale3) -> Self::Output {
+        &self * rhs
+    }
+}
+
+#[allow(clippy::op_ref)]
+impl Mul<Scale3> for &Scale3 {
+    type Output = Scale3;
+
+    fn mul(self, rhs: Scale3) -> Self::Output {
+        self * &rhs
+    }
+}
+
+impl Mul<Scale3> for Scale3 {
+    type Output = Scale3;
+
+    fn mul(self, rhs: Scale3) -> Self::Output {
+        &self * &rhs
+    }
+}
+
+impl MulAssign<&Scale3> for Scale3 {
+    fn mul_assign(&mut self, rhs: &Scale3) {
+        self.vector *= rhs.vector;
+    }
+}
+
+impl MulAssign<Scale3> for Scale3 {
+    fn mul_assign(&mut self, rhs: Scale3) {
+        *self *= &rhs;
+    }
+}
+// Mul Scale3 -------------------------------------------------------------------------------------
+
+// Transform3 -------------------------------------------------------------------------------------
+impl Transform3 for Scale3 {
+    fn transform_point(&self, point: &Vec3) -> Vec3 {
+        self.scale(*point)
+    }
+
+    fn transform_vector(&self, vector: &Vec3) -> Vec3 {
+        self.scale(*vector)
+    }
+
+    fn to_homogeneous(&self) -> Matrix<4, 4> {
+        self.homogeneous_matrix()
+    }
+
+    fn try_inverse(&self) -> Option<Self> {
+        Scale3::try_inverse(self)
+    }
+}
+// Transform3 -------------------------------------------------------------------------------------
@@ -0,0 +1,373 @@
+use std::ops::{Mul, MulAssign};
+
+use crate::{
+    matrix::Matrix,
+    rotation::rotation2::Rotation2,
+    scale::scale2::Scale2,
+    transform::{Transform2, isometry2::Isometry2, similarity2::Similarity2},
+    translation::translation2::Translation2,
+    vector::vec2::Vec2,
+};
+
+/// A general 2D affine transform stored as a column-major 2x3 matrix.
+///
+/// The first two columns hold the linear part (rotation, scale and shear) and the last column
+/// holds the translation, so `Affine2` is the top two rows of the matching 3x3 homogeneous
+/// matrix.
+///
+/// # Example
+/// ```
+/// use vectorama::{Affine2, Scale2, Translation2, Vec2};
+/// let a = Affine2::from(Translation2::new(1.0, 0.0)) * Affine2::from(Scale2::new(2.0, 3.0));
+/// let p = a.transform_point(&Vec2::ONE);
+/// assert_eq!(p.as_array(), &[3.0, 3.0]);
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct Affine2 {
+    matrix: Matrix<2, 3>,
+}
+
+impl Default for Affine2 {
+    fn default() -> Self {
+        Self::IDENTITY
+    }
+}
+
+impl Affine2 {
+    /// The identity transform.
+    pub const IDENTITY: Self =
+        Self::from_matrix(Matrix::from_cols([[1.0, 0.0], [0.0, 1.0], [0.0, 0.0]]));
+
+    /// Creates an affine transform from a 2x3 matrix.
+    ///
+    /// # Parameters
+    /// - `matrix`: The linear part in the first two columns and the translation in the last.
+    ///
+    /// # Returns
+    /// A new `Affine2`.
+    pub const fn from_matrix(matrix: Matrix<2, 3>) -> Self {
+        Self { matrix }
+    }
+
+    /// Creates an affine transform from the images of the basis vectors and a translation.
+    ///
+    /// # Parameters
+    /// - `x_axis`: The image of the X axis.
+    /// - `y_axis`: The image of the Y axis.
+    /// - `translation`: The image of the origin.
+    ///
+    /// # Returns
+    /// A new `Affine2`.
+    pub fn from_cols(x_axis: Vec2, y_axis: Vec2, translation: Vec2) -> Self {
+        Self::from_matrix(Matrix::from([
+            *x_axis.as_array(),
+            *y_axis.as_array(),
+            *translation.as_array(),
+        ]))
+    }
+
+    /// Creates an affine transform from a linear part and a translation.
+    ///
+    /// # Parameters
+    /// - `linear`: The 2x2 linear part.
+    /// - `translation`: The translation applied after the linear part.
+    ///
+    /// # Returns
+    /// A new `Affine2`.
+    pub fn from_linear_translation(linear: &Matrix<2, 2>, translation: Translation2) -> Self {
+        Self::from_cols(
+            linear.column(0).into(),
+            linear.column(1).into(),
+            *translation,
+        )
+    }
+
+    /// Creates an affine transform that scales, then rotates, then translates.
+    ///
+    /// # Parameters
+    /// - `scale`: The non-uniform scale applied first.
+    /// - `rotation`: The rotation.
+    /// - `translation`: The translation applied last.
+    ///
+    /// # Returns
+    /// A new `Affine2`.
+    pub fn from_scale_rotation_translation(
+        scale: Scale2,
+        rotation: Rotation2,
+        translation: Translation2,
+    ) -> Self {
+        Self::from_cols(
+            rotation.rotate_vector(Vec2::X * scale.x),
+            rotation.rotate_vector(Vec2::Y * scale.y),
+            *translation,
+        )
+    }
+
+    /// Creates a shear transform that maps `(x, y)` to `(x + shear * y, y)`.
+    ///
+    /// # Parameters
+    /// - `shear`: The shear factor along the X axis.
+    ///
+    /// # Returns
+    /// A new `Affine2`.
+    pub fn from_shear(shear: f32) -> Self {
+        Self::from_cols(Vec2::X, Vec2::new(shear, 1.0), Vec2::ZERO)
+    }
+
+    /// Returns the underlying 2x3 matrix.
+    pub const fn matrix(&self) -> &Matrix<2, 3> {
+        &self.matrix
+    }
+
+    /// Returns the 2x2 linear part (rotation, scale and shear).
+    pub fn linear(&self) -> Matrix<2, 2> {
+        self.matrix.view(0, 0)
+    }
+
+    /// Returns the translation part.
+    pub fn translation(&self) -> Translation2 {
+        Translation2::from(Vec2::from(self.matrix.column(2)))
+    }
+
+    /// Computes the determinant of the linear part.
+    ///
+    /// # Returns
+    /// The determinant; negative values indicate a reflection.
+    pub fn determinant(&self) -> f32 {
+        self.linear().determinant()
+    }
+
+    /// Attempts to compute the inverse of the transform.
+    ///
+    /// # Returns
+    /// The inverse transform, or `None` if the linear part is singular.
+    pub fn try_inverse(&self) -> Option<Self> {
+        let linear = self.linear().try_inverse()?;
+        let translation = -(linear * *self.translation());
+        Some(Self::from_linear_translation(&linear, translation.into()))
+    }
+
+    /// Transforms a point, applying both the linear part and the translation.
+    ///
+    /// # Parameters
+    /// - `point`: The point to transform.
+    ///
+    /// # Returns
+    /// The transformed point.
+    pub fn transform_point(&self, point: &Vec2) -> Vec2 {
+        self.transform_vector(point) + *self.translation()
+    }
+
+    /// Transforms a vector, applying only the linear part.
+    ///
+    /// # Parameters
+    /// - `vector`: The vector to transform.
+    ///
+    /// # Returns
+    /// The transformed vector.
+    pub fn transform_vector(&self, vector: &Vec2) -> Vec2 {
+        self.linear() * vector
+    }
+
+    /// Decomposes the transform into translation, rotation, scale and shear.
+    ///
+    /// The parts satisfy `self == T * R * S * H`, where `H` is [`Affine2::from_shear`]. The scale
+    /// along X is never negative; a reflection shows up as a negative scale along Y.
+    ///
+    /// # Returns
+    /// A tuple `(translation, rotation, scale, shear)`.
+    pub fn decompose(&self) -> (Translation2, Rotation2, Scale2, f32) {
+        let x_axis = Vec2::from(self.matrix.column(0));
+        let y_axis = Vec2::from(self.matrix.column(1));
+        let scale_x = x_axis.magnitude();
+        let rotation = Rotation2::rotation_between(Vec2::X, x_axis);
+        let y_local = rotation.inverse_rotate_vector(y_axis);
+        let shear = if scale_x < f32::EPSILON {
+            0.0
+        } else {
+            y_local.x / scale_x
+        };
+        let scale = Scale2::new(scale_x, y_local.y);
+        (self.translation(), rotation, scale, shear)
+    }
+
+    /// Decomposes the transform into scale, rotation and translation, discarding any shear.
+    ///
+    /// # Returns
+    /// A tuple `(scale, rotation, translation)`.
+    pub fn to_scale_rotation_translation(&self) -> (Scale2, Rotation2, Translation2) {
+        let (translation, rotation, scale, _) = self.decompose();
+        (scale, rotation, translation)
+    }
+
+    /// Returns the 3x3 homogeneous transformation matrix for this transform.
+    ///
+    /// # Returns
+    /// A 3x3 matrix with `[0, 0, 1]` as its last row.
+    pub fn homogeneous_matrix(&self) -> Matrix<3, 3> {
+        let mut matrix = Matrix::identity();
+        for n in 0..3 {
+            for m in 0..2 {
+                matrix[(m, n)] = self.matrix[(m, n)];
+            }
+        }
+        matrix
+    }
+}
+
+// From -------------------------------------------------------------------------------------------
+impl From<Translation2> for Affine2 {
+    fn from(translation: Translation2) -> Self {
+        Self::from_cols(Vec2::X, Vec2::Y, *translation)
+    }
+}
+
+impl From<Rotation2> for Affine2 {
+    fn from(rotation: Rotation2) -> Self {
+        Self::from_linear_translation(&rotation.rotation_matrix(), Translation2::IDENTITY)
+    }
+}
+
+impl From<Scale2> for Affine2 {
+    fn from(scale: Scale2) -> Self {
+        Self::from_cols(Vec2::X * scale.x, Vec2::Y * scale.y, Vec2::ZERO)
+    }
+}
+
+impl From<Isometry2> for Affine2 {
+    fn from(isometry: Isometry2) -> Self {
+        Self::from(&isometry.homogeneous_matrix())
+    }
+}
+
+impl From<Similarity2> for Affine2 {
+    fn from(similarity: Similarity2) -> Self {
+        Self::from(&similarity.homogeneous_matrix())
+    }
+}
+
+impl From<Matrix<2, 3>> for Affine2 {
+    fn from(matrix: Matrix<2, 3>) -> Self {
+        Self::from_matrix(matrix)
+    }
+}
+
+impl From<&Matrix<3, 3>> for Affine2 {
+    fn from(matrix: &Matrix<3, 3>) -> Self {
+        Self::from_matrix(matrix.view(0, 0))
+    }
+}
+
+impl From<Matrix<3, 3>> for Affine2 {
+    fn from(matrix: Matrix<3, 3>) -> Self {
+        Self::from(&matrix)
+    }
+}
+// From -------------------------------------------------------------------------------------------
+
+// Mul Affine2 ------------------------------------------------------------------------------------
+impl Mul<&Affine2> for &Affine2 {
+    type Output = Affine2;
+
+    fn mul(self, rhs: &Affine2) -> Self::Output {
+        Affine2::from_linear_translation(
+            &(self.linear() * rhs.linear()),
+            self.transform_point(&rhs.translation()).into(),
+        )
+    }
+}
+
+#[allow(clippy::op_ref)]
+impl Mul<&Affine2> for Affine2 {
+    type Output = Affine2;
+
+    fn mul(self, rhs: &Affine2) -> Self::Output {
+        &self * rhs
+    }
+}
+
+#[allow(clippy::op_ref)]
+impl Mul<Affine2> for &Affine2 {
+    type Output = Affine2;
+
+    fn mul(self, rhs: Affine2) -> Self::Output {
+        self * &rhs
+    }
+}
+
+impl Mul<Affine2> for Affine2 {
+    type Output = Affine2;
+
+    fn mul(self, rhs: Affine2) -> Self::Output {
+        &self * &rhs
+    }
+}
+
+impl MulAssign<&Affine2> for Affine2 {
+    fn mul_assign(&mut self, rhs: &Affine2) {
+        *self = *self * rhs;
+    }
+}
+
+impl MulAssign<Affine2> for Affine2 {
+    fn mul_assign(&mut self, rhs: Affine2) {
+        *self *= &rhs;
+    }
+}
+// Mul Affine2 ------------------------------------------------------------------------------------
+
+// Mul Vector -------------------------------------------------------------------------------------
+impl Mul<&Vec2> for &Affine2 {
+    type Output = Vec2;
+
+    fn mul(self, point: &Vec2) -> Self::Output {
+        self.transform_point(point)
+    }
+}
+
+#[allow(clippy::op_ref)]
+impl Mul<Vec2> for &Affine2 {
+    type Output = Vec2;
+
+    fn mul(self, point: Vec2) -> Self::Output {
+        self * &point
+    }
+}
+
+#[allow(clippy::op_ref)]
+impl Mul<&Vec2> for Affine2 {
+    type Output = Vec2;
+
+    fn mul(self, point: &Vec2) -> Self::Output {
+        &self * point
+    }
+}
+
+impl Mul<Vec2> for Affine2 {
+    type Output = Vec2;
+
+    fn mul(self, point: Vec2) -> Self::Output {
+        &self * &point
+    }
+}
+// Mul Vector -------------------------------------------------------------------------------------
+
+// Transform2 -------------------------------------------------------------------------------------
+impl Transform2 for Affine2 {
+    fn transform_point(&self, point: &Vec2) -> Vec2 {
+        Affine2::transform_point(self, point)
+    }
+
+    fn transform_vector(&self, vector: &Vec2) -> Vec2 {
+        Affine2::transform_vector(self, vector)
+    }
+
+    fn to_homogeneous(&self) -> Matrix<3, 3> {
+        self.homogeneous_matrix()
+    }
+
+    fn try_inverse(&self) -> Option<Self> {
+        Affine2::try_inverse(self)
+    }
+}
+// Transform2 -------------------------------------------------------------------------------------
@@ -0,0 +1,280 @@
+use std::ops::{Mul, MulAssign};
+
+use crate::{
+    matrix::Matrix, rotation::rotation2::Rotation2, transform::Transform2,
+    translation::translation2::Translation2, vector::vec2::Vec2,
+};
+
+/// A 2D rigid transform: a rotation followed by a translation.
+///
+/// `Isometry2` preserves distances and angles. Applied to a point `p` it computes
+/// `rotation * p + translation`, which matches the homogeneous matrix
+/// `translation.homogeneous_matrix() * rotation.homogeneous_matrix()`.
+///
+/// # Example
+/// ```
+/// use vectorama::{Isometry2, Vec2};
+/// let iso = Isometry2::new(Vec2::new(1.0, 0.0), std::f32::consts::FRAC_PI_2);
+/// let p = iso.transform_point(&Vec2::X);
+/// assert!((p.x - 1.0).abs() < 1e-6 && (p.y - 1.0).abs() < 1e-6);
+/// ```
+#[derive(Default, Debug, Clone, Copy)]
+pub struct Isometry2 {
+    pub rotation: Rotation2,
+    pub translation: Translation2,
+}
+
+impl Isometry2 {
+    /// The identity isometry (no rotation or translation).
+    pub const IDENTITY: Self = Self::identity();
+
+    /// Creates a new isometry from a translation vector and a rotation angle.
+    ///
+    /// # Parameters
+    /// - `translation`: The translation applied after the rotation.
+    /// - `angle`: The rotation angle in radians, counter-clockwise.
+    ///
+    /// # Returns
+    /// A new `Isometry2`.
+    pub fn new(translation: Vec2, angle: f32) -> Self {
+        Self::from_parts(translation.into(), Rotation2::from_angle(angle))
+    }
+
+    /// Creates a new isometry from its translation and rotation parts.
+    ///
+    /// # Parameters
+    /// - `translation`: The translation applied after the rotation.
+    /// - `rotation`: The rotation.
+    ///
+    /// # Returns
+    /// A new `Isometry2`.
+    pub const fn from_parts(translation: Translation2, rotation: Rotation2) -> Self {
+        Self {
+            rotation,
+            translation,
+        }
+    }
+
+    /// Returns the identity isometry (no rotation or translation).
+    ///
+    /// # Returns
+    /// The identity isometry.
+    pub const fn identity() -> Self {
+        Self::from_parts(Translation2::IDENTITY, Rotation2::IDENTITY)
+    }
+
+    /// Returns the inverse of the isometry.
+    ///
+    /// # Returns
+    /// The isometry that undoes this one.
+    pub fn inverse(&self) -> Self {
+        let rotation = self.rotation.inverse();
+        let translation = -rotation.rotate_vector(*self.translation);
+        Self::from_parts(translation.into(), rotation)
+    }
+
+    /// Transforms a point, applying both the rotation and the translation.
+    ///
+    /// # Parameters
+    /// - `point`: The point to transform.
+    ///
+    /// # Returns
+    /// The transformed point.
+    pub fn transform_point(&self, point: &Vec2) -> Vec2 {
+        self.translation
+            .translate(&self.rotation.rotate_vector(*point))
+    }
+
+    /// Transforms a vector, applying only the rotation.
+    ///
+    /// # Parameters
+    /// - `vector`: The vector to transform.
+    ///
+    /// # Returns
+    /// The rotated vector.
+    pub fn transform_vector(&self, vector: &Vec2) -> Vec2 {
+        self.rotation.rotate_vector(*vector)
+    }
+
+    /// Transforms a point by the inverse of this isometry.
+    ///
+    /// # Parameters
+    /// - `point`: The point to transform.
+    ///
+    /// # Returns
+    /// The transformed point.
+    pub fn inverse_transform_point(&self, point: &Vec2) -> Vec2 {
+        self.rotation
+            .inverse_rotate_vector(point - *self.translation)
+    }
+
+    /// Transforms a vector by the inverse of this isometry.
+    ///
+    /// # Parameters
+    /// - `vector`: The vector to transform.
+    ///
+    /// # Returns
+    /// The rotated vector.
+    pub fn inverse_transform_vector(&self, vector: &Vec2) -> Vec2 {
+        self.rotation.inverse_rotate_vector(*vector)
+    }
+
+    /// Interpolates linearly between the translations and spherically between the rotations.
+    ///
+    /// # Parameters
+    /// - `other`: The target isometry.
+    /// - `t`: Interpolation factor in [0, 1].
+    ///
+    /// # Returns
+    /// The interpolated isometry.
+    pub fn lerp_slerp(&self, other: &Self, t: f32) -> Self {
+        Self::from_parts(
+            self.translation.lerp(&other.translation, t),
+            self.rotation.slerp(&other.rotation, t),
+        )
+    }
+
+    /// Returns the 3x3 homogeneous transformation matrix for this isometry.
+    ///
+    /// # Returns
+    /// A 3x3 matrix representing the isometry in homogeneous coordinates.
+    pub fn homogeneous_matrix(&self) -> Matrix<3, 3> {
+        let mut matrix = self.rotation.homogeneous_matrix();
+        matrix[(0, 2)] = self.translation.x;
+        matrix[(1, 2)] = self.translation.y;
+        matrix
+    }
+}
+
+// From -------------------------------------------------------------------------------------------
+impl From<Translation2> for Isometry2 {
+    fn from(translation: Translation2) -> Self {
+        Self::from_parts(translation, Rotation2::IDENTITY)
+    }
+}
+
+impl From<Rotation2> for Isometry2 {
+    fn from(rotation: Rotation2) -> Self {
+        Self::from_parts(Translation2::IDENTITY, rotation)
+    }
+}
+
+impl From<&Matrix<3, 3>> for Isometry2 {
+    fn from(matrix: &Matrix<3, 3>) -> Self {
+        Self::from_parts(Translation2::from(matrix), Rotation2::from(matrix))
+    }
+}
+
+impl From<Matrix<3, 3>> for Isometry2 {
+    fn from(matrix: Matrix<3, 3>) -> Self {
+        Self::from(&matrix)
+    }
+}
+// From -------------------------------------------------------------------------------------------
+
+// Mul Isometry2 ----------------------------------------------------------------------------------
+impl Mul<&Isometry2> for &Isometry2 {
+    type Output = Isometry2;
+
+    fn mul(self, rhs: &Isometry2) -> Self::Output {
+        Isometry2::from_parts(
+            self.transform_point(&rhs.translation).into(),
+            self.rotation * rhs.rotation,
+        )
+    }
+}
+
+#[allow(clippy::op_ref)]
+impl Mul<&Isometry2> for Isometry2 {
+    type Output = Isometry2;
+
+    fn mul(self, rhs: &Isometry2) -> Self::Output {
+        &self * rhs
+    }
+}
+
+#[allow(clippy::op_ref)]
+impl Mul<Isometry2> for &Isometry2 {
+    type Output = Isometry2;
+
+    fn mul(self, rhs: Isometry2) -> Self::Output {
+        self * &rhs
+    }
+}
+
+impl Mul<Isometry2> for Isometry2 {
+    type Output = Isometry2;
+
+    fn mul(self, rhs: Isometry2) -> Self::Output {
+        &self * &rhs
+    }
+}
+
+impl MulAssign<&Isometry2> for Isometry2 {
+    fn mul_assign(&mut self, rhs: &Isometry2) {
+        *self = *self * rhs;
+    }
+}
+
+impl MulAssign<Isometry2> for Isometry2 {
+    fn mul_assign(&mut self, rhs: Isometry2) {
+        *self *= &rhs;
+    }
+}
+// Mul Isometry2 ----------------------------------------------------------------------------------
+
+// Mul Vector -------------------------------------------------------------------------------------
+impl Mul<&Vec2> for &Isometry2 {
+    type Output = Vec2;
+
+    fn mul(self, point: &Vec2) -> Self::Output {
+        self.transform_point(point)
+    }
+}
+
+#[allow(clippy::op_ref)]
+impl Mul<Vec2> for &Isometry2 {
+    type Output = Vec2;
+
+    fn mul(self, point: Vec2) -> Self::Output {
+        self * &point
+    }
+}
+
+#[allow(clippy::op_ref)]
+impl Mul<&Vec2> for Isometry2 {
+    type Output = Vec2;
+
+    fn mul(self, point: &Vec2) -> Self::Output {
+        &self * point
+    }
+}
+
+impl Mul<Vec2> for Isometry2 {
+    type Output = Vec2;
+
+    fn mul(self, point: Vec2) -> Self::Output {
+        &self * &point
+    }
+}
+// Mul Vector -------------------------------------------------------------------------------------
+
+// Transform2 -------------------------------------------------------------------------------------
+impl Transform2 for Isometry2 {
+    fn transform_point(&self, point: &Vec2) -> Vec2 {
+        Isometry2::transform_point(self, point)
+    }
+
+    fn transform_vector(&self, vector: &Vec2) -> Vec2 {
+        Isometry2::transform_vector(self, vector)
+    }
+
+    fn to_homogeneous(&self) -> Matrix<3, 3> {
+        self.homogeneous_matrix()
+    }
+
+    fn try_inverse(&self) -> Option<Self> {
+        Some(self.inverse())
+    }
+}
+// Transform2 -------------------------------------------------------------------------------------
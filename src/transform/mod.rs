@@ -0,0 +1,126 @@
+use crate::{
+    matrix::Matrix,
+    vector::{vec2::Vec2, vec3::Vec3},
+};
+
+pub mod affine2;
+pub mod isometry2;
+mod products;
+pub mod similarity2;
+
+/// Behaviour shared by every 3D transform type.
+///
+/// Implemented for [`Translation3`](crate::Translation3), [`Scale3`](crate::Scale3),
+/// [`UnitQuaternion`](crate::UnitQuaternion), [`UnitDualQuaternion`](crate::UnitDualQuaternion)
+/// and `Matrix<4, 4>`, so code that only needs to move points and vectors around can be written
+/// once for all of them.
+///
+/// # Example
+/// ```
+/// use vectorama::{Translation3, UnitQuaternion, Vec3, transform::Transform3};
+///
+/// fn centroid<T: Transform3>(transform: &T, points: &[Vec3]) -> Vec3 {
+///     let sum = points.iter().map(|p| transform.transform_point(p)).sum::<Vec3>();
+///     sum / points.len() as f32
+/// }
+///
+/// let points = [Vec3::X, Vec3::NEG_X];
+/// let c = centroid(&Translation3::new(0.0, 1.0, 0.0), &points);
+/// assert_eq!(c.as_array(), &[0.0, 1.0, 0.0]);
+/// let c = centroid(&UnitQuaternion::identity(), &points);
+/// assert_eq!(c.as_array(), &[0.0, 0.0, 0.0]);
+/// ```
+pub trait Transform3 {
+    /// Transforms a point, including any translation.
+    ///
+    /// # Parameters
+    /// - `point`: The point to transform.
+    ///
+    /// # Returns
+    /// The transformed point.
+    fn transform_point(&self, point: &Vec3) -> Vec3;
+
+    /// Transforms a direction vector, ignoring any translation.
+    ///
+    /// # Parameters
+    /// - `vector`: The vector to transform.
+    ///
+    /// # Returns
+    /// The transformed vector.
+    fn transform_vector(&self, vector: &Vec3) -> Vec3;
+
+    /// Returns the 4x4 homogeneous matrix equivalent to this transform.
+    ///
+    /// # Returns
+    /// A 4x4 homogeneous matrix.
+    fn to_homogeneous(&self) -> Matrix<4, 4>;
+
+    /// Attempts to compute the inverse transform.
+    ///
+    /// # Returns
+    /// The inverse, or `None` if the transform is not invertible.
+    fn try_inverse(&self) -> Option<Self>
+    where
+        Self: Sized;
+
+    /// Composes this transform with another one that is applied afterwards.
+    ///
+    /// # Parameters
+    /// - `other`: The transform applied after `self`.
+    ///
+    /// # Returns
+    /// The homogeneous matrix of `other * self`.
+    fn then<T: Transform3>(&self, other: &T) -> Matrix<4, 4> {
+        other.to_homogeneous() * self.to_homogeneous()
+    }
+}
+
+/// Behaviour shared by every 2D transform type.
+///
+/// Implemented for [`Translation2`](crate::Translation2), [`Scale2`](crate::Scale2),
+/// [`Rotation2`](crate::Rotation2), [`Isometry2`](crate::Isometry2),
+/// [`Similarity2`](crate::Similarity2), [`Affine2`](crate::Affine2) and `Matrix<3, 3>`.
+pub trait Transform2 {
+    /// Transforms a point, including any translation.
+    ///
+    /// # Parameters
+    /// - `point`: The point to transform.
+    ///
+    /// # Returns
+    /// The transformed point.
+    fn transform_point(&self, point: &Vec2) -> Vec2;
+
+    /// Transforms a direction vector, ignoring any translation.
+    ///
+    /// # Parameters
+    /// - `vector`: The vector to transform.
+    ///
+    /// # Returns
+    /// The transformed vector.
+    fn transform_vector(&self, vector: &Vec2) -> Vec2;
+
+    /// Returns the 3x3 homogeneous matrix equivalent to this transform.
+    ///
+    /// # Returns
+    /// A 3x3 homogeneous matrix.
+    fn to_homogeneous(&self) -> Matrix<3, 3>;
+
+    /// Attempts to compute the inverse transform.
+    ///
+    /// # Returns
+    /// The inverse, or `None` if the transform is not invertible.
+    fn try_inverse(&self) -> Option<Self>
+    where
+        Self: Sized;
+
+    /// Composes this transform with another one that is applied afterwards.
+    ///
+    /// # Parameters
+    /// - `other`: The transform applied after `self`.
+    ///
+    /// # Returns
+    /// The homogeneous matrix of `other * self`.
+    fn then<T: Transform2>(&self, other: &T) -> Matrix<3, 3> {
+        other.to_homogeneous() * self.to_homogeneous()
+    }
+}
@@ -0,0 +1,127 @@
+//! Products between different transform types.
+//!
+//! Products that stay within a closed 2D family produce the smallest type that can represent the
+//! result: a translation and a rotation compose into an [`Isometry2`], and anything involving a
+//! non-uniform scale composes into an [`Affine2`]. The 3D types have no combined representation
+//! other than a homogeneous matrix, so their mixed products produce a `Matrix<4, 4>`. Every
+//! product applies the right operand first, like matrix multiplication.
+
+use std::ops::Mul;
+
+use crate::{
+    matrix::Matrix,
+    quaternion::unit::UnitQuaternion,
+    rotation::rotation2::Rotation2,
+    scale::{scale2::Scale2, scale3::Scale3},
+    transform::{affine2::Affine2, isometry2::Isometry2, similarity2::Similarity2},
+    translation::{translation2::Translation2, translation3::Translation3},
+};
+
+/// Implements `Mul` for every combination of owned and borrowed operands, forwarding to a single
+/// body that receives both operands by reference.
+macro_rules! transform_mul {
+    ($lhs:ty, $rhs:ty => $out:ty, |$a:ident, $b:ident| $body:expr) => {
+        impl Mul<&$rhs> for &$lhs {
+            type Output = $out;
+
+            fn mul(self, rhs: &$rhs) -> Self::Output {
+                let ($a, $b) = (self, rhs);
+                $body
+            }
+        }
+
+        #[allow(clippy::op_ref)]
+        impl Mul<&$rhs> for $lhs {
+            type Output = $out;
+
+            fn mul(self, rhs: &$rhs) -> Self::Output {
+                &self * rhs
+            }
+        }
+
+        #[allow(clippy::op_ref)]
+        impl Mul<$rhs> for &$lhs {
+            type Output = $out;
+
+            fn mul(self, rhs: $rhs) -> Self::Output {
+                self * &rhs
+            }
+        }
+
+        impl Mul<$rhs> for $lhs {
+            type Output = $out;
+
+            fn mul(self, rhs: $rhs) -> Self::Output {
+                &self * &rhs
+            }
+        }
+    };
+}
+
+// Isometry2 --------------------------------------------------------------------------------------
+transform_mul!(Translation2, Rotation2 => Isometry2, |t, r| Isometry2::from_parts(*t, *r));
+transform_mul!(Rotation2, Translation2 => Isometry2, |r, t| {
+    Isometry2::from_parts(r.rotate_vector(**t).into(), *r)
+});
+transform_mul!(Translation2, Isometry2 => Isometry2, |t, i| Isometry2::from(*t) * i);
+transform_mul!(Isometry2, Translation2 => Isometry2, |i, t| i * Isometry2::from(*t));
+transform_mul!(Rotation2, Isometry2 => Isometry2, |r, i| Isometry2::from(*r) * i);
+transform_mul!(Isometry2, Rotation2 => Isometry2, |i, r| i * Isometry2::from(*r));
+// Isometry2 --------------------------------------------------------------------------------------
+
+// Affine2 ----------------------------------------------------------------------------------------
+transform_mul!(Translation2, Scale2 => Affine2, |t, s| Affine2::from(*t) * Affine2::from(*s));
+transform_mul!(Scale2, Translation2 => Affine2, |s, t| Affine2::from(*s) * Affine2::from(*t));
+transform_mul!(Rotation2, Scale2 => Affine2, |r, s| Affine2::from(*r) * Affine2::from(*s));
+transform_mul!(Scale2, Rotation2 => Affine2, |s, r| Affine2::from(*s) * Affine2::from(*r));
+transform_mul!(Isometry2, Scale2 => Affine2, |i, s| Affine2::from(*i) * Affine2::from(*s));
+transform_mul!(Scale2, Isometry2 => Affine2, |s, i| Affine2::from(*s) * Affine2::from(*i));
+transform_mul!(Affine2, Translation2 => Affine2, |a, t| a * Affine2::from(*t));
+transform_mul!(Translation2, Affine2 => Affine2, |t, a| Affine2::from(*t) * a);
+transform_mul!(Affine2, Rotation2 => Affine2, |a, r| a * Affine2::from(*r));
+transform_mul!(Rotation2, Affine2 => Affine2, |r, a| Affine2::from(*r) * a);
+transform_mul!(Affine2, Scale2 => Affine2, |a, s| a * Affine2::from(*s));
+transform_mul!(Scale2, Affine2 => Affine2, |s, a| Affine2::from(*s) * a);
+// Affine2 ----------------------------------------------------------------------------------------
+
+// Matrix<3, 3> -----------------------------------------------------------------------------------
+transform_mul!(Translation2, Matrix<3, 3> => Matrix<3, 3>, |t, m| t.homogeneous_matrix() * m);
+transform_mul!(Matrix<3, 3>, Translation2 => Matrix<3, 3>, |m, t| m * t.homogeneous_matrix());
+transform_mul!(Scale2, Matrix<3, 3> => Matrix<3, 3>, |s, m| s.homogeneous_matrix() * m);
+transform_mul!(Matrix<3, 3>, Scale2 => Matrix<3, 3>, |m, s| m * s.homogeneous_matrix());
+transform_mul!(Rotation2, Matrix<3, 3> => Matrix<3, 3>, |r, m| r.homogeneous_matrix() * m);
+transform_mul!(Matrix<3, 3>, Rotation2 => Matrix<3, 3>, |m, r| m * r.homogeneous_matrix());
+transform_mul!(Isometry2, Matrix<3, 3> => Matrix<3, 3>, |i, m| i.homogeneous_matrix() * m);
+transform_mul!(Matrix<3, 3>, Isometry2 => Matrix<3, 3>, |m, i| m * i.homogeneous_matrix());
+transform_mul!(Similarity2, Matrix<3, 3> => Matrix<3, 3>, |s, m| s.homogeneous_matrix() * m);
+transform_mul!(Matrix<3, 3>, Similarity2 => Matrix<3, 3>, |m, s| m * s.homogeneous_matrix());
+transform_mul!(Affine2, Matrix<3, 3> => Matrix<3, 3>, |a, m| a.homogeneous_matrix() * m);
+transform_mul!(Matrix<3, 3>, Affine2 => Matrix<3, 3>, |m, a| m * a.homogeneous_matrix());
+// Matrix<3, 3> -----------------------------------------------------------------------------------
+
+// Matrix<4, 4> -----------------------------------------------------------------------------------
+transform_mul!(Translation3, UnitQuaternion => Matrix<4, 4>, |t, q| {
+    t.homogeneous_matrix() * q.homogeneous_matrix()
+});
+transform_mul!(UnitQuaternion, Translation3 => Matrix<4, 4>, |q, t| {
+    q.homogeneous_matrix() * t.homogeneous_matrix()
+});
+transform_mul!(Translation3, Scale3 => Matrix<4, 4>, |t, s| {
+    t.homogeneous_matrix() * s.homogeneous_matrix()
+});
+transform_mul!(Scale3, Translation3 => Matrix<4, 4>, |s, t| {
+    s.homogeneous_matrix() * t.homogeneous_matrix()
+});
+transform_mul!(UnitQuaternion, Scale3 => Matrix<4, 4>, |q, s| {
+    q.homogeneous_matrix() * s.homogeneous_matrix()
+});
+transform_mul!(Scale3, UnitQuaternion => Matrix<4, 4>, |s, q| {
+    s.homogeneous_matrix() * q.homogeneous_matrix()
+});
+transform_mul!(Translation3, Matrix<4, 4> => Matrix<4, 4>, |t, m| t.homogeneous_matrix() * m);
+transform_mul!(Matrix<4, 4>, Translation3 => Matrix<4, 4>, |m, t| m * t.homogeneous_matrix());
+transform_mul!(Scale3, Matrix<4, 4> => Matrix<4, 4>, |s, m| s.homogeneous_matrix() * m);
+transform_mul!(Matrix<4, 4>, Scale3 => Matrix<4, 4>, |m, s| m * s.homogeneous_matrix());
+transform_mul!(UnitQuaternion, Matrix<4, 4> => Matrix<4, 4>, |q, m| q.homogeneous_matrix() * m);
+transform_mul!(Matrix<4, 4>, UnitQuaternion => Matrix<4, 4>, |m, q| m * q.homogeneous_matrix());
+// Matrix<4, 4> -----------------------------------------------------------------------------------
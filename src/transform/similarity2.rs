@@ -0,0 +1,298 @@
+use std::ops::{Mul, MulAssign};
+
+use crate::{
+    matrix::Matrix,
+    rotation::rotation2::Rotation2,
+    transform::{Transform2, isometry2::Isometry2},
+    translation::translation2::Translation2,
+    vector::vec2::Vec2,
+};
+
+/// A 2D similarity transform: a uniform scale, then a rotation, then a translation.
+///
+/// `Similarity2` preserves angles and ratios of distances. Applied to a point `p` it computes
+/// `rotation * (scale * p) + translation`.
+///
+/// # Example
+/// ```
+/// use vectorama::{Similarity2, Vec2};
+/// let sim = Similarity2::new(Vec2::new(1.0, 0.0), 0.0, 2.0);
+/// let p = sim.transform_point(&Vec2::ONE);
+/// assert_eq!(p.as_array(), &[3.0, 2.0]);
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct Similarity2 {
+    pub isometry: Isometry2,
+    pub scale: f32,
+}
+
+impl Default for Similarity2 {
+    fn default() -> Self {
+        Self::identity()
+    }
+}
+
+impl Similarity2 {
+    /// The identity similarity (unit scale, no rotation or translation).
+    pub const IDENTITY: Self = Self::identity();
+
+    /// Creates a new similarity from a translation vector, a rotation angle and a scale factor.
+    ///
+    /// # Parameters
+    /// - `translation`: The translation applied last.
+    /// - `angle`: The rotation angle in radians, counter-clockwise.
+    /// - `scale`: The uniform scale factor applied first.
+    ///
+    /// # Returns
+    /// A new `Similarity2`.
+    pub fn new(translation: Vec2, angle: f32, scale: f32) -> Self {
+        Self::from_isometry(Isometry2::new(translation, angle), scale)
+    }
+
+    /// Creates a new similarity from its translation, rotation and scale parts.
+    ///
+    /// # Parameters
+    /// - `translation`: The translation applied last.
+    /// - `rotation`: The rotation.
+    /// - `scale`: The uniform scale factor applied first.
+    ///
+    /// # Returns
+    /// A new `Similarity2`.
+    pub const fn from_parts(translation: Translation2, rotation: Rotation2, scale: f32) -> Self {
+        Self::from_isometry(Isometry2::from_parts(translation, rotation), scale)
+    }
+
+    /// Creates a new similarity from an isometry and a scale factor.
+    ///
+    /// # Parameters
+    /// - `isometry`: The rigid part of the transform.
+    /// - `scale`: The uniform scale factor applied before the isometry.
+    ///
+    /// # Returns
+    /// A new `Similarity2`.
+    pub const fn from_isometry(isometry: Isometry2, scale: f32) -> Self {
+        Self { isometry, scale }
+    }
+
+    /// Creates a similarity that only scales.
+    ///
+    /// # Parameters
+    /// - `scale`: The uniform scale factor.
+    ///
+    /// # Returns
+    /// A new `Similarity2`.
+    pub const fn from_scaling(scale: f32) -> Self {
+        Self::from_isometry(Isometry2::IDENTITY, scale)
+    }
+
+    /// Returns the identity similarity (unit scale, no rotation or translation).
+    ///
+    /// # Returns
+    /// The identity similarity.
+    pub const fn identity() -> Self {
+        Self::from_scaling(1.0)
+    }
+
+    /// Returns the rotation part of the similarity.
+    pub const fn rotation(&self) -> Rotation2 {
+        self.isometry.rotation
+    }
+
+    /// Returns the translation part of the similarity.
+    pub const fn translation(&self) -> Translation2 {
+        self.isometry.translation
+    }
+
+    /// Attempts to compute the inverse of the similarity.
+    ///
+    /// # Returns
+    /// The inverse similarity, or `None` if the scale factor is zero or so small that its
+    /// reciprocal overflows.
+    pub fn try_inverse(&self) -> Option<Self> {
+        let scale = self.scale.recip();
+        if !scale.is_finite() {
+            return None;
+        }
+        let rotation = self.isometry.rotation.inverse();
+        let translation = -rotation.rotate_vector(*self.isometry.translation) * scale;
+        Some(Self::from_parts(translation.into(), rotation, scale))
+    }
+
+    /// Transforms a point, applying the scale, rotation and translation.
+    ///
+    /// # Parameters
+    /// - `point`: The point to transform.
+    ///
+    /// # Returns
+    /// The transformed point.
+    pub fn transform_point(&self, point: &Vec2) -> Vec2 {
+        self.isometry.transform_point(&(point * self.scale))
+    }
+
+    /// Transforms a vector, applying the scale and rotation but not the translation.
+    ///
+    /// # Parameters
+    /// - `vector`: The vector to transform.
+    ///
+    /// # Returns
+    /// The transformed vector.
+    pub fn transform_vector(&self, vector: &Vec2) -> Vec2 {
+        self.isometry.transform_vector(&(vector * self.scale))
+    }
+
+    /// Returns the 3x3 homogeneous transformation matrix for this similarity.
+    ///
+    /// # Returns
+    /// A 3x3 matrix representing the similarity in homogeneous coordinates.
+    pub fn homogeneous_matrix(&self) -> Matrix<3, 3> {
+        let mut matrix = self.isometry.homogeneous_matrix();
+        for n in 0..2 {
+            for m in 0..2 {
+                matrix[(m, n)] *= self.scale;
+            }
+        }
+        matrix
+    }
+}
+
+// From -------------------------------------------------------------------------------------------
+impl From<Isometry2> for Similarity2 {
+    fn from(isometry: Isometry2) -> Self {
+        Self::from_isometry(isometry, 1.0)
+    }
+}
+
+impl From<Translation2> for Similarity2 {
+    fn from(translation: Translation2) -> Self {
+        Self::from(Isometry2::from(translation))
+    }
+}
+
+impl From<Rotation2> for Similarity2 {
+    fn from(rotation: Rotation2) -> Self {
+        Self::from(Isometry2::from(rotation))
+    }
+}
+
+impl From<&Matrix<3, 3>> for Similarity2 {
+    fn from(matrix: &Matrix<3, 3>) -> Self {
+        let linear = matrix.view::<2, 2>(0, 0);
+        let scale = linear.determinant().abs().sqrt();
+        Self::from_isometry(Isometry2::from(matrix), scale)
+    }
+}
+
+impl From<Matrix<3, 3>> for Similarity2 {
+    fn from(matrix: Matrix<3, 3>) -> Self {
+        Self::from(&matrix)
+    }
+}
+// From -------------------------------------------------------------------------------------------
+
+// Mul Similarity2 --------------------------------------------------------------------------------
+impl Mul<&Similarity2> for &Similarity2 {
+    type Output = Similarity2;
+
+    fn mul(self, rhs: &Similarity2) -> Self::Output {
+        Similarity2::from_parts(
+            self.transform_point(&rhs.isometry.translation).into(),
+            self.isometry.rotation * rhs.isometry.rotation,
+            self.scale * rhs.scale,
+        )
+    }
+}
+
+#[allow(clippy::op_ref)]
+impl Mul<&Similarity2> for Similarity2 {
+    type Output = Similarity2;
+
+    fn mul(self, rhs: &Similarity2) -> Self::Output {
+        &self * rhs
+    }
+}
+
+#[allow(clippy::op_ref)]
+impl Mul<Similarity2> for &Similarity2 {
+    type Output = Similarity2;
+
+    fn mul(self, rhs: Similarity2) -> Self::Output {
+        self * &rhs
+    }
+}
+
+impl Mul<Similarity2> for Similarity2 {
+    type Output = Similarity2;
+
+    fn mul(self, rhs: Similarity2) -> Self::Output {
+        &self * &rhs
+    }
+}
+
+impl MulAssign<&Similarity2> for Similarity2 {
+    fn mul_assign(&mut self, rhs: &Similarity2) {
+        *self = *self * rhs;
+    }
+}
+
+impl MulAssign<Similarity2> for Similarity2 {
+    fn mul_assign(&mut self, rhs: Similarity2) {
+        *self *= &rhs;
+    }
+}
+// Mul Similarity2 --------------------------------------------------------------------------------
+
+// Mul Vector -------------------------------------------------------------------------------------
+impl Mul<&Vec2> for &Similarity2 {
+    type Output = Vec2;
+
+    fn mul(self, point: &Vec2) -> Self::Output {
+        self.transform_point(point)
+    }
+}
+
+#[allow(clippy::op_ref)]
+impl Mul<Vec2> for &Similarity2 {
+    type Output = Vec2;
+
+    fn mul(self, point: Vec2) -> Self::Output {
+        self * &point
+    }
+}
+
+#[allow(clippy::op_ref)]
+impl Mul<&Vec2> for Similarity2 {
+    type Output = Vec2;
+
+    fn mul(self, point: &Vec2) -> Self::Output {
+        &self * point
+    }
+}
+
+impl Mul<Vec2> for Similarity2 {
+    type Output = Vec2;
+
+    fn mul(self, point: Vec2) -> Self::Output {
+        &self * &point
+    }
+}
+// Mul Vector -------------------------------------------------------------------------------------
+
+// Transform2 -------------------------------------------------------------------------------------
+impl Transform2 for Similarity2 {
+    fn transform_point(&self, point: &Vec2) -> Vec2 {
+        Similarity2::transform_point(self, point)
+    }
+
+    fn transform_vector(&self, vector: &Vec2) -> Vec2 {
+        Similarity2::transform_vector(self, vector)
+    }
+
+    fn to_homogeneous(&self) -> Matrix<3, 3> {
+        self.homogeneous_matrix()
+    }
+
+    fn try_inverse(&self) -> Option<Self> {
+        Similarity2::try_inverse(self)
+    }
+}
+// Transform2 -------------------------------------------------------------------------------------
@@ -1,7 +1,8 @@
-use std::ops::{AddAssign, Deref, DerefMut, Mul, SubAssign};
+use std::ops::{AddAssign, Deref, DerefMut, Mul, MulAssign, SubAssign};
 
 use crate::{
     matrix::Matrix,
+    transform::Transform2,
     translation::{from_homogeneous_matrix, to_homogeneous_matrix},
     vector::{Vector, vec2::Vec2},
 };
@@ -23,6 +24,9 @@ pub struct Translation2 {
 }
 
 impl Translation2 {
+    /// The identity translation (no displacement).
+    pub const IDENTITY: Self = Self { vector: Vec2::ZERO };
+
     /// Creates a new translation from x, y components.
     ///
     /// # Parameters
@@ -31,7 +35,7 @@ impl Translation2 {
     ///
     /// # Returns
     /// A new `Translation2` representing the given translation.
-    pub fn new(x: f32, y: f32) -> Self {
+    pub const fn new(x: f32, y: f32) -> Self {
         Translation2 {
             vector: Vec2::new(x, y),
         }
@@ -55,6 +59,30 @@ impl Translation2 {
     pub fn translate(&self, vector: &Vec2) -> Vec2 {
         vector + self.vector
     }
+
+    /// Returns the inverse of this translation.
+    ///
+    /// # Returns
+    /// The translation by the opposite displacement.
+    pub fn inverse(&self) -> Self {
+        Translation2 {
+            vector: -self.vector,
+        }
+    }
+
+    /// Linearly interpolates between this and another translation.
+    ///
+    /// # Parameters
+    /// - `other`: The target translation.
+    /// - `t`: Interpolation factor in [0, 1].
+    ///
+    /// # Returns
+    /// The interpolated translation.
+    pub fn lerp(&self, other: &Self, t: f32) -> Self {
+        Translation2 {
+            vector: self.vector.lerp(&other.vector, t),
+        }
+    }
 }
 
 // From -------------------------------------------------------------------------------------------
@@ -171,3 +199,73 @@ impl SubAssign<Vec2> for Translation2 {
     }
 }
 // SubAssign Vector -------------------------------------------------------------------------------
+
+// Mul Translation2 -------------------------------------------------------------------------------
+impl Mul<&Translation2> for &Translation2 {
+    type Output = Translation2;
+
+    fn mul(self, rhs: &Translation2) -> Self::Output {
+        Translation2 {
+            vector: self.translate(&rhs.vector),
+        }
+    }
+}
+
+#[allow(clippy::op_ref)]
+impl Mul<&Translation2> for Translation2 {
+    type Output = Translation2;
+
+    fn mul(self, rhs: &Translation2) -> Self::Output {
+        &self * rhs
+    }
+}
+
+#[allow(clippy::op_ref)]
+impl Mul<Translation2> for &Translation2 {
+    type Output = Translation2;
+
+    fn mul(self, rhs: Translation2) -> Self::Output {
+        self * &rhs
+    }
+}
+
+impl Mul<Translation2> for Translation2 {
+    type Output = Translation2;
+
+    fn mul(self, rhs: Translation2) -> Self::Output {
+        &self * &rhs
+    }
+}
+
+impl MulAssign<&Translation2> for Translation2 {
+    fn mul_assign(&mut self, rhs: &Translation2) {
+        *self = *self * rhs;
+    }
+}
+
+impl MulAssign<Translation2> for Translation2 {
+    fn mul_assign(&mut self, rhs: Translation2) {
+        *self *= &rhs;
+    }
+}
+// Mul Translation2 -------------------------------------------------------------------------------
+
+// Transform2 -------------------------------------------------------------------------------------
+impl Transform2 for Translation2 {
+    fn transform_point(&self, point: &Vec2) -> Vec2 {
+        self.translate(point)
+    }
+
+    fn transform_vector(&self, vector: &Vec2) -> Vec2 {
+        *vector
+    }
+
+    fn to_homogeneous(&self) -> Matrix<3, 3> {
+        self.homogeneous_matrix()
+    }
+
+    fn try_inverse(&self) -> Option<Self> {
+        Some(self.inverse())
+    }
+}
+// Transform2 -------------------------------------------------------------------------------------
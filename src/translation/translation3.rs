@@ -1,7 +1,8 @@
-use std::ops::{AddAssign, Deref, DerefMut, Mul, SubAssign};
+use std::ops::{AddAssign, Deref, DerefMut, Mul, MulAssign, SubAssign};
 
 use crate::{
     matrix::Matrix,
+    transform::Transform3,
     translation::{from_homogeneous_matrix, to_homogeneous_matrix},
     vector::{Vector, vec3::Vec3},
 };
@@ -23,6 +24,9 @@ pub struct Translation3 {
 }
 
 impl Translation3 {
+    /// The identity translation (no displacement).
+    pub const IDENTITY: Self = Self { vector: Vec3::ZERO };
+
     /// Creates a new translation from x, y, z components.
     ///
     /// # Parameters
@@ -32,7 +36,7 @@ impl Translation3 {
     ///
     /// # Returns
     /// A new `Translation3` representing the given translation.
-    pub fn new(x: f32, y: f32, z: f32) -> Self {
+    pub const fn new(x: f32, y: f32, z: f32) -> Self {
         Translation3 {
             vector: Vec3::new(x, y, z),
         }
@@ -56,6 +60,30 @@ impl Translation3 {
     pub fn translate(&self, vector: &Vec3) -> Vec3 {
         vector + self.vector
     }
+
+    /// Returns the inverse of this translation.
+    ///
+    /// # Returns
+    /// The translation by the opposite displacement.
+    pub fn inverse(&self) -> Self {
+        Translation3 {
+            vector: -self.vector,
+        }
+    }
+
+    /// Linearly interpolates between this and another translation.
+    ///
+    /// # Parameters
+    /// - `other`: The target translation.
+    /// - `t`: Interpolation factor in [0, 1].
+    ///
+    /// # Returns
+    /// The interpolated translation.
+    pub fn lerp(&self, other: &Self, t: f32) -> Self {
+        Translation3 {
+            vector: self.vector.lerp(&other.vector, t),
+        }
+    }
 }
 
 // From -------------------------------------------------------------------------------------------
@@ -172,3 +200,73 @@ impl SubAssign<Vec3> for Translation3 {
     }
 }
 // SubAssign Vector -------------------------------------------------------------------------------
+
+// Mul Translation3 -------------------------------------------------------------------------------
+impl Mul<&Translation3> for &Translation3 {
+    type Output = Translation3;
+
+    fn mul(self, rhs: &Translation3) -> Self::Output {
+        Translation3 {
+            vector: self.translate(&rhs.vector),
+        }
+    }
+}
+
+#[allow(clippy::op_ref)]
+impl Mul<&Translation3> for Translation3 {
+    type Output = Translation3;
+
+    fn mul(self, rhs: &Translation3) -> Self::Output {
+        &self * rhs
+    }
+}
+
+#[allow(clippy::op_ref)]
+impl Mul<Translation3> for &Translation3 {
+    type Output = Translation3;
+
+    fn mul(self, rhs: Translation3) -> Self::Output {
+        self * &rhs
+    }
+}
+
+impl Mul<Translation3> for Translation3 {
+    type Output = Translation3;
+
+    fn mul(self, rhs: Translation3) -> Self::Output {
+        &self * &rhs
+    }
+}
+
+impl MulAssign<&Translation3> for Translation3 {
+    fn mul_assign(&mut self, rhs: &Translation3) {
+        *self = *self * rhs;
+    }
+}
+
+impl MulAssign<Translation3> for Translation3 {
+    fn mul_assign(&mut self, rhs: Translation3) {
+        *self *= &rhs;
+    }
+}
+// Mul Translation3 -------------------------------------------------------------------------------
+
+// Transform3 -------------------------------------------------------------------------------------
+impl Transform3 for Translation3 {
+    fn transform_point(&self, point: &Vec3) -> Vec3 {
+        self.translate(point)
+    }
+
+    fn transform_vector(&self, vector: &Vec3) -> Vec3 {
+        *vector
+    }
+
+    fn to_homogeneous(&self) -> Matrix<4, 4> {
+        self.homogeneous_matrix()
+    }
+
+    fn try_inverse(&self) -> Option<Self> {
+        Some(self.inverse())
+    }
+}
+// Transform3 -------------------------------------------------------------------------------------
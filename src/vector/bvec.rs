@@ -0,0 +1,242 @@
+//! Boolean mask vectors and component-wise comparisons.
+//!
+//! Comparison methods (`cmpeq`, `cmplt`, ...) on every vector type return a mask, which can be
+//! reduced with `any`/`all` or used to pick components with `select`.
+//!
+//! # Example
+//! ```
+//! use vectorama::Vec3;
+//! let a = Vec3::new(1.0, 5.0, 3.0);
+//! let b = Vec3::new(2.0, 4.0, 3.0);
+//! let mask = a.cmplt(&b);
+//! assert!(mask.any() && !mask.all());
+//! let smallest = Vec3::select(mask, a, b);
+//! assert_eq!(smallest.as_array(), &[1.0, 4.0, 3.0]);
+//! ```
+
+use std::ops::{BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Not};
+
+use crate::vector::{vec2::Vec2, vec3::Vec3, vec4::Vec4};
+
+macro_rules! mask_vector {
+    ($(#[$doc:meta])* $name:ident, $n:literal, [$($c:ident),+]) => {
+        $(#[$doc])*
+        #[repr(C)]
+        #[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+        pub struct $name {
+            $(pub $c: bool,)+
+        }
+
+        impl $name {
+            /// A mask with every component set to `false`.
+            pub const FALSE: Self = Self::splat(false);
+
+            /// A mask with every component set to `true`.
+            pub const TRUE: Self = Self::splat(true);
+
+            /// Creates a new mask from its components.
+            ///
+            /// # Returns
+            /// A new mask with the specified components.
+            pub const fn new($($c: bool),+) -> Self {
+                Self { $($c),+ }
+            }
+
+            /// Creates a mask with every component set to `value`.
+            ///
+            /// # Parameters
+            /// - `value`: The value of every component.
+            ///
+            /// # Returns
+            /// A new mask.
+            pub const fn splat(value: bool) -> Self {
+                Self { $($c: value),+ }
+            }
+
+            /// Returns `true` if any component is `true`.
+            pub const fn any(&self) -> bool {
+                false $(|| self.$c)+
+            }
+
+            /// Returns `true` if every component is `true`.
+            pub const fn all(&self) -> bool {
+                true $(&& self.$c)+
+            }
+
+            /// Packs the mask into the low bits of an integer, with `x` in bit 0.
+            ///
+            /// # Returns
+            /// The bitmask as a `u32`.
+            pub const fn bitmask(&self) -> u32 {
+                let array = self.to_array();
+                let mut bits = 0;
+                let mut m = 0;
+                while m < $n {
+                    if array[m] {
+                        bits |= 1 << m;
+                    }
+                    m += 1;
+                }
+                bits
+            }
+
+            /// Returns the components as an array.
+            ///
+            /// # Returns
+            /// The components, in order.
+            pub const fn to_array(&self) -> [bool; $n] {
+                [$(self.$c),+]
+            }
+        }
+
+        impl From<[bool; $n]> for $name {
+            fn from(array: [bool; $n]) -> Self {
+                let [$($c),+] = array;
+                Self { $($c),+ }
+            }
+        }
+
+        impl From<$name> for [bool; $n] {
+            fn from(mask: $name) -> Self {
+                mask.to_array()
+            }
+        }
+
+        impl Not for $name {
+            type Output = $name;
+
+            fn not(self) -> Self::Output {
+                Self { $($c: !self.$c),+ }
+            }
+        }
+
+        impl BitAnd for $name {
+            type Output = $name;
+
+            fn bitand(self, rhs: $name) -> Self::Output {
+                Self { $($c: self.$c & rhs.$c),+ }
+            }
+        }
+
+        impl BitAndAssign for $name {
+            fn bitand_assign(&mut self, rhs: $name) {
+                *self = *self & rhs;
+            }
+        }
+
+        impl BitOr for $name {
+            type Output = $name;
+
+            fn bitor(self, rhs: $name) -> Self::Output {
+                Self { $($c: self.$c | rhs.$c),+ }
+            }
+        }
+
+        impl BitOrAssign for $name {
+            fn bitor_assign(&mut self, rhs: $name) {
+                *self = *self | rhs;
+            }
+        }
+
+        impl BitXor for $name {
+            type Output = $name;
+
+            fn bitxor(self, rhs: $name) -> Self::Output {
+                Self { $($c: self.$c ^ rhs.$c),+ }
+            }
+        }
+
+        impl BitXorAssign for $name {
+            fn bitxor_assign(&mut self, rhs: $name) {
+                *self = *self ^ rhs;
+            }
+        }
+    };
+}
+
+/// Implements the `cmp*` comparison methods and `select` for a vector type and its mask type.
+macro_rules! comparisons {
+    ($vec:ident, $mask:ident, [$($c:ident),+]) => {
+        impl $vec {
+            /// Compares every component for equality.
+            ///
+            /// # Returns
+            /// A mask holding `self[m] == other[m]`.
+            pub fn cmpeq(&self, other: &$vec) -> $mask {
+                $mask::new($(self.$c == other.$c),+)
+            }
+
+            /// Compares every component for inequality.
+            ///
+            /// # Returns
+            /// A mask holding `self[m] != other[m]`.
+            pub fn cmpne(&self, other: &$vec) -> $mask {
+                $mask::new($(self.$c != other.$c),+)
+            }
+
+            /// Compares every component with `<`.
+            ///
+            /// # Returns
+            /// A mask holding `self[m] < other[m]`.
+            pub fn cmplt(&self, other: &$vec) -> $mask {
+                $mask::new($(self.$c < other.$c),+)
+            }
+
+            /// Compares every component with `<=`.
+            ///
+            /// # Returns
+            /// A mask holding `self[m] <= other[m]`.
+            pub fn cmple(&self, other: &$vec) -> $mask {
+                $mask::new($(self.$c <= other.$c),+)
+            }
+
+            /// Compares every component with `>`.
+            ///
+            /// # Returns
+            /// A mask holding `self[m] > other[m]`.
+            pub fn cmpgt(&self, other: &$vec) -> $mask {
+                $mask::new($(self.$c > other.$c),+)
+            }
+
+            /// Compares every component with `>=`.
+            ///
+            /// # Returns
+            /// A mask holding `self[m] >= other[m]`.
+            pub fn cmpge(&self, other: &$vec) -> $mask {
+                $mask::new($(self.$c >= other.$c),+)
+            }
+
+            /// Picks every component from `if_true` or `if_false` depending on `mask`.
+            ///
+            /// # Parameters
+            /// - `mask`: The selection mask.
+            /// - `if_true`: The components used where the mask is `true`.
+            /// - `if_false`: The components used where the mask is `false`.
+            ///
+            /// # Returns
+            /// The selected vector.
+            pub fn select(mask: $mask, if_true: $vec, if_false: $vec) -> $vec {
+                $vec::new($(if mask.$c { if_true.$c } else { if_false.$c }),+)
+            }
+        }
+    };
+}
+
+pub(crate) use comparisons;
+
+mask_vector!(
+    /// A 2D boolean mask, as produced by comparing two 2D vectors.
+    BVec2, 2, [x, y]
+);
+mask_vector!(
+    /// A 3D boolean mask, as produced by comparing two 3D vectors.
+    BVec3, 3, [x, y, z]
+);
+mask_vector!(
+    /// A 4D boolean mask, as produced by comparing two 4D vectors.
+    BVec4, 4, [x, y, z, w]
+);
+
+comparisons!(Vec2, BVec2, [x, y]);
+comparisons!(Vec3, BVec3, [x, y, z]);
+comparisons!(Vec4, BVec4, [x, y, z, w]);
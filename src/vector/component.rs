@@ -0,0 +1,449 @@
+//! Component-wise vector math following GLSL semantics.
+//!
+//! The generic implementations live on [`Vector<M>`]; [`Vec2`], [`Vec3`] and [`Vec4`] forward to
+//! them so shader prototypes behave identically on the CPU.
+
+use std::iter::Product;
+
+use crate::vector::{Vector, vec2::Vec2, vec3::Vec3, vec4::Vec4};
+
+impl<const M: usize> Vector<M> {
+    /// Applies `f` to every component.
+    ///
+    /// # Parameters
+    /// - `f`: The function applied to each component.
+    ///
+    /// # Returns
+    /// A new vector holding `f(self[m])` for every component.
+    pub fn map(&self, f: impl Fn(f32) -> f32) -> Self {
+        let mut result = Self::zeros();
+        for m in 0..M {
+            result[m] = f(self[m]);
+        }
+        result
+    }
+
+    /// Applies `f` to every pair of components of `self` and `other`.
+    ///
+    /// # Parameters
+    /// - `other`: The other vector.
+    /// - `f`: The function applied to each pair of components.
+    ///
+    /// # Returns
+    /// A new vector holding `f(self[m], other[m])` for every component.
+    pub fn zip_map(&self, other: &Self, f: impl Fn(f32, f32) -> f32) -> Self {
+        let mut result = Self::zeros();
+        for m in 0..M {
+            result[m] = f(self[m], other[m]);
+        }
+        result
+    }
+
+    /// Computes the component-wise (Hadamard) product.
+    ///
+    /// # Parameters
+    /// - `other`: The other vector.
+    ///
+    /// # Returns
+    /// A new vector holding `self[m] * other[m]`.
+    pub fn component_mul(&self, other: &Self) -> Self {
+        self.zip_map(other, |a, b| a * b)
+    }
+
+    /// Computes the component-wise quotient.
+    ///
+    /// # Parameters
+    /// - `other`: The divisor vector.
+    ///
+    /// # Returns
+    /// A new vector holding `self[m] / other[m]`.
+    pub fn component_div(&self, other: &Self) -> Self {
+        self.zip_map(other, |a, b| a / b)
+    }
+
+    /// Computes the component-wise minimum.
+    ///
+    /// # Parameters
+    /// - `other`: The other vector.
+    ///
+    /// # Returns
+    /// A new vector holding the smaller of each pair of components.
+    pub fn min(&self, other: &Self) -> Self {
+        self.zip_map(other, f32::min)
+    }
+
+    /// Computes the component-wise maximum.
+    ///
+    /// # Parameters
+    /// - `other`: The other vector.
+    ///
+    /// # Returns
+    /// A new vector holding the larger of each pair of components.
+    pub fn max(&self, other: &Self) -> Self {
+        self.zip_map(other, f32::max)
+    }
+
+    /// Clamps every component between the matching components of `min` and `max`.
+    ///
+    /// Like GLSL `clamp`, this computes `min(max(self, min), max)` and does not panic when
+    /// `min > max`.
+    ///
+    /// # Parameters
+    /// - `min`: The lower bounds.
+    /// - `max`: The upper bounds.
+    ///
+    /// # Returns
+    /// The clamped vector.
+    pub fn clamp(&self, min: &Self, max: &Self) -> Self {
+        self.max(min).min(max)
+    }
+
+    /// Returns the absolute value of every component.
+    ///
+    /// # Returns
+    /// A new vector holding `|self[m]|`.
+    pub fn abs(&self) -> Self {
+        self.map(f32::abs)
+    }
+
+    /// Rounds every component down to the nearest integer.
+    ///
+    /// # Returns
+    /// A new vector holding `floor(self[m])`.
+    pub fn floor(&self) -> Self {
+        self.map(f32::floor)
+    }
+
+    /// Rounds every component up to the nearest integer.
+    ///
+    /// # Returns
+    /// A new vector holding `ceil(self[m])`.
+    pub fn ceil(&self) -> Self {
+        self.map(f32::ceil)
+    }
+
+    /// Rounds every component to the nearest integer, with halfway cases rounded away from zero.
+    ///
+    /// # Returns
+    /// A new vector holding `round(self[m])`.
+    pub fn round(&self) -> Self {
+        self.map(f32::round)
+    }
+
+    /// Returns the fractional part of every component, as GLSL `fract`: `x - floor(x)`.
+    ///
+    /// Unlike [`f32::fract`], the result is always in `[0, 1)`, also for negative inputs.
+    ///
+    /// # Returns
+    /// A new vector holding `self[m] - floor(self[m])`.
+    pub fn fract(&self) -> Self {
+        self.map(|x| x - x.floor())
+    }
+
+    /// Returns the sign of every component, as GLSL `sign`.
+    ///
+    /// Unlike [`f32::signum`], zero maps to zero.
+    ///
+    /// # Returns
+    /// A new vector holding `1.0`, `-1.0` or `0.0` per component (`NaN` is preserved).
+    pub fn signum(&self) -> Self {
+        self.map(|x| {
+            if x > 0.0 {
+                1.0
+            } else if x < 0.0 {
+                -1.0
+            } else {
+                x
+            }
+        })
+    }
+
+    /// Returns the reciprocal of every component.
+    ///
+    /// # Returns
+    /// A new vector holding `1.0 / self[m]`.
+    pub fn recip(&self) -> Self {
+        self.map(f32::recip)
+    }
+
+    /// Linearly interpolates towards `other` by a scalar factor.
+    ///
+    /// # Parameters
+    /// - `other`: The target vector.
+    /// - `t`: The interpolation factor; `0.0` returns `self`, `1.0` returns `other`.
+    ///
+    /// # Returns
+    /// The interpolated vector `self * (1 - t) + other * t`.
+    pub fn lerp(&self, other: &Self, t: f32) -> Self {
+        self.zip_map(other, |a, b| a * (1.0 - t) + b * t)
+    }
+
+    /// Linearly interpolates towards `other` with a per-component factor, as GLSL `mix`.
+    ///
+    /// # Parameters
+    /// - `other`: The target vector.
+    /// - `a`: The interpolation factors, one per component.
+    ///
+    /// # Returns
+    /// The interpolated vector `self[m] * (1 - a[m]) + other[m] * a[m]`.
+    pub fn mix(&self, other: &Self, a: &Self) -> Self {
+        let mut result = Self::zeros();
+        for m in 0..M {
+            result[m] = self[m] * (1.0 - a[m]) + other[m] * a[m];
+        }
+        result
+    }
+
+    /// Compares every component against `edge`, as GLSL `step(edge, self)`.
+    ///
+    /// # Parameters
+    /// - `edge`: The edge of the step function.
+    ///
+    /// # Returns
+    /// A new vector holding `0.0` where `self[m] < edge[m]` and `1.0` otherwise.
+    pub fn step(&self, edge: &Self) -> Self {
+        self.zip_map(edge, |x, edge| if x < edge { 0.0 } else { 1.0 })
+    }
+
+    /// Performs Hermite interpolation between two edges, as GLSL `smoothstep(edge0, edge1, self)`.
+    ///
+    /// # Parameters
+    /// - `edge0`: The lower edge.
+    /// - `edge1`: The upper edge.
+    ///
+    /// # Returns
+    /// A new vector holding `t * t * (3 - 2 * t)`, where
+    /// `t = clamp((self - edge0) / (edge1 - edge0), 0, 1)`.
+    pub fn smoothstep(&self, edge0: &Self, edge1: &Self) -> Self {
+        let mut result = Self::zeros();
+        for m in 0..M {
+            let t = ((self[m] - edge0[m]) / (edge1[m] - edge0[m])).clamp(0.0, 1.0);
+            result[m] = t * t * (3.0 - 2.0 * t);
+        }
+        result
+    }
+
+    /// Returns the smallest component.
+    ///
+    /// # Returns
+    /// The minimum over all components.
+    pub fn min_element(&self) -> f32 {
+        self.column(0).into_iter().fold(f32::INFINITY, f32::min)
+    }
+
+    /// Returns the largest component.
+    ///
+    /// # Returns
+    /// The maximum over all components.
+    pub fn max_element(&self) -> f32 {
+        self.column(0).into_iter().fold(f32::NEG_INFINITY, f32::max)
+    }
+}
+
+// Product ----------------------------------------------------------------------------------------
+impl<const M: usize> Product for Vector<M> {
+    fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::ones(), |acc, v| acc.component_mul(&v))
+    }
+}
+
+impl<'a, const M: usize> Product<&'a Vector<M>> for Vector<M> {
+    fn product<I: Iterator<Item = &'a Vector<M>>>(iter: I) -> Self {
+        iter.fold(Self::ones(), |acc, v| acc.component_mul(v))
+    }
+}
+// Product ----------------------------------------------------------------------------------------
+
+macro_rules! component_wise {
+    ($vec:ident, $m:literal) => {
+        impl $vec {
+            /// Computes the component-wise (Hadamard) product.
+            ///
+            /// # Parameters
+            /// - `other`: The other vector.
+            ///
+            /// # Returns
+            /// A new vector holding the products of each pair of components.
+            pub fn component_mul(&self, other: &$vec) -> $vec {
+                Self::from(Vector::<$m>::from(*self).component_mul(&Vector::from(*other)))
+            }
+
+            /// Computes the component-wise quotient.
+            ///
+            /// # Parameters
+            /// - `other`: The divisor vector.
+            ///
+            /// # Returns
+            /// A new vector holding the quotients of each pair of components.
+            pub fn component_div(&self, other: &$vec) -> $vec {
+                Self::from(Vector::<$m>::from(*self).component_div(&Vector::from(*other)))
+            }
+
+            /// Computes the component-wise minimum.
+            ///
+            /// # Parameters
+            /// - `other`: The other vector.
+            ///
+            /// # Returns
+            /// A new vector holding the smaller of each pair of components.
+            pub fn min(&self, other: &$vec) -> $vec {
+                Self::from(Vector::<$m>::from(*self).min(&Vector::from(*other)))
+            }
+
+            /// Computes the component-wise maximum.
+            ///
+            /// # Parameters
+            /// - `other`: The other vector.
+            ///
+            /// # Returns
+            /// A new vector holding the larger of each pair of components.
+            pub fn max(&self, other: &$vec) -> $vec {
+                Self::from(Vector::<$m>::from(*self).max(&Vector::from(*other)))
+            }
+
+            /// Clamps every component between the matching components of `min` and `max`, as GLSL
+            /// `clamp`.
+            ///
+            /// # Parameters
+            /// - `min`: The lower bounds.
+            /// - `max`: The upper bounds.
+            ///
+            /// # Returns
+            /// The clamped vector.
+            pub fn clamp(&self, min: &$vec, max: &$vec) -> $vec {
+                Self::from(
+                    Vector::<$m>::from(*self).clamp(&Vector::from(*min), &Vector::from(*max)),
+                )
+            }
+
+            /// Returns the absolute value of every component.
+            ///
+            /// # Returns
+            /// A new vector with non-negative components.
+            pub fn abs(&self) -> $vec {
+                Self::from(Vector::<$m>::from(*self).abs())
+            }
+
+            /// Rounds every component down to the nearest integer.
+            ///
+            /// # Returns
+            /// The rounded vector.
+            pub fn floor(&self) -> $vec {
+                Self::from(Vector::<$m>::from(*self).floor())
+            }
+
+            /// Rounds every component up to the nearest integer.
+            ///
+            /// # Returns
+            /// The rounded vector.
+            pub fn ceil(&self) -> $vec {
+                Self::from(Vector::<$m>::from(*self).ceil())
+            }
+
+            /// Rounds every component to the nearest integer, with halfway cases rounded away from
+            /// zero.
+            ///
+            /// # Returns
+            /// The rounded vector.
+            pub fn round(&self) -> $vec {
+                Self::from(Vector::<$m>::from(*self).round())
+            }
+
+            /// Returns the fractional part of every component, as GLSL `fract`: `x - floor(x)`.
+            ///
+            /// # Returns
+            /// A new vector with components in `[0, 1)`.
+            pub fn fract(&self) -> $vec {
+                Self::from(Vector::<$m>::from(*self).fract())
+            }
+
+            /// Returns the sign of every component, as GLSL `sign` (zero maps to zero).
+            ///
+            /// # Returns
+            /// A new vector holding `1.0`, `-1.0` or `0.0` per component.
+            pub fn signum(&self) -> $vec {
+                Self::from(Vector::<$m>::from(*self).signum())
+            }
+
+            /// Returns the reciprocal of every component.
+            ///
+            /// # Returns
+            /// A new vector holding `1.0 / x` per component.
+            pub fn recip(&self) -> $vec {
+                Self::from(Vector::<$m>::from(*self).recip())
+            }
+
+            /// Linearly interpolates towards `other` by a scalar factor.
+            ///
+            /// # Parameters
+            /// - `other`: The target vector.
+            /// - `t`: The interpolation factor; `0.0` returns `self`, `1.0` returns `other`.
+            ///
+            /// # Returns
+            /// The interpolated vector.
+            pub fn lerp(&self, other: &$vec, t: f32) -> $vec {
+                Self::from(Vector::<$m>::from(*self).lerp(&Vector::from(*other), t))
+            }
+
+            /// Linearly interpolates towards `other` with a per-component factor, as GLSL `mix`.
+            ///
+            /// # Parameters
+            /// - `other`: The target vector.
+            /// - `a`: The interpolation factors, one per component.
+            ///
+            /// # Returns
+            /// The interpolated vector.
+            pub fn mix(&self, other: &$vec, a: &$vec) -> $vec {
+                Self::from(Vector::<$m>::from(*self).mix(&Vector::from(*other), &Vector::from(*a)))
+            }
+
+            /// Compares every component against `edge`, as GLSL `step(edge, self)`.
+            ///
+            /// # Parameters
+            /// - `edge`: The edge of the step function.
+            ///
+            /// # Returns
+            /// A new vector holding `0.0` where the component is below the edge and `1.0`
+            /// otherwise.
+            pub fn step(&self, edge: &$vec) -> $vec {
+                Self::from(Vector::<$m>::from(*self).step(&Vector::from(*edge)))
+            }
+
+            /// Performs Hermite interpolation between two edges, as GLSL
+            /// `smoothstep(edge0, edge1, self)`.
+            ///
+            /// # Parameters
+            /// - `edge0`: The lower edge.
+            /// - `edge1`: The upper edge.
+            ///
+            /// # Returns
+            /// A new vector with components smoothly mapped to `[0, 1]`.
+            pub fn smoothstep(&self, edge0: &$vec, edge1: &$vec) -> $vec {
+                Self::from(
+                    Vector::<$m>::from(*self)
+                        .smoothstep(&Vector::from(*edge0), &Vector::from(*edge1)),
+                )
+            }
+
+            /// Returns the smallest component.
+            ///
+            /// # Returns
+            /// The minimum over all components.
+            pub fn min_element(&self) -> f32 {
+                Vector::<$m>::from(*self).min_element()
+            }
+
+            /// Returns the largest component.
+            ///
+            /// # Returns
+            /// The maximum over all components.
+            pub fn max_element(&self) -> f32 {
+                Vector::<$m>::from(*self).max_element()
+            }
+        }
+    };
+}
+
+component_wise!(Vec2, 2);
+component_wise!(Vec3, 3);
+component_wise!(Vec4, 4);
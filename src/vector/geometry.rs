@@ -0,0 +1,307 @@
+//! Geometric vector helpers: reflection, refraction, projection, angles and distances.
+
+use crate::vector::{Vector, vec2::Vec2, vec3::Vec3, vec4::Vec4};
+
+impl<const M: usize> Vector<M> {
+    /// Computes the squared Euclidean distance to another point.
+    ///
+    /// # Parameters
+    /// - `other`: The other point.
+    ///
+    /// # Returns
+    /// The squared distance as a `f32`.
+    pub fn distance_squared(&self, other: &Self) -> f32 {
+        let delta = self - other;
+        delta.dot(&delta)
+    }
+
+    /// Computes the Euclidean distance to another point.
+    ///
+    /// # Parameters
+    /// - `other`: The other point.
+    ///
+    /// # Returns
+    /// The distance as a `f32`.
+    pub fn distance(&self, other: &Self) -> f32 {
+        self.distance_squared(other).sqrt()
+    }
+
+    /// Reflects this incident vector about a surface normal, as GLSL `reflect`.
+    ///
+    /// # Parameters
+    /// - `normal`: The surface normal; it should be normalized.
+    ///
+    /// # Returns
+    /// The reflected vector `self - 2 * dot(normal, self) * normal`.
+    pub fn reflect(&self, normal: &Self) -> Self {
+        self - normal * (2.0 * normal.dot(self))
+    }
+
+    /// Refracts this incident vector through a surface, as GLSL `refract`.
+    ///
+    /// # Parameters
+    /// - `normal`: The surface normal; it should be normalized and face against `self`.
+    /// - `eta`: The ratio of indices of refraction.
+    ///
+    /// # Returns
+    /// `Some(refracted)`, or `None` on total internal reflection.
+    pub fn refract(&self, normal: &Self, eta: f32) -> Option<Self> {
+        let n_dot_i = normal.dot(self);
+        let k = 1.0 - eta * eta * (1.0 - n_dot_i * n_dot_i);
+        if k < 0.0 {
+            return None;
+        }
+        Some(self * eta - normal * (eta * n_dot_i + k.sqrt()))
+    }
+
+    /// Projects this vector onto another vector.
+    ///
+    /// # Parameters
+    /// - `other`: The vector to project onto; it does not need to be normalized.
+    ///
+    /// # Returns
+    /// The component of `self` parallel to `other`, or a zero vector if `other` is zero.
+    pub fn project_onto(&self, other: &Self) -> Self {
+        let other_sq = other.dot(other);
+        if other_sq <= f32::EPSILON {
+            return Self::zeros();
+        }
+        other * (self.dot(other) / other_sq)
+    }
+
+    /// Rejects this vector from another vector.
+    ///
+    /// # Parameters
+    /// - `other`: The vector to reject from; it does not need to be normalized.
+    ///
+    /// # Returns
+    /// The component of `self` perpendicular to `other`.
+    pub fn reject_from(&self, other: &Self) -> Self {
+        self - self.project_onto(other)
+    }
+
+    /// Computes the angle between this vector and another vector.
+    ///
+    /// Uses Kahan's `atan2` formulation, which stays accurate for nearly parallel and nearly
+    /// antiparallel vectors where `acos` of the normalized dot product loses precision.
+    ///
+    /// # Parameters
+    /// - `other`: The other vector.
+    ///
+    /// # Returns
+    /// The unsigned angle in radians, in `[0, π]`.
+    pub fn angle_between(&self, other: &Self) -> f32 {
+        let a = self * other.magnitude();
+        let b = other * self.magnitude();
+        2.0 * (a - b).magnitude().atan2((a + b).magnitude())
+    }
+
+    /// Spherically interpolates between two direction vectors.
+    ///
+    /// The direction rotates at constant angular velocity while the magnitude is interpolated
+    /// linearly. Antiparallel inputs rotate through an arbitrary perpendicular direction. A zero
+    /// vector has no direction to rotate from or to, so if either input is zero the vectors are
+    /// interpolated linearly instead.
+    ///
+    /// # Parameters
+    /// - `other`: The target vector.
+    /// - `t`: Interpolation factor in [0, 1].
+    ///
+    /// # Returns
+    /// The interpolated vector.
+    pub fn slerp(&self, other: &Self, t: f32) -> Self {
+        let from_mag = self.magnitude();
+        let to_mag = other.magnitude();
+        if from_mag == 0.0 || to_mag == 0.0 {
+            return self.lerp(other, t);
+        }
+        let magnitude = from_mag + (to_mag - from_mag) * t;
+        let from = self.normalize();
+        let to = other.normalize();
+
+        let theta = self.angle_between(other);
+        let sin_theta = theta.sin();
+        if theta < 1e-4 {
+            // Nearly parallel: normalized lerp is accurate and avoids dividing by sin(theta).
+            return from.lerp(&to, t).normalize() * magnitude;
+        }
+        if sin_theta < 1e-4 {
+            // Nearly antiparallel: any perpendicular direction is a valid great circle.
+            let perpendicular = from.any_orthogonal();
+            let angle = std::f32::consts::PI * t;
+            return (from * angle.cos() + perpendicular * angle.sin()) * magnitude;
+        }
+        let scale_a = ((1.0 - t) * theta).sin() / sin_theta;
+        let scale_b = (t * theta).sin() / sin_theta;
+        (from * scale_a + to * scale_b) * magnitude
+    }
+
+    /// Returns a unit vector orthogonal to this vector, built by Gram-Schmidt against the
+    /// coordinate axis this vector is least aligned with.
+    fn any_orthogonal(&self) -> Self {
+        let mut axis = 0;
+        for m in 1..M {
+            if self[m].abs() < self[axis].abs() {
+                axis = m;
+            }
+        }
+        let mut basis = Self::zeros();
+        basis[axis] = 1.0;
+        basis.reject_from(self).normalize()
+    }
+}
+
+impl Vector<2> {
+    /// Returns this vector rotated by 90 degrees counter-clockwise.
+    ///
+    /// # Returns
+    /// The perpendicular vector `(-y, x)`.
+    pub fn perp(&self) -> Vector<2> {
+        Vector::from([-self[1], self[0]])
+    }
+}
+
+impl Vector<3> {
+    /// Builds two unit vectors that, together with this normalized vector, form a right-handed
+    /// orthonormal basis.
+    ///
+    /// Uses the branchless construction of Duff et al. (2017), which improves on Frisvad's
+    /// method near the negative Z pole.
+    ///
+    /// # Returns
+    /// A tuple `(b1, b2)` such that `(b1, b2, self)` is orthonormal and `b1.cross(&b2) == self`.
+    pub fn any_orthonormal_basis(&self) -> (Vector<3>, Vector<3>) {
+        let (x, y, z) = (self[0], self[1], self[2]);
+        let sign = 1.0f32.copysign(z);
+        let a = -1.0 / (sign + z);
+        let b = x * y * a;
+        (
+            Vector::from([1.0 + sign * x * x * a, sign * b, -sign * x]),
+            Vector::from([b, sign + y * y * a, -y]),
+        )
+    }
+}
+
+macro_rules! geometry {
+    ($vec:ident, $m:literal) => {
+        impl $vec {
+            /// Computes the squared Euclidean distance to another point.
+            ///
+            /// # Parameters
+            /// - `other`: The other point.
+            ///
+            /// # Returns
+            /// The squared distance as a `f32`.
+            pub fn distance_squared(&self, other: &$vec) -> f32 {
+                Vector::<$m>::from(*self).distance_squared(&Vector::from(*other))
+            }
+
+            /// Computes the Euclidean distance to another point.
+            ///
+            /// # Parameters
+            /// - `other`: The other point.
+            ///
+            /// # Returns
+            /// The distance as a `f32`.
+            pub fn distance(&self, other: &$vec) -> f32 {
+                Vector::<$m>::from(*self).distance(&Vector::from(*other))
+            }
+
+            /// Reflects this incident vector about a surface normal, as GLSL `reflect`.
+            ///
+            /// # Parameters
+            /// - `normal`: The surface normal; it should be normalized.
+            ///
+            /// # Returns
+            /// The reflected vector.
+            pub fn reflect(&self, normal: &$vec) -> $vec {
+                Self::from(Vector::<$m>::from(*self).reflect(&Vector::from(*normal)))
+            }
+
+            /// Refracts this incident vector through a surface, as GLSL `refract`.
+            ///
+            /// # Parameters
+            /// - `normal`: The surface normal; it should be normalized and face against `self`.
+            /// - `eta`: The ratio of indices of refraction.
+            ///
+            /// # Returns
+            /// `Some(refracted)`, or `None` on total internal reflection.
+            pub fn refract(&self, normal: &$vec, eta: f32) -> Option<$vec> {
+                Vector::<$m>::from(*self)
+                    .refract(&Vector::from(*normal), eta)
+                    .map(Self::from)
+            }
+
+            /// Projects this vector onto another vector.
+            ///
+            /// # Parameters
+            /// - `other`: The vector to project onto; it does not need to be normalized.
+            ///
+            /// # Returns
+            /// The component of `self` parallel to `other`.
+            pub fn project_onto(&self, other: &$vec) -> $vec {
+                Self::from(Vector::<$m>::from(*self).project_onto(&Vector::from(*other)))
+            }
+
+            /// Rejects this vector from another vector.
+            ///
+            /// # Parameters
+            /// - `other`: The vector to reject from; it does not need to be normalized.
+            ///
+            /// # Returns
+            /// The component of `self` perpendicular to `other`.
+            pub fn reject_from(&self, other: &$vec) -> $vec {
+                Self::from(Vector::<$m>::from(*self).reject_from(&Vector::from(*other)))
+            }
+
+            /// Computes the angle between this vector and another vector.
+            ///
+            /// # Parameters
+            /// - `other`: The other vector.
+            ///
+            /// # Returns
+            /// The unsigned angle in radians, in `[0, π]`.
+            pub fn angle_between(&self, other: &$vec) -> f32 {
+                Vector::<$m>::from(*self).angle_between(&Vector::from(*other))
+            }
+
+            /// Spherically interpolates between two direction vectors.
+            ///
+            /// # Parameters
+            /// - `other`: The target vector.
+            /// - `t`: Interpolation factor in [0, 1].
+            ///
+            /// # Returns
+            /// The interpolated vector.
+            pub fn slerp(&self, other: &$vec, t: f32) -> $vec {
+                Self::from(Vector::<$m>::from(*self).slerp(&Vector::from(*other), t))
+            }
+        }
+    };
+}
+
+geometry!(Vec2, 2);
+geometry!(Vec3, 3);
+geometry!(Vec4, 4);
+
+impl Vec2 {
+    /// Returns this vector rotated by 90 degrees counter-clockwise.
+    ///
+    /// # Returns
+    /// The perpendicular vector `(-y, x)`.
+    pub fn perp(&self) -> Vec2 {
+        Vec2::new(-self.y, self.x)
+    }
+}
+
+impl Vec3 {
+    /// Builds two unit vectors that, together with this normalized vector, form a right-handed
+    /// orthonormal basis (Frisvad's construction as revised by Duff et al.).
+    ///
+    /// # Returns
+    /// A tuple `(b1, b2)` such that `(b1, b2, self)` is orthonormal and `b1.cross(&b2) == self`.
+    pub fn any_orthonormal_basis(&self) -> (Vec3, Vec3) {
+        let (b1, b2) = Vector::<3>::from(*self).any_orthonormal_basis();
+        (b1.into(), b2.into())
+    }
+}
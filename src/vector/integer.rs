@@ -0,0 +1,308 @@
+//! Shared implementation of the integer vector families ([`IVec2`], [`UVec2`], ...).
+//!
+//! [`IVec2`]: crate::vector::ivec::IVec2
+//! [`UVec2`]: crate::vector::uvec::UVec2
+
+/// Defines an integer vector type with arithmetic, wrapping and saturating operations, and
+/// checked conversions to and from its `f32` counterpart.
+macro_rules! integer_vector {
+    (
+        $(#[$doc:meta])*
+        $name:ident($t:ty, $n:literal, [$($c:ident),+]),
+        mask: $mask:ident,
+        float: $vec:ident($as_vec:ident, $try_as_vec:ident, $from_vec:ident, $try_from_vec:ident),
+        axes: { $($axis:ident => [$($v:expr),+]),+ $(,)? } $(,)?
+    ) => {
+        $(#[$doc])*
+        #[repr(C)]
+        #[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+        pub struct $name {
+            $(pub $c: $t,)+
+        }
+
+        impl $name {
+            /// A vector with all components set to zero.
+            pub const ZERO: Self = Self::splat(0);
+
+            /// A vector with all components set to one.
+            pub const ONE: Self = Self::splat(1);
+
+            #[doc = concat!("A vector with all components set to `", stringify!($t), "::MIN`.")]
+            pub const MIN: Self = Self::splat(<$t>::MIN);
+
+            #[doc = concat!("A vector with all components set to `", stringify!($t), "::MAX`.")]
+            pub const MAX: Self = Self::splat(<$t>::MAX);
+
+            $(
+                #[doc = concat!("The `", stringify!($axis), "` axis unit vector.")]
+                pub const $axis: Self = Self::new($($v),+);
+            )+
+
+            /// Creates a new vector from its components.
+            ///
+            /// # Returns
+            /// A new vector with the specified components.
+            pub const fn new($($c: $t),+) -> Self {
+                Self { $($c),+ }
+            }
+
+            /// Creates a vector with every component set to `value`.
+            ///
+            /// # Parameters
+            /// - `value`: The value of every component.
+            ///
+            /// # Returns
+            /// A new vector.
+            pub const fn splat(value: $t) -> Self {
+                Self { $($c: value),+ }
+            }
+
+            /// Returns the components as an array.
+            ///
+            /// # Returns
+            /// The components, in order.
+            pub const fn to_array(&self) -> [$t; $n] {
+                [$(self.$c),+]
+            }
+
+            /// Computes the dot product with another vector.
+            ///
+            /// # Parameters
+            /// - `other`: The other vector.
+            ///
+            /// # Returns
+            /// The dot product.
+            pub fn dot(&self, other: &$name) -> $t {
+                0 $(+ self.$c * other.$c)+
+            }
+
+            /// Computes the component-wise minimum.
+            ///
+            /// # Returns
+            /// A new vector holding the smaller of each pair of components.
+            pub fn min(&self, other: &$name) -> $name {
+                Self { $($c: self.$c.min(other.$c)),+ }
+            }
+
+            /// Computes the component-wise maximum.
+            ///
+            /// # Returns
+            /// A new vector holding the larger of each pair of components.
+            pub fn max(&self, other: &$name) -> $name {
+                Self { $($c: self.$c.max(other.$c)),+ }
+            }
+
+            /// Clamps every component between the matching components of `min` and `max`.
+            ///
+            /// # Returns
+            /// The clamped vector.
+            pub fn clamp(&self, min: &$name, max: &$name) -> $name {
+                self.max(min).min(max)
+            }
+
+            /// Returns the smallest component.
+            pub fn min_element(&self) -> $t {
+                <$t>::MAX $(.min(self.$c))+
+            }
+
+            /// Returns the largest component.
+            pub fn max_element(&self) -> $t {
+                <$t>::MIN $(.max(self.$c))+
+            }
+
+            /// Adds component-wise, wrapping around on overflow.
+            pub fn wrapping_add(&self, other: &$name) -> $name {
+                Self { $($c: self.$c.wrapping_add(other.$c)),+ }
+            }
+
+            /// Subtracts component-wise, wrapping around on overflow.
+            pub fn wrapping_sub(&self, other: &$name) -> $name {
+                Self { $($c: self.$c.wrapping_sub(other.$c)),+ }
+            }
+
+            /// Multiplies component-wise, wrapping around on overflow.
+            pub fn wrapping_mul(&self, other: &$name) -> $name {
+                Self { $($c: self.$c.wrapping_mul(other.$c)),+ }
+            }
+
+            /// Adds component-wise, saturating at the numeric bounds.
+            pub fn saturating_add(&self, other: &$name) -> $name {
+                Self { $($c: self.$c.saturating_add(other.$c)),+ }
+            }
+
+            /// Subtracts component-wise, saturating at the numeric bounds.
+            pub fn saturating_sub(&self, other: &$name) -> $name {
+                Self { $($c: self.$c.saturating_sub(other.$c)),+ }
+            }
+
+            /// Multiplies component-wise, saturating at the numeric bounds.
+            pub fn saturating_mul(&self, other: &$name) -> $name {
+                Self { $($c: self.$c.saturating_mul(other.$c)),+ }
+            }
+
+            /// Adds component-wise, returning `None` if any component overflows.
+            pub fn checked_add(&self, other: &$name) -> Option<$name> {
+                Some(Self { $($c: self.$c.checked_add(other.$c)?),+ })
+            }
+
+            /// Subtracts component-wise, returning `None` if any component overflows.
+            pub fn checked_sub(&self, other: &$name) -> Option<$name> {
+                Some(Self { $($c: self.$c.checked_sub(other.$c)?),+ })
+            }
+
+            #[doc = concat!(
+                "Converts to [`", stringify!($vec), "`], ",
+                "rounding values that `f32` cannot represent exactly."
+            )]
+            pub fn $as_vec(&self) -> $vec {
+                $vec::new($(self.$c as f32),+)
+            }
+
+            #[doc = concat!(
+                "Converts to [`", stringify!($vec), "`] ",
+                "if every component is exactly representable as `f32`."
+            )]
+            ///
+            /// # Returns
+            /// `Some(vector)`, or `None` if a component would be rounded.
+            pub fn $try_as_vec(&self) -> Option<$vec> {
+                if true $(&& (self.$c as f32) as i64 == self.$c as i64)+ {
+                    Some(self.$as_vec())
+                } else {
+                    None
+                }
+            }
+        }
+
+        impl $vec {
+            #[doc = concat!(
+                "Converts to [`", stringify!($name), "`], ",
+                "truncating towards zero and saturating at the numeric bounds (`NaN` becomes zero)."
+            )]
+            pub fn $from_vec(&self) -> $name {
+                $name::new($(self.$c as $t),+)
+            }
+
+            #[doc = concat!("Converts to [`", stringify!($name), "`], truncating towards zero.")]
+            ///
+            /// # Returns
+            /// `Some(vector)`, or `None` if a component is `NaN`, infinite or out of range.
+            pub fn $try_from_vec(&self) -> Option<$name> {
+                // `MAX as f32` rounds up to the next power of two, which is an exclusive bound.
+                let in_range = |value: f32| {
+                    value.trunc() >= <$t>::MIN as f32 && value.trunc() < <$t>::MAX as f32
+                };
+                if true $(&& in_range(self.$c))+ {
+                    Some(self.$from_vec())
+                } else {
+                    None
+                }
+            }
+        }
+
+        impl From<[$t; $n]> for $name {
+            fn from(array: [$t; $n]) -> Self {
+                let [$($c),+] = array;
+                Self { $($c),+ }
+            }
+        }
+
+        impl From<$name> for [$t; $n] {
+            fn from(vector: $name) -> Self {
+                vector.to_array()
+            }
+        }
+
+        crate::vector::integer::integer_vector!(
+            @op $name, $t, [$($c),+], Add, add, AddAssign, add_assign, +
+        );
+        crate::vector::integer::integer_vector!(
+            @op $name, $t, [$($c),+], Sub, sub, SubAssign, sub_assign, -
+        );
+        crate::vector::integer::integer_vector!(
+            @op $name, $t, [$($c),+], Mul, mul, MulAssign, mul_assign, *
+        );
+        crate::vector::integer::integer_vector!(
+            @op $name, $t, [$($c),+], Div, div, DivAssign, div_assign, /
+        );
+        crate::vector::integer::integer_vector!(
+            @op $name, $t, [$($c),+], Rem, rem, RemAssign, rem_assign, %
+        );
+
+        crate::vector::bvec::comparisons!($name, $mask, [$($c),+]);
+    };
+
+    (
+        @op $name:ident, $t:ty, [$($c:ident),+],
+        $op:ident, $fn:ident, $op_assign:ident, $fn_assign:ident, $sym:tt
+    ) => {
+        impl std::ops::$op<$name> for $name {
+            type Output = $name;
+
+            fn $fn(self, rhs: $name) -> Self::Output {
+                $name { $($c: self.$c $sym rhs.$c),+ }
+            }
+        }
+
+        impl std::ops::$op<$t> for $name {
+            type Output = $name;
+
+            fn $fn(self, rhs: $t) -> Self::Output {
+                $name { $($c: self.$c $sym rhs),+ }
+            }
+        }
+
+        impl std::ops::$op_assign<$name> for $name {
+            fn $fn_assign(&mut self, rhs: $name) {
+                *self = *self $sym rhs;
+            }
+        }
+
+        impl std::ops::$op_assign<$t> for $name {
+            fn $fn_assign(&mut self, rhs: $t) {
+                *self = *self $sym rhs;
+            }
+        }
+    };
+}
+
+/// Adds the sign-dependent operations of a signed integer vector.
+macro_rules! signed_integer_vector {
+    ($name:ident, [$($c:ident),+], axes: { $($axis:ident => [$($v:expr),+]),+ $(,)? } $(,)?) => {
+        impl $name {
+            /// A vector with all components set to negative one.
+            pub const NEG_ONE: Self = Self::splat(-1);
+
+            $(
+                #[doc = concat!("The `", stringify!($axis), "` axis unit vector.")]
+                pub const $axis: Self = Self::new($($v),+);
+            )+
+
+            /// Returns the absolute value of every component.
+            pub fn abs(&self) -> $name {
+                Self { $($c: self.$c.abs()),+ }
+            }
+
+            /// Returns the sign of every component: `1`, `-1` or `0`.
+            pub fn signum(&self) -> $name {
+                Self { $($c: self.$c.signum()),+ }
+            }
+
+            /// Negates every component, wrapping around on overflow.
+            pub fn wrapping_neg(&self) -> $name {
+                Self { $($c: self.$c.wrapping_neg()),+ }
+            }
+        }
+
+        impl std::ops::Neg for $name {
+            type Output = $name;
+
+            fn neg(self) -> Self::Output {
+                Self { $($c: -self.$c),+ }
+            }
+        }
+    };
+}
+
+pub(crate) use integer_vector;
+pub(crate) use signed_integer_vector;
@@ -0,0 +1,81 @@
+//! Signed integer vectors, for tile coordinates, texel offsets and other lattice quantities.
+//!
+//! # Example
+//! ```
+//! use vectorama::{IVec2, Vec2};
+//! let tile = Vec2::new(3.7, -1.2).try_as_ivec2().unwrap();
+//! assert_eq!(tile, IVec2::new(3, -1));
+//! assert_eq!(tile + IVec2::X, IVec2::new(4, -1));
+//! ```
+
+use crate::vector::{
+    bvec::{BVec2, BVec3, BVec4},
+    integer::{integer_vector, signed_integer_vector},
+    uvec::{UVec2, UVec3, UVec4},
+    vec2::Vec2,
+    vec3::Vec3,
+    vec4::Vec4,
+};
+
+integer_vector!(
+    /// A 2D vector with `i32` components.
+    IVec2(i32, 2, [x, y]),
+    mask: BVec2,
+    float: Vec2(as_vec2, try_as_vec2, as_ivec2, try_as_ivec2),
+    axes: { X => [1, 0], Y => [0, 1] },
+);
+integer_vector!(
+    /// A 3D vector with `i32` components.
+    IVec3(i32, 3, [x, y, z]),
+    mask: BVec3,
+    float: Vec3(as_vec3, try_as_vec3, as_ivec3, try_as_ivec3),
+    axes: { X => [1, 0, 0], Y => [0, 1, 0], Z => [0, 0, 1] },
+);
+integer_vector!(
+    /// A 4D vector with `i32` components.
+    IVec4(i32, 4, [x, y, z, w]),
+    mask: BVec4,
+    float: Vec4(as_vec4, try_as_vec4, as_ivec4, try_as_ivec4),
+    axes: { X => [1, 0, 0, 0], Y => [0, 1, 0, 0], Z => [0, 0, 1, 0], W => [0, 0, 0, 1] },
+);
+
+signed_integer_vector!(IVec2, [x, y], axes: { NEG_X => [-1, 0], NEG_Y => [0, -1] });
+signed_integer_vector!(
+    IVec3,
+    [x, y, z],
+    axes: { NEG_X => [-1, 0, 0], NEG_Y => [0, -1, 0], NEG_Z => [0, 0, -1] },
+);
+signed_integer_vector!(
+    IVec4,
+    [x, y, z, w],
+    axes: {
+        NEG_X => [-1, 0, 0, 0],
+        NEG_Y => [0, -1, 0, 0],
+        NEG_Z => [0, 0, -1, 0],
+        NEG_W => [0, 0, 0, -1],
+    },
+);
+
+macro_rules! unsigned_conversions {
+    ($ivec:ident, $uvec:ident, $as_uvec:ident, $as_ivec:ident, [$($c:ident),+]) => {
+        impl $ivec {
+            #[doc = concat!("Converts to [`", stringify!($uvec), "`] if no component is negative.")]
+            pub fn $as_uvec(&self) -> Option<$uvec> {
+                Some($uvec::new($(u32::try_from(self.$c).ok()?),+))
+            }
+        }
+
+        impl $uvec {
+            #[doc = concat!(
+                "Converts to [`", stringify!($ivec), "`] if every component fits in an `i32`."
+            )]
+            pub fn $as_ivec(&self) -> Option<$ivec> {
+                Some($ivec::new($(i32::try_from(self.$c).ok()?),+))
+            }
+        }
+    };
+}
+
+unsigned_conversions!(IVec2, UVec2, try_as_uvec2, try_as_ivec2, [x, y]);
+unsigned_conversions!(IVec3, UVec3, try_as_uvec3, try_as_ivec3, [x, y, z]);
+unsigned_conversions!(IVec4, UVec4, try_as_uvec4, try_as_ivec4, [x, y, z, w]);
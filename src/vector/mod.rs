@@ -1,5 +1,12 @@
 use std::ops::{Index, IndexMut};
 
+pub mod bvec;
+mod component;
+mod geometry;
+mod integer;
+pub mod ivec;
+mod swizzle;
+pub mod uvec;
 pub mod vec2;
 pub mod vec3;
 pub mod vec4;
@@ -0,0 +1,688 @@
+//! GLSL-style swizzles for [`Vec2`], [`Vec3`] and [`Vec4`].
+//!
+//! Every read swizzle of two to four components is available under its `xyzw` name as well as
+//! the `rgba` and `stpq` aliases, e.g. `v.zyx()`, `v.bgr()` and `v.pts()` are equivalent. Write
+//! swizzles (`set_xz`, `set_rb`, `set_sp`, ...) exist for every combination of distinct
+//! components.
+//!
+//! # Example
+//! ```
+//! use vectorama::{Vec2, Vec4};
+//! let mut v = Vec4::new(1.0, 2.0, 3.0, 4.0);
+//! assert_eq!(v.wzyx().as_array(), &[4.0, 3.0, 2.0, 1.0]);
+//! assert_eq!(v.rgb().as_array(), v.xyz().as_array());
+//! v.set_xz(Vec2::new(5.0, 6.0));
+//! assert_eq!(v.as_array(), &[5.0, 2.0, 6.0, 4.0]);
+//! ```
+
+use crate::vector::{vec2::Vec2, vec3::Vec3, vec4::Vec4};
+
+macro_rules! component_index {
+    (x) => {
+        0
+    };
+    (y) => {
+        1
+    };
+    (z) => {
+        2
+    };
+    (w) => {
+        3
+    };
+}
+
+macro_rules! swizzle {
+    ($vec:ident { $($name:ident, $rgba:ident, $stpq:ident => $out:ident [$($c:ident),+];)* }) => {
+        impl $vec {
+            $(
+                #[doc = concat!(
+                    "Returns the `", stringify!($name), "` swizzle as a `", stringify!($out), "`.",
+                )]
+                pub const fn $name(&self) -> $out {
+                    let array = self.as_array();
+                    $out::new($(array[component_index!($c)]),+)
+                }
+
+                #[doc = concat!(
+                    "Returns the `", stringify!($rgba), "` swizzle, same as [`", stringify!($vec),
+                    "::", stringify!($name), "`].",
+                )]
+                pub const fn $rgba(&self) -> $out {
+                    self.$name()
+                }
+
+                #[doc = concat!(
+                    "Returns the `", stringify!($stpq), "` swizzle, same as [`", stringify!($vec),
+                    "::", stringify!($name), "`].",
+                )]
+                pub const fn $stpq(&self) -> $out {
+                    self.$name()
+                }
+            )*
+        }
+    };
+}
+
+macro_rules! swizzle_set {
+    ($vec:ident { $($name:ident, $rgba:ident, $stpq:ident => $input:ident [$($c:ident),+];)* }) => {
+        impl $vec {
+            $(
+                #[doc = concat!(
+                    "Writes the components of `value` into `", stringify!($name), "`, in order.",
+                )]
+                pub fn $name(&mut self, value: $input) {
+                    let [$($c),+] = *value.as_array();
+                    $(self.$c = $c;)+
+                }
+
+                #[doc = concat!(
+                    "Writes the components of `value` into `", stringify!($rgba), "`, same as [`",
+                    stringify!($vec), "::", stringify!($name), "`].",
+                )]
+                pub fn $rgba(&mut self, value: $input) {
+                    self.$name(value)
+                }
+
+                #[doc = concat!(
+                    "Writes the components of `value` into `", stringify!($stpq), "`, same as [`",
+                    stringify!($vec), "::", stringify!($name), "`].",
+                )]
+                pub fn $stpq(&mut self, value: $input) {
+                    self.$name(value)
+                }
+            )*
+        }
+    };
+}
+
+// Vec2 -------------------------------------------------------------------------------------------
+swizzle! {
+    Vec2 {
+        xx, rr, ss => Vec2 [x, x];
+        xy, rg, st => Vec2 [x, y];
+        yx, gr, ts => Vec2 [y, x];
+        yy, gg, tt => Vec2 [y, y];
+        xxx, rrr, sss => Vec3 [x, x, x];
+        xxy, rrg, sst => Vec3 [x, x, y];
+        xyx, rgr, sts => Vec3 [x, y, x];
+        xyy, rgg, stt => Vec3 [x, y, y];
+        yxx, grr, tss => Vec3 [y, x, x];
+        yxy, grg, tst => Vec3 [y, x, y];
+        yyx, ggr, tts => Vec3 [y, y, x];
+        yyy, ggg, ttt => Vec3 [y, y, y];
+        xxxx, rrrr, ssss => Vec4 [x, x, x, x];
+        xxxy, rrrg, ssst => Vec4 [x, x, x, y];
+        xxyx, rrgr, ssts => Vec4 [x, x, y, x];
+        xxyy, rrgg, sstt => Vec4 [x, x, y, y];
+        xyxx, rgrr, stss => Vec4 [x, y, x, x];
+        xyxy, rgrg, stst => Vec4 [x, y, x, y];
+        xyyx, rggr, stts => Vec4 [x, y, y, x];
+        xyyy, rggg, sttt => Vec4 [x, y, y, y];
+        yxxx, grrr, tsss => Vec4 [y, x, x, x];
+        yxxy, grrg, tsst => Vec4 [y, x, x, y];
+        yxyx, grgr, tsts => Vec4 [y, x, y, x];
+        yxyy, grgg, tstt => Vec4 [y, x, y, y];
+        yyxx, ggrr, ttss => Vec4 [y, y, x, x];
+        yyxy, ggrg, ttst => Vec4 [y, y, x, y];
+        yyyx, gggr, ttts => Vec4 [y, y, y, x];
+        yyyy, gggg, tttt => Vec4 [y, y, y, y];
+    }
+}
+
+swizzle_set! {
+    Vec2 {
+        set_xy, set_rg, set_st => Vec2 [x, y];
+        set_yx, set_gr, set_ts => Vec2 [y, x];
+    }
+}
+// Vec2 -------------------------------------------------------------------------------------------
+
+// Vec3 -------------------------------------------------------------------------------------------
+swizzle! {
+    Vec3 {
+        xx, rr, ss => Vec2 [x, x];
+        xy, rg, st => Vec2 [x, y];
+        xz, rb, sp => Vec2 [x, z];
+        yx, gr, ts => Vec2 [y, x];
+        yy, gg, tt => Vec2 [y, y];
+        yz, gb, tp => Vec2 [y, z];
+        zx, br, ps => Vec2 [z, x];
+        zy, bg, pt => Vec2 [z, y];
+        zz, bb, pp => Vec2 [z, z];
+        xxx, rrr, sss => Vec3 [x, x, x];
+        xxy, rrg, sst => Vec3 [x, x, y];
+        xxz, rrb, ssp => Vec3 [x, x, z];
+        xyx, rgr, sts => Vec3 [x, y, x];
+        xyy, rgg, stt => Vec3 [x, y, y];
+        xyz, rgb, stp => Vec3 [x, y, z];
+        xzx, rbr, sps => Vec3 [x, z, x];
+        xzy, rbg, spt => Vec3 [x, z, y];
+        xzz, rbb, spp => Vec3 [x, z, z];
+        yxx, grr, tss => Vec3 [y, x, x];
+        yxy, grg, tst => Vec3 [y, x, y];
+        yxz, grb, tsp => Vec3 [y, x, z];
+        yyx, ggr, tts => Vec3 [y, y, x];
+        yyy, ggg, ttt => Vec3 [y, y, y];
+        yyz, ggb, ttp => Vec3 [y, y, z];
+        yzx, gbr, tps => Vec3 [y, z, x];
+        yzy, gbg, tpt => Vec3 [y, z, y];
+        yzz, gbb, tpp => Vec3 [y, z, z];
+        zxx, brr, pss => Vec3 [z, x, x];
+        zxy, brg, pst => Vec3 [z, x, y];
+        zxz, brb, psp => Vec3 [z, x, z];
+        zyx, bgr, pts => Vec3 [z, y, x];
+        zyy, bgg, ptt => Vec3 [z, y, y];
+        zyz, bgb, ptp => Vec3 [z, y, z];
+        zzx, bbr, pps => Vec3 [z, z, x];
+        zzy, bbg, ppt => Vec3 [z, z, y];
+        zzz, bbb, ppp => Vec3 [z, z, z];
+        xxxx, rrrr, ssss => Vec4 [x, x, x, x];
+        xxxy, rrrg, ssst => Vec4 [x, x, x, y];
+        xxxz, rrrb, sssp => Vec4 [x, x, x, z];
+        xxyx, rrgr, ssts => Vec4 [x, x, y, x];
+        xxyy, rrgg, sstt => Vec4 [x, x, y, y];
+        xxyz, rrgb, sstp => Vec4 [x, x, y, z];
+        xxzx, rrbr, ssps => Vec4 [x, x, z, x];
+        xxzy, rrbg, sspt => Vec4 [x, x, z, y];
+        xxzz, rrbb, sspp => Vec4 [x, x, z, z];
+        xyxx, rgrr, stss => Vec4 [x, y, x, x];
+        xyxy, rgrg, stst => Vec4 [x, y, x, y];
+        xyxz, rgrb, stsp => Vec4 [x, y, x, z];
+        xyyx, rggr, stts => Vec4 [x, y, y, x];
+        xyyy, rggg, sttt => Vec4 [x, y, y, y];
+        xyyz, rggb, sttp => Vec4 [x, y, y, z];
+        xyzx, rgbr, stps => Vec4 [x, y, z, x];
+        xyzy, rgbg, stpt => Vec4 [x, y, z, y];
+        xyzz, rgbb, stpp => Vec4 [x, y, z, z];
+        xzxx, rbrr, spss => Vec4 [x, z, x, x];
+        xzxy, rbrg, spst => Vec4 [x, z, x, y];
+        xzxz, rbrb, spsp => Vec4 [x, z, x, z];
+        xzyx, rbgr, spts => Vec4 [x, z, y, x];
+        xzyy, rbgg, sptt => Vec4 [x, z, y, y];
+        xzyz, rbgb, sptp => Vec4 [x, z, y, z];
+        xzzx, rbbr, spps => Vec4 [x, z, z, x];
+        xzzy, rbbg, sppt => Vec4 [x, z, z, y];
+        xzzz, rbbb, sppp => Vec4 [x, z, z, z];
+        yxxx, grrr, tsss => Vec4 [y, x, x, x];
+        yxxy, grrg, tsst => Vec4 [y, x, x, y];
+        yxxz, grrb, tssp => Vec4 [y, x, x, z];
+        yxyx, grgr, tsts => Vec4 [y, x, y, x];
+        yxyy, grgg, tstt => Vec4 [y, x, y, y];
+        yxyz, grgb, tstp => Vec4 [y, x, y, z];
+        yxzx, grbr, tsps => Vec4 [y, x, z, x];
+        yxzy, grbg, tspt => Vec4 [y, x, z, y];
+        yxzz, grbb, tspp => Vec4 [y, x, z, z];
+        yyxx, ggrr, ttss => Vec4 [y, y, x, x];
+        yyxy, ggrg, ttst => Vec4 [y, y, x, y];
+        yyxz, ggrb, ttsp => Vec4 [y, y, x, z];
+        yyyx, gggr, ttts => Vec4 [y, y, y, x];
+        yyyy, gggg, tttt => Vec4 [y, y, y, y];
+        yyyz, gggb, tttp => Vec4 [y, y, y, z];
+        yyzx, ggbr, ttps => Vec4 [y, y, z, x];
+        yyzy, ggbg, ttpt => Vec4 [y, y, z, y];
+        yyzz, ggbb, ttpp => Vec4 [y, y, z, z];
+        yzxx, gbrr, tpss => Vec4 [y, z, x, x];
+        yzxy, gbrg, tpst => Vec4 [y, z, x, y];
+        yzxz, gbrb, tpsp => Vec4 [y, z, x, z];
+        yzyx, gbgr, tpts => Vec4 [y, z, y, x];
+        yzyy, gbgg, tptt => Vec4 [y, z, y, y];
+        yzyz, gbgb, tptp => Vec4 [y, z, y, z];
+        yzzx, gbbr, tpps => Vec4 [y, z, z, x];
+        yzzy, gbbg, tppt => Vec4 [y, z, z, y];
+        yzzz, gbbb, tppp => Vec4 [y, z, z, z];
+        zxxx, brrr, psss => Vec4 [z, x, x, x];
+        zxxy, brrg, psst => Vec4 [z, x, x, y];
+        zxxz, brrb, pssp => Vec4 [z, x, x, z];
+        zxyx, brgr, psts => Vec4 [z, x, y, x];
+        zxyy, brgg, pstt => Vec4 [z, x, y, y];
+        zxyz, brgb, pstp => Vec4 [z, x, y, z];
+        zxzx, brbr, psps => Vec4 [z, x, z, x];
+        zxzy, brbg, pspt => Vec4 [z, x, z, y];
+        zxzz, brbb, pspp => Vec4 [z, x, z, z];
+        zyxx, bgrr, ptss => Vec4 [z, y, x, x];
+        zyxy, bgrg, ptst => Vec4 [z, y, x, y];
+        zyxz, bgrb, ptsp => Vec4 [z, y, x, z];
+        zyyx, bggr, ptts => Vec4 [z, y, y, x];
+        zyyy, bggg, pttt => Vec4 [z, y, y, y];
+        zyyz, bggb, pttp => Vec4 [z, y, y, z];
+        zyzx, bgbr, ptps => Vec4 [z, y, z, x];
+        zyzy, bgbg, ptpt => Vec4 [z, y, z, y];
+        zyzz, bgbb, ptpp => Vec4 [z, y, z, z];
+        zzxx, bbrr, ppss => Vec4 [z, z, x, x];
+        zzxy, bbrg, ppst => Vec4 [z, z, x, y];
+        zzxz, bbrb, ppsp => Vec4 [z, z, x, z];
+        zzyx, bbgr, ppts => Vec4 [z, z, y, x];
+        zzyy, bbgg, pptt => Vec4 [z, z, y, y];
+        zzyz, bbgb, pptp => Vec4 [z, z, y, z];
+        zzzx, bbbr, ppps => Vec4 [z, z, z, x];
+        zzzy, bbbg, pppt => Vec4 [z, z, z, y];
+        zzzz, bbbb, pppp => Vec4 [z, z, z, z];
+    }
+}
+
+swizzle_set! {
+    Vec3 {
+        set_xy, set_rg, set_st => Vec2 [x, y];
+        set_xz, set_rb, set_sp => Vec2 [x, z];
+        set_yx, set_gr, set_ts => Vec2 [y, x];
+        set_yz, set_gb, set_tp => Vec2 [y, z];
+        set_zx, set_br, set_ps => Vec2 [z, x];
+        set_zy, set_bg, set_pt => Vec2 [z, y];
+        set_xyz, set_rgb, set_stp => Vec3 [x, y, z];
+        set_xzy, set_rbg, set_spt => Vec3 [x, z, y];
+        set_yxz, set_grb, set_tsp => Vec3 [y, x, z];
+        set_yzx, set_gbr, set_tps => Vec3 [y, z, x];
+        set_zxy, set_brg, set_pst => Vec3 [z, x, y];
+        set_zyx, set_bgr, set_pts => Vec3 [z, y, x];
+    }
+}
+// Vec3 -------------------------------------------------------------------------------------------
+
+// Vec4 -------------------------------------------------------------------------------------------
+swizzle! {
+    Vec4 {
+        xx, rr, ss => Vec2 [x, x];
+        xy, rg, st => Vec2 [x, y];
+        xz, rb, sp => Vec2 [x, z];
+        xw, ra, sq => Vec2 [x, w];
+        yx, gr, ts => Vec2 [y, x];
+        yy, gg, tt => Vec2 [y, y];
+        yz, gb, tp => Vec2 [y, z];
+        yw, ga, tq => Vec2 [y, w];
+        zx, br, ps => Vec2 [z, x];
+        zy, bg, pt => Vec2 [z, y];
+        zz, bb, pp => Vec2 [z, z];
+        zw, ba, pq => Vec2 [z, w];
+        wx, ar, qs => Vec2 [w, x];
+        wy, ag, qt => Vec2 [w, y];
+        wz, ab, qp => Vec2 [w, z];
+        ww, aa, qq => Vec2 [w, w];
+        xxx, rrr, sss => Vec3 [x, x, x];
+        xxy, rrg, sst => Vec3 [x, x, y];
+        xxz, rrb, ssp => Vec3 [x, x, z];
+        xxw, rra, ssq => Vec3 [x, x, w];
+        xyx, rgr, sts => Vec3 [x, y, x];
+        xyy, rgg, stt => Vec3 [x, y, y];
+        xyz, rgb, stp => Vec3 [x, y, z];
+        xyw, rga, stq => Vec3 [x, y, w];
+        xzx, rbr, sps => Vec3 [x, z, x];
+        xzy, rbg, spt => Vec3 [x, z, y];
+        xzz, rbb, spp => Vec3 [x, z, z];
+        xzw, rba, spq => Vec3 [x, z, w];
+        xwx, rar, sqs => Vec3 [x, w, x];
+        xwy, rag, sqt => Vec3 [x, w, y];
+        xwz, rab, sqp => Vec3 [x, w, z];
+        xww, raa, sqq => Vec3 [x, w, w];
+        yxx, grr, tss => Vec3 [y, x, x];
+        yxy, grg, tst => Vec3 [y, x, y];
+        yxz, grb, tsp => Vec3 [y, x, z];
+        yxw, gra, tsq => Vec3 [y, x, w];
+        yyx, ggr, tts => Vec3 [y, y, x];
+        yyy, ggg, ttt => Vec3 [y, y, y];
+        yyz, ggb, ttp => Vec3 [y, y, z];
+        yyw, gga, ttq => Vec3 [y, y, w];
+        yzx, gbr, tps => Vec3 [y, z, x];
+        yzy, gbg, tpt => Vec3 [y, z, y];
+        yzz, gbb, tpp => Vec3 [y, z, z];
+        yzw, gba, tpq => Vec3 [y, z, w];
+        ywx, gar, tqs => Vec3 [y, w, x];
+        ywy, gag, tqt => Vec3 [y, w, y];
+        ywz, gab, tqp => Vec3 [y, w, z];
+        yww, gaa, tqq => Vec3 [y, w, w];
+        zxx, brr, pss => Vec3 [z, x, x];
+        zxy, brg, pst => Vec3 [z, x, y];
+        zxz, brb, psp => Vec3 [z, x, z];
+        zxw, bra, psq => Vec3 [z, x, w];
+        zyx, bgr, pts => Vec3 [z, y, x];
+        zyy, bgg, ptt => Vec3 [z, y, y];
+        zyz, bgb, ptp => Vec3 [z, y, z];
+        zyw, bga, ptq => Vec3 [z, y, w];
+        zzx, bbr, pps => Vec3 [z, z, x];
+        zzy, bbg, ppt => Vec3 [z, z, y];
+        zzz, bbb, ppp => Vec3 [z, z, z];
+        zzw, bba, ppq => Vec3 [z, z, w];
+        zwx, bar, pqs => Vec3 [z, w, x];
+        zwy, bag, pqt => Vec3 [z, w, y];
+        zwz, bab, pqp => Vec3 [z, w, z];
+        zww, baa, pqq => Vec3 [z, w, w];
+        wxx, arr, qss => Vec3 [w, x, x];
+        wxy, arg, qst => Vec3 [w, x, y];
+        wxz, arb, qsp => Vec3 [w, x, z];
+        wxw, ara, qsq => Vec3 [w, x, w];
+        wyx, agr, qts => Vec3 [w, y, x];
+        wyy, agg, qtt => Vec3 [w, y, y];
+        wyz, agb, qtp => Vec3 [w, y, z];
+        wyw, aga, qtq => Vec3 [w, y, w];
+        wzx, abr, qps => Vec3 [w, z, x];
+        wzy, abg, qpt => Vec3 [w, z, y];
+        wzz, abb, qpp => Vec3 [w, z, z];
+        wzw, aba, qpq => Vec3 [w, z, w];
+        wwx, aar, qqs => Vec3 [w, w, x];
+        wwy, aag, qqt => Vec3 [w, w, y];
+        wwz, aab, qqp => Vec3 [w, w, z];
+        www, aaa, qqq => Vec3 [w, w, w];
+        xxxx, rrrr, ssss => Vec4 [x, x, x, x];
+        xxxy, rrrg, ssst => Vec4 [x, x, x, y];
+        xxxz, rrrb, sssp => Vec4 [x, x, x, z];
+        xxxw, rrra, sssq => Vec4 [x, x, x, w];
+        xxyx, rrgr, ssts => Vec4 [x, x, y, x];
+        xxyy, rrgg, sstt => Vec4 [x, x, y, y];
+        xxyz, rrgb, sstp => Vec4 [x, x, y, z];
+        xxyw, rrga, sstq => Vec4 [x, x, y, w];
+        xxzx, rrbr, ssps => Vec4 [x, x, z, x];
+        xxzy, rrbg, sspt => Vec4 [x, x, z, y];
+        xxzz, rrbb, sspp => Vec4 [x, x, z, z];
+        xxzw, rrba, sspq => Vec4 [x, x, z, w];
+        xxwx, rrar, ssqs => Vec4 [x, x, w, x];
+        xxwy, rrag, ssqt => Vec4 [x, x, w, y];
+        xxwz, rrab, ssqp => Vec4 [x, x, w, z];
+        xxww, rraa, ssqq => Vec4 [x, x, w, w];
+        xyxx, rgrr, stss => Vec4 [x, y, x, x];
+        xyxy, rgrg, stst => Vec4 [x, y, x, y];
+        xyxz, rgrb, stsp => Vec4 [x, y, x, z];
+        xyxw, rgra, stsq => Vec4 [x, y, x, w];
+        xyyx, rggr, stts => Vec4 [x, y, y, x];
+        xyyy, rggg, sttt => Vec4 [x, y, y, y];
+        xyyz, rggb, sttp => Vec4 [x, y, y, z];
+        xyyw, rgga, sttq => Vec4 [x, y, y, w];
+        xyzx, rgbr, stps => Vec4 [x, y, z, x];
+        xyzy, rgbg, stpt => Vec4 [x, y, z, y];
+        xyzz, rgbb, stpp => Vec4 [x, y, z, z];
+        xyzw, rgba, stpq => Vec4 [x, y, z, w];
+        xywx, rgar, stqs => Vec4 [x, y, w, x];
+        xywy, rgag, stqt => Vec4 [x, y, w, y];
+        xywz, rgab, stqp => Vec4 [x, y, w, z];
+        xyww, rgaa, stqq => Vec4 [x, y, w, w];
+        xzxx, rbrr, spss => Vec4 [x, z, x, x];
+        xzxy, rbrg, spst => Vec4 [x, z, x, y];
+        xzxz, rbrb, spsp => Vec4 [x, z, x, z];
+        xzxw, rbra, spsq => Vec4 [x, z, x, w];
+        xzyx, rbgr, spts => Vec4 [x, z, y, x];
+        xzyy, rbgg, sptt => Vec4 [x, z, y, y];
+        xzyz, rbgb, sptp => Vec4 [x, z, y, z];
+        xzyw, rbga, sptq => Vec4 [x, z, y, w];
+        xzzx, rbbr, spps => Vec4 [x, z, z, x];
+        xzzy, rbbg, sppt => Vec4 [x, z, z, y];
+        xzzz, rbbb, sppp => Vec4 [x, z, z, z];
+        xzzw, rbba, sppq => Vec4 [x, z, z, w];
+        xzwx, rbar, spqs => Vec4 [x, z, w, x];
+        xzwy, rbag, spqt => Vec4 [x, z, w, y];
+        xzwz, rbab, spqp => Vec4 [x, z, w, z];
+        xzww, rbaa, spqq => Vec4 [x, z, w, w];
+        xwxx, rarr, sqss => Vec4 [x, w, x, x];
+        xwxy, rarg, sqst => Vec4 [x, w, x, y];
+        xwxz, rarb, sqsp => Vec4 [x, w, x, z];
+        xwxw, rara, sqsq => Vec4 [x, w, x, w];
+        xwyx, ragr, sqts => Vec4 [x, w, y, x];
+        xwyy, ragg, sqtt => Vec4 [x, w, y, y];
+        xwyz, ragb, sqtp => Vec4 [x, w, y, z];
+        xwyw, raga, sqtq => Vec4 [x, w, y, w];
+        xwzx, rabr, sqps => Vec4 [x, w, z, x];
+        xwzy, rabg, sqpt => Vec4 [x, w, z, y];
+        xwzz, rabb, sqpp => Vec4 [x, w, z, z];
+        xwzw, raba, sqpq => Vec4 [x, w, z, w];
+        xwwx, raar, sqqs => Vec4 [x, w, w, x];
+        xwwy, raag, sqqt => Vec4 [x, w, w, y];
+        xwwz, raab, sqqp => Vec4 [x, w, w, z];
+        xwww, raaa, sqqq => Vec4 [x, w, w, w];
+        yxxx, grrr, tsss => Vec4 [y, x, x, x];
+        yxxy, grrg, tsst => Vec4 [y, x, x, y];
+        yxxz, grrb, tssp => Vec4 [y, x, x, z];
+        yxxw, grra, tssq => Vec4 [y, x, x, w];
+        yxyx, grgr, tsts => Vec4 [y, x, y, x];
+        yxyy, grgg, tstt => Vec4 [y, x, y, y];
+        yxyz, grgb, tstp => Vec4 [y, x, y, z];
+        yxyw, grga, tstq => Vec4 [y, x, y, w];
+        yxzx, grbr, tsps => Vec4 [y, x, z, x];
+        yxzy, grbg, tspt => Vec4 [y, x, z, y];
+        yxzz, grbb, tspp => Vec4 [y, x, z, z];
+        yxzw, grba, tspq => Vec4 [y, x, z, w];
+        yxwx, grar, tsqs => Vec4 [y, x, w, x];
+        yxwy, grag, tsqt => Vec4 [y, x, w, y];
+        yxwz, grab, tsqp => Vec4 [y, x, w, z];
+        yxww, graa, tsqq => Vec4 [y, x, w, w];
+        yyxx, ggrr, ttss => Vec4 [y, y, x, x];
+        yyxy, ggrg, ttst => Vec4 [y, y, x, y];
+        yyxz, ggrb, ttsp => Vec4 [y, y, x, z];
+        yyxw, ggra, ttsq => Vec4 [y, y, x, w];
+        yyyx, gggr, ttts => Vec4 [y, y, y, x];
+        yyyy, gggg, tttt => Vec4 [y, y, y, y];
+        yyyz, gggb, tttp => Vec4 [y, y, y, z];
+        yyyw, ggga, tttq => Vec4 [y, y, y, w];
+        yyzx, ggbr, ttps => Vec4 [y, y, z, x];
+        yyzy, ggbg, ttpt => Vec4 [y, y, z, y];
+        yyzz, ggbb, ttpp => Vec4 [y, y, z, z];
+        yyzw, ggba, ttpq => Vec4 [y, y, z, w];
+        yywx, ggar, ttqs => Vec4 [y, y, w, x];
+        yywy, ggag, ttqt => Vec4 [y, y, w, y];
+        yywz, ggab, ttqp => Vec4 [y, y, w, z];
+        yyww, ggaa, ttqq => Vec4 [y, y, w, w];
+        yzxx, gbrr, tpss => Vec4 [y, z, x, x];
+        yzxy, gbrg, tpst => Vec4 [y, z, x, y];
+        yzxz, gbrb, tpsp => Vec4 [y, z, x, z];
+        yzxw, gbra, tpsq => Vec4 [y, z, x, w];
+        yzyx, gbgr, tpts => Vec4 [y, z, y, x];
+        yzyy, gbgg, tptt => Vec4 [y, z, y, y];
+        yzyz, gbgb, tptp => Vec4 [y, z, y, z];
+        yzyw, gbga, tptq => Vec4 [y, z, y, w];
+        yzzx, gbbr, tpps => Vec4 [y, z, z, x];
+        yzzy, gbbg, tppt => Vec4 [y, z, z, y];
+        yzzz, gbbb, tppp => Vec4 [y, z, z, z];
+        yzzw, gbba, tppq => Vec4 [y, z, z, w];
+        yzwx, gbar, tpqs => Vec4 [y, z, w, x];
+        yzwy, gbag, tpqt => Vec4 [y, z, w, y];
+        yzwz, gbab, tpqp => Vec4 [y, z, w, z];
+        yzww, gbaa, tpqq => Vec4 [y, z, w, w];
+        ywxx, garr, tqss => Vec4 [y, w, x, x];
+        ywxy, garg, tqst => Vec4 [y, w, x, y];
+        ywxz, garb, tqsp => Vec4 [y, w, x, z];
+        ywxw, gara, tqsq => Vec4 [y, w, x, w];
+        ywyx, gagr, tqts => Vec4 [y, w, y, x];
+        ywyy, gagg, tqtt => Vec4 [y, w, y, y];
+        ywyz, gagb, tqtp => Vec4 [y, w, y, z];
+        ywyw, gaga, tqtq => Vec4 [y, w, y, w];
+        ywzx, gabr, tqps => Vec4 [y, w, z, x];
+        ywzy, gabg, tqpt => Vec4 [y, w, z, y];
+        ywzz, gabb, tqpp => Vec4 [y, w, z, z];
+        ywzw, gaba, tqpq => Vec4 [y, w, z, w];
+        ywwx, gaar, tqqs => Vec4 [y, w, w, x];
+        ywwy, gaag, tqqt => Vec4 [y, w, w, y];
+        ywwz, gaab, tqqp => Vec4 [y, w, w, z];
+        ywww, gaaa, tqqq => Vec4 [y, w, w, w];
+        zxxx, brrr, psss => Vec4 [z, x, x, x];
+        zxxy, brrg, psst => Vec4 [z, x, x, y];
+        zxxz, brrb, pssp => Vec4 [z, x, x, z];
+        zxxw, brra, pssq => Vec4 [z, x, x, w];
+        zxyx, brgr, psts => Vec4 [z, x, y, x];
+        zxyy, brgg, pstt => Vec4 [z, x, y, y];
+        zxyz, brgb, pstp => Vec4 [z, x, y, z];
+        zxyw, brga, pstq => Vec4 [z, x, y, w];
+        zxzx, brbr, psps => Vec4 [z, x, z, x];
+        zxzy, brbg, pspt => Vec4 [z, x, z, y];
+        zxzz, brbb, pspp => Vec4 [z, x, z, z];
+        zxzw, brba, pspq => Vec4 [z, x, z, w];
+        zxwx, brar, psqs => Vec4 [z, x, w, x];
+        zxwy, brag, psqt => Vec4 [z, x, w, y];
+        zxwz, brab, psqp => Vec4 [z, x, w, z];
+        zxww, braa, psqq => Vec4 [z, x, w, w];
+        zyxx, bgrr, ptss => Vec4 [z, y, x, x];
+        zyxy, bgrg, ptst => Vec4 [z, y, x, y];
+        zyxz, bgrb, ptsp => Vec4 [z, y, x, z];
+        zyxw, bgra, ptsq => Vec4 [z, y, x, w];
+        zyyx, bggr, ptts => Vec4 [z, y, y, x];
+        zyyy, bggg, pttt => Vec4 [z, y, y, y];
+        zyyz, bggb, pttp => Vec4 [z, y, y, z];
+        zyyw, bgga, pttq => Vec4 [z, y, y, w];
+        zyzx, bgbr, ptps => Vec4 [z, y, z, x];
+        zyzy, bgbg, ptpt => Vec4 [z, y, z, y];
+        zyzz, bgbb, ptpp => Vec4 [z, y, z, z];
+        zyzw, bgba, ptpq => Vec4 [z, y, z, w];
+        zywx, bgar, ptqs => Vec4 [z, y, w, x];
+        zywy, bgag, ptqt => Vec4 [z, y, w, y];
+        zywz, bgab, ptqp => Vec4 [z, y, w, z];
+        zyww, bgaa, ptqq => Vec4 [z, y, w, w];
+        zzxx, bbrr, ppss => Vec4 [z, z, x, x];
+        zzxy, bbrg, ppst => Vec4 [z, z, x, y];
+        zzxz, bbrb, ppsp => Vec4 [z, z, x, z];
+        zzxw, bbra, ppsq => Vec4 [z, z, x, w];
+        zzyx, bbgr, ppts => Vec4 [z, z, y, x];
+        zzyy, bbgg, pptt => Vec4 [z, z, y, y];
+        zzyz, bbgb, pptp => Vec4 [z, z, y, z];
+        zzyw, bbga, pptq => Vec4 [z, z, y, w];
+        zzzx, bbbr, ppps => Vec4 [z, z, z, x];
+        zzzy, bbbg, pppt => Vec4 [z, z, z, y];
+        zzzz, bbbb, pppp => Vec4 [z, z, z, z];
+        zzzw, bbba, pppq => Vec4 [z, z, z, w];
+        zzwx, bbar, ppqs => Vec4 [z, z, w, x];
+        zzwy, bbag, ppqt => Vec4 [z, z, w, y];
+        zzwz, bbab, ppqp => Vec4 [z, z, w, z];
+        zzww, bbaa, ppqq => Vec4 [z, z, w, w];
+        zwxx, barr, pqss => Vec4 [z, w, x, x];
+        zwxy, barg, pqst => Vec4 [z, w, x, y];
+        zwxz, barb, pqsp => Vec4 [z, w, x, z];
+        zwxw, bara, pqsq => Vec4 [z, w, x, w];
+        zwyx, bagr, pqts => Vec4 [z, w, y, x];
+        zwyy, bagg, pqtt => Vec4 [z, w, y, y];
+        zwyz, bagb, pqtp => Vec4 [z, w, y, z];
+        zwyw, baga, pqtq => Vec4 [z, w, y, w];
+        zwzx, babr, pqps => Vec4 [z, w, z, x];
+        zwzy, babg, pqpt => Vec4 [z, w, z, y];
+        zwzz, babb, pqpp => Vec4 [z, w, z, z];
+        zwzw, baba, pqpq => Vec4 [z, w, z, w];
+        zwwx, baar, pqqs => Vec4 [z, w, w, x];
+        zwwy, baag, pqqt => Vec4 [z, w, w, y];
+        zwwz, baab, pqqp => Vec4 [z, w, w, z];
+        zwww, baaa, pqqq => Vec4 [z, w, w, w];
+        wxxx, arrr, qsss => Vec4 [w, x, x, x];
+        wxxy, arrg, qsst => Vec4 [w, x, x, y];
+        wxxz, arrb, qssp => Vec4 [w, x, x, z];
+        wxxw, arra, qssq => Vec4 [w, x, x, w];
+        wxyx, argr, qsts => Vec4 [w, x, y, x];
+        wxyy, argg, qstt => Vec4 [w, x, y, y];
+        wxyz, argb, qstp => Vec4 [w, x, y, z];
+        wxyw, arga, qstq => Vec4 [w, x, y, w];
+        wxzx, arbr, qsps => Vec4 [w, x, z, x];
+        wxzy, arbg, qspt => Vec4 [w, x, z, y];
+        wxzz, arbb, qspp => Vec4 [w, x, z, z];
+        wxzw, arba, qspq => Vec4 [w, x, z, w];
+        wxwx, arar, qsqs => Vec4 [w, x, w, x];
+        wxwy, arag, qsqt => Vec4 [w, x, w, y];
+        wxwz, arab, qsqp => Vec4 [w, x, w, z];
+        wxww, araa, qsqq => Vec4 [w, x, w, w];
+        wyxx, agrr, qtss => Vec4 [w, y, x, x];
+        wyxy, agrg, qtst => Vec4 [w, y, x, y];
+        wyxz, agrb, qtsp => Vec4 [w, y, x, z];
+        wyxw, agra, qtsq => Vec4 [w, y, x, w];
+        wyyx, aggr, qtts => Vec4 [w, y, y, x];
+        wyyy, aggg, qttt => Vec4 [w, y, y, y];
+        wyyz, aggb, qttp => Vec4 [w, y, y, z];
+        wyyw, agga, qttq => Vec4 [w, y, y, w];
+        wyzx, agbr, qtps => Vec4 [w, y, z, x];
+        wyzy, agbg, qtpt => Vec4 [w, y, z, y];
+        wyzz, agbb, qtpp => Vec4 [w, y, z, z];
+        wyzw, agba, qtpq => Vec4 [w, y, z, w];
+        wywx, agar, qtqs => Vec4 [w, y, w, x];
+        wywy, agag, qtqt => Vec4 [w, y, w, y];
+        wywz, agab, qtqp => Vec4 [w, y, w, z];
+        wyww, agaa, qtqq => Vec4 [w, y, w, w];
+        wzxx, abrr, qpss => Vec4 [w, z, x, x];
+        wzxy, abrg, qpst => Vec4 [w, z, x, y];
+        wzxz, abrb, qpsp => Vec4 [w, z, x, z];
+        wzxw, abra, qpsq => Vec4 [w, z, x, w];
+        wzyx, abgr, qpts => Vec4 [w, z, y, x];
+        wzyy, abgg, qptt => Vec4 [w, z, y, y];
+        wzyz, abgb, qptp => Vec4 [w, z, y, z];
+        wzyw, abga, qptq => Vec4 [w, z, y, w];
+        wzzx, abbr, qpps => Vec4 [w, z, z, x];
+        wzzy, abbg, qppt => Vec4 [w, z, z, y];
+        wzzz, abbb, qppp => Vec4 [w, z, z, z];
+        wzzw, abba, qppq => Vec4 [w, z, z, w];
+        wzwx, abar, qpqs => Vec4 [w, z, w, x];
+        wzwy, abag, qpqt => Vec4 [w, z, w, y];
+        wzwz, abab, qpqp => Vec4 [w, z, w, z];
+        wzww, abaa, qpqq => Vec4 [w, z, w, w];
+        wwxx, aarr, qqss => Vec4 [w, w, x, x];
+        wwxy, aarg, qqst => Vec4 [w, w, x, y];
+        wwxz, aarb, qqsp => Vec4 [w, w, x, z];
+        wwxw, aara, qqsq => Vec4 [w, w, x, w];
+        wwyx, aagr, qqts => Vec4 [w, w, y, x];
+        wwyy, aagg, qqtt => Vec4 [w, w, y, y];
+        wwyz, aagb, qqtp => Vec4 [w, w, y, z];
+        wwyw, aaga, qqtq => Vec4 [w, w, y, w];
+        wwzx, aabr, qqps => Vec4 [w, w, z, x];
+        wwzy, aabg, qqpt => Vec4 [w, w, z, y];
+        wwzz, aabb, qqpp => Vec4 [w, w, z, z];
+        wwzw, aaba, qqpq => Vec4 [w, w, z, w];
+        wwwx, aaar, qqqs => Vec4 [w, w, w, x];
+        wwwy, aaag, qqqt => Vec4 [w, w, w, y];
+        wwwz, aaab, qqqp => Vec4 [w, w, w, z];
+        wwww, aaaa, qqqq => Vec4 [w, w, w, w];
+    }
+}
+
+swizzle_set! {
+    Vec4 {
+        set_xy, set_rg, set_st => Vec2 [x, y];
+        set_xz, set_rb, set_sp => Vec2 [x, z];
+        set_xw, set_ra, set_sq => Vec2 [x, w];
+        set_yx, set_gr, set_ts => Vec2 [y, x];
+        set_yz, set_gb, set_tp => Vec2 [y, z];
+        set_yw, set_ga, set_tq => Vec2 [y, w];
+        set_zx, set_br, set_ps => Vec2 [z, x];
+        set_zy, set_bg, set_pt => Vec2 [z, y];
+        set_zw, set_ba, set_pq => Vec2 [z, w];
+        set_wx, set_ar, set_qs => Vec2 [w, x];
+        set_wy, set_ag, set_qt => Vec2 [w, y];
+        set_wz, set_ab, set_qp => Vec2 [w, z];
+        set_xyz, set_rgb, set_stp => Vec3 [x, y, z];
+        set_xyw, set_rga, set_stq => Vec3 [x, y, w];
+        set_xzy, set_rbg, set_spt => Vec3 [x, z, y];
+        set_xzw, set_rba, set_spq => Vec3 [x, z, w];
+        set_xwy, set_rag, set_sqt => Vec3 [x, w, y];
+        set_xwz, set_rab, set_sqp => Vec3 [x, w, z];
+        set_yxz, set_grb, set_tsp => Vec3 [y, x, z];
+        set_yxw, set_gra, set_tsq => Vec3 [y, x, w];
+        set_yzx, set_gbr, set_tps => Vec3 [y, z, x];
+        set_yzw, set_gba, set_tpq => Vec3 [y, z, w];
+        set_ywx, set_gar, set_tqs => Vec3 [y, w, x];
+        set_ywz, set_gab, set_tqp => Vec3 [y, w, z];
+        set_zxy, set_brg, set_pst => Vec3 [z, x, y];
+        set_zxw, set_bra, set_psq => Vec3 [z, x, w];
+        set_zyx, set_bgr, set_pts => Vec3 [z, y, x];
+        set_zyw, set_bga, set_ptq => Vec3 [z, y, w];
+        set_zwx, set_bar, set_pqs => Vec3 [z, w, x];
+        set_zwy, set_bag, set_pqt => Vec3 [z, w, y];
+        set_wxy, set_arg, set_qst => Vec3 [w, x, y];
+        set_wxz, set_arb, set_qsp => Vec3 [w, x, z];
+        set_wyx, set_agr, set_qts => Vec3 [w, y, x];
+        set_wyz, set_agb, set_qtp => Vec3 [w, y, z];
+        set_wzx, set_abr, set_qps => Vec3 [w, z, x];
+        set_wzy, set_abg, set_qpt => Vec3 [w, z, y];
+        set_xyzw, set_rgba, set_stpq => Vec4 [x, y, z, w];
+        set_xywz, set_rgab, set_stqp => Vec4 [x, y, w, z];
+        set_xzyw, set_rbga, set_sptq => Vec4 [x, z, y, w];
+        set_xzwy, set_rbag, set_spqt => Vec4 [x, z, w, y];
+        set_xwyz, set_ragb, set_sqtp => Vec4 [x, w, y, z];
+        set_xwzy, set_rabg, set_sqpt => Vec4 [x, w, z, y];
+        set_yxzw, set_grba, set_tspq => Vec4 [y, x, z, w];
+        set_yxwz, set_grab, set_tsqp => Vec4 [y, x, w, z];
+        set_yzxw, set_gbra, set_tpsq => Vec4 [y, z, x, w];
+        set_yzwx, set_gbar, set_tpqs => Vec4 [y, z, w, x];
+        set_ywxz, set_garb, set_tqsp => Vec4 [y, w, x, z];
+        set_ywzx, set_gabr, set_tqps => Vec4 [y, w, z, x];
+        set_zxyw, set_brga, set_pstq => Vec4 [z, x, y, w];
+        set_zxwy, set_brag, set_psqt => Vec4 [z, x, w, y];
+        set_zyxw, set_bgra, set_ptsq => Vec4 [z, y, x, w];
+        set_zywx, set_bgar, set_ptqs => Vec4 [z, y, w, x];
+        set_zwxy, set_barg, set_pqst => Vec4 [z, w, x, y];
+        set_zwyx, set_bagr, set_pqts => Vec4 [z, w, y, x];
+        set_wxyz, set_argb, set_qstp => Vec4 [w, x, y, z];
+        set_wxzy, set_arbg, set_qspt => Vec4 [w, x, z, y];
+        set_wyxz, set_agrb, set_qtsp => Vec4 [w, y, x, z];
+        set_wyzx, set_agbr, set_qtps => Vec4 [w, y, z, x];
+        set_wzxy, set_abrg, set_qpst => Vec4 [w, z, x, y];
+        set_wzyx, set_abgr, set_qpts => Vec4 [w, z, y, x];
+    }
+}
+// Vec4 -------------------------------------------------------------------------------------------
@@ -0,0 +1,39 @@
+//! Unsigned integer vectors, for texel indices, grid sizes and other non-negative quantities.
+//!
+//! # Example
+//! ```
+//! use vectorama::UVec2;
+//! let size = UVec2::new(1920, 1080);
+//! assert_eq!(size / 2, UVec2::new(960, 540));
+//! assert_eq!(UVec2::ZERO.saturating_sub(&size), UVec2::ZERO);
+//! ```
+
+use crate::vector::{
+    bvec::{BVec2, BVec3, BVec4},
+    integer::integer_vector,
+    vec2::Vec2,
+    vec3::Vec3,
+    vec4::Vec4,
+};
+
+integer_vector!(
+    /// A 2D vector with `u32` components.
+    UVec2(u32, 2, [x, y]),
+    mask: BVec2,
+    float: Vec2(as_vec2, try_as_vec2, as_uvec2, try_as_uvec2),
+    axes: { X => [1, 0], Y => [0, 1] },
+);
+integer_vector!(
+    /// A 3D vector with `u32` components.
+    UVec3(u32, 3, [x, y, z]),
+    mask: BVec3,
+    float: Vec3(as_vec3, try_as_vec3, as_uvec3, try_as_uvec3),
+    axes: { X => [1, 0, 0], Y => [0, 1, 0], Z => [0, 0, 1] },
+);
+integer_vector!(
+    /// A 4D vector with `u32` components.
+    UVec4(u32, 4, [x, y, z, w]),
+    mask: BVec4,
+    float: Vec4(as_vec4, try_as_vec4, as_uvec4, try_as_uvec4),
+    axes: { X => [1, 0, 0, 0], Y => [0, 1, 0, 0], Z => [0, 0, 1, 0], W => [0, 0, 0, 1] },
+);
@@ -1,4 +1,7 @@
-use std::ops::{Add, AddAssign};
+use std::{
+    iter::Sum,
+    ops::{Add, AddAssign},
+};
 
 use crate::vector::vec2::Vec2;
 
@@ -7,7 +10,7 @@ impl Add<&Vec2> for &Vec2 {
     type Output = Vec2;
 
     fn add(self, rhs: &Vec2) -> Self::Output {
-        let matrix = unsafe { self.data.matrix + rhs.data.matrix };
+        let matrix = self.matrix() + rhs.matrix();
         Vec2::from(matrix)
     }
 }
@@ -40,7 +43,7 @@ impl Add<Vec2> for Vec2 {
 
 impl AddAssign<&Vec2> for Vec2 {
     fn add_assign(&mut self, rhs: &Vec2) {
-        unsafe { self.data.matrix += rhs.data.matrix }
+        *self = *self + rhs
     }
 }
 
@@ -50,3 +53,17 @@ impl AddAssign<Vec2> for Vec2 {
     }
 }
 // Vector -----------------------------------------------------------------------------------------
+
+// Sum --------------------------------------------------------------------------------------------
+impl Sum for Vec2 {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Vec2::ZERO, |acc, vector| acc + vector)
+    }
+}
+
+impl<'a> Sum<&'a Vec2> for Vec2 {
+    fn sum<I: Iterator<Item = &'a Vec2>>(iter: I) -> Self {
+        iter.fold(Vec2::ZERO, |acc, vector| acc + vector)
+    }
+}
+// Sum --------------------------------------------------------------------------------------------
@@ -7,7 +7,7 @@ impl Div<f32> for &Vec2 {
     type Output = Vec2;
 
     fn div(self, rhs: f32) -> Self::Output {
-        let matrix = unsafe { self.data.matrix / rhs };
+        let matrix = self.matrix() / rhs;
         Vec2::from(matrix)
     }
 }
@@ -23,7 +23,55 @@ impl Div<f32> for Vec2 {
 
 impl DivAssign<f32> for Vec2 {
     fn div_assign(&mut self, rhs: f32) {
-        unsafe { self.data.matrix /= rhs }
+        *self = *self / rhs
     }
 }
 // Scalar -----------------------------------------------------------------------------------------
+
+// Vector -----------------------------------------------------------------------------------------
+impl Div<&Vec2> for &Vec2 {
+    type Output = Vec2;
+
+    fn div(self, rhs: &Vec2) -> Self::Output {
+        self.component_div(rhs)
+    }
+}
+
+#[allow(clippy::op_ref)]
+impl Div<&Vec2> for Vec2 {
+    type Output = Vec2;
+
+    fn div(self, rhs: &Vec2) -> Self::Output {
+        &self / rhs
+    }
+}
+
+#[allow(clippy::op_ref)]
+impl Div<Vec2> for &Vec2 {
+    type Output = Vec2;
+
+    fn div(self, rhs: Vec2) -> Self::Output {
+        self / &rhs
+    }
+}
+
+impl Div<Vec2> for Vec2 {
+    type Output = Vec2;
+
+    fn div(self, rhs: Vec2) -> Self::Output {
+        &self / &rhs
+    }
+}
+
+impl DivAssign<&Vec2> for Vec2 {
+    fn div_assign(&mut self, rhs: &Vec2) {
+        *self = *self / rhs
+    }
+}
+
+impl DivAssign<Vec2> for Vec2 {
+    fn div_assign(&mut self, rhs: Vec2) {
+        *self /= &rhs
+    }
+}
+// Vector -----------------------------------------------------------------------------------------
@@ -18,20 +18,6 @@ pub struct Vector2 {
     pub y: f32,
 }
 
-#[derive(Clone, Copy)]
-pub union Vector2Union {
-    matrix: Vector<2>,
-    vector: Vector2,
-}
-
-impl Default for Vector2Union {
-    fn default() -> Self {
-        Self {
-            vector: Default::default(),
-        }
-    }
-}
-
 /// A 2D vector with `f32` components.
 ///
 /// `Vec2` provides convenient methods for 2D vector arithmetic, normalization, and conversion.
@@ -41,11 +27,18 @@ impl Default for Vector2Union {
 /// use vectorama::Vec2;
 /// let v = Vec2::new(1.0, 2.0);
 /// ```
+#[repr(transparent)]
 #[derive(Default, Clone, Copy)]
 pub struct Vec2 {
-    data: Vector2Union,
+    data: Vector2,
 }
 
+// Fails to compile if `as_array` could not reinterpret a `Vec2` as `[f32; 2]`.
+const _: () = assert!(
+    std::mem::size_of::<Vec2>() == std::mem::size_of::<[f32; 2]>()
+        && std::mem::align_of::<Vec2>() == std::mem::align_of::<[f32; 2]>()
+);
+
 impl Debug for Vec2 {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("Vec2")
@@ -56,6 +49,42 @@ impl Debug for Vec2 {
 }
 
 impl Vec2 {
+    /// A vector with all components set to zero.
+    pub const ZERO: Self = Self::new(0.0, 0.0);
+
+    /// A vector with all components set to one.
+    pub const ONE: Self = Self::new(1.0, 1.0);
+
+    /// A vector with all components set to negative one.
+    pub const NEG_ONE: Self = Self::new(-1.0, -1.0);
+
+    /// A vector with all components set to `f32::MIN`.
+    pub const MIN: Self = Self::new(f32::MIN, f32::MIN);
+
+    /// A vector with all components set to `f32::MAX`.
+    pub const MAX: Self = Self::new(f32::MAX, f32::MAX);
+
+    /// A vector with all components set to `f32::NAN`.
+    pub const NAN: Self = Self::new(f32::NAN, f32::NAN);
+
+    /// A vector with all components set to `f32::INFINITY`.
+    pub const INFINITY: Self = Self::new(f32::INFINITY, f32::INFINITY);
+
+    /// A vector with all components set to `f32::NEG_INFINITY`.
+    pub const NEG_INFINITY: Self = Self::new(f32::NEG_INFINITY, f32::NEG_INFINITY);
+
+    /// A unit vector pointing along the positive X axis.
+    pub const X: Self = Self::new(1.0, 0.0);
+
+    /// A unit vector pointing along the positive Y axis.
+    pub const Y: Self = Self::new(0.0, 1.0);
+
+    /// A unit vector pointing along the negative X axis.
+    pub const NEG_X: Self = Self::new(-1.0, 0.0);
+
+    /// A unit vector pointing along the negative Y axis.
+    pub const NEG_Y: Self = Self::new(0.0, -1.0);
+
     /// Creates a new 2D vector from `x` and `y` components.
     ///
     /// # Parameters
@@ -66,25 +95,23 @@ impl Vec2 {
     /// A new `Vec2` with the specified components.
     pub const fn new(x: f32, y: f32) -> Self {
         let vector2 = Vector2 { x, y };
-        Self {
-            data: Vector2Union { vector: vector2 },
-        }
+        Self { data: vector2 }
     }
 
     /// Returns a vector with both components set to zero.
     ///
     /// # Returns
     /// A zero vector.
-    pub fn zeros() -> Self {
-        Self::from(Vector::zeros())
+    pub const fn zeros() -> Self {
+        Self::ZERO
     }
 
     /// Returns a vector with both components set to one.
     ///
     /// # Returns
     /// A vector with all components equal to 1.0.
-    pub fn ones() -> Self {
-        Self::from(Vector::ones())
+    pub const fn ones() -> Self {
+        Self::ONE
     }
 
     /// Creates a 3D vector from this 2D vector and a given z component.
@@ -106,7 +133,7 @@ impl Vec2 {
     /// # Returns
     /// The dot product as a `f32`.
     pub fn dot(&self, other: &Vec2) -> f32 {
-        unsafe { self.data.matrix.dot(&other.data.matrix) }
+        self.matrix().dot(&other.matrix())
     }
 
     /// Computes the magnitude (length) of the vector.
@@ -114,7 +141,7 @@ impl Vec2 {
     /// # Returns
     /// The magnitude as a `f32`.
     pub fn magnitude(&self) -> f32 {
-        unsafe { self.data.matrix.magnitude() }
+        self.matrix().magnitude()
     }
 
     /// Returns a normalized (unit length) version of this vector.
@@ -122,9 +149,26 @@ impl Vec2 {
     /// # Returns
     /// The normalized vector.
     pub fn normalize(&self) -> Vec2 {
-        let matrix = unsafe { self.data.matrix.normalize() };
+        let matrix = self.matrix().normalize();
         Vec2::from(matrix)
     }
+
+    /// Returns a reference to the components as an array.
+    ///
+    /// # Returns
+    /// A reference to the `[x, y]` components, borrowed from this vector.
+    pub const fn as_array(&self) -> &[f32; 2] {
+        // SAFETY: `Vec2` is `repr(transparent)` over `Vector2`, which is `repr(C)` with 2 `f32`
+        // fields and therefore has the same size, alignment and layout as `[f32; 2]`; this is
+        // asserted at compile time next to the struct. A safe borrow would need a layout trait
+        // such as zerocopy's implemented on the public `Vector2`, making it part of the API.
+        unsafe { &*(self as *const Self as *const [f32; 2]) }
+    }
+
+    /// Returns the components as a `Vector<2>` (a `Matrix<2, 1>`).
+    fn matrix(&self) -> Vector<2> {
+        Vector::from([self.data.x, self.data.y])
+    }
 }
 
 // Deref and DerefMut -----------------------------------------------------------------------------
@@ -132,13 +176,13 @@ impl Deref for Vec2 {
     type Target = Vector2;
 
     fn deref(&self) -> &Self::Target {
-        unsafe { &self.data.vector }
+        &self.data
     }
 }
 
 impl DerefMut for Vec2 {
     fn deref_mut(&mut self) -> &mut Self::Target {
-        unsafe { &mut self.data.vector }
+        &mut self.data
     }
 }
 // Deref and DerefMut -----------------------------------------------------------------------------
@@ -146,21 +190,25 @@ impl DerefMut for Vec2 {
 // From -------------------------------------------------------------------------------------------
 impl From<Vector<2>> for Vec2 {
     fn from(value: Vector<2>) -> Self {
-        Self {
-            data: Vector2Union { matrix: value },
-        }
+        Self::from(<[f32; 2]>::from(value))
     }
 }
 
 impl From<[f32; 2]> for Vec2 {
     fn from(value: [f32; 2]) -> Self {
-        Self::from(Vector::from(value))
+        Self::new(value[0], value[1])
+    }
+}
+
+impl From<Vec2> for [f32; 2] {
+    fn from(value: Vec2) -> Self {
+        *value.as_array()
     }
 }
 
 impl From<Vec2> for Vector<2> {
     fn from(value: Vec2) -> Self {
-        unsafe { value.data.matrix }
+        value.matrix()
     }
 }
 // From -------------------------------------------------------------------------------------------
@@ -168,7 +216,7 @@ impl From<Vec2> for Vector<2> {
 // AsRef ------------------------------------------------------------------------------------------
 impl AsRef<[f32]> for Vec2 {
     fn as_ref(&self) -> &[f32] {
-        unsafe { self.data.matrix.as_ref() }
+        self.as_array()
     }
 }
 // AsRef ------------------------------------------------------------------------------------------
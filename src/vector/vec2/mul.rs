@@ -1,4 +1,7 @@
-use std::ops::{Mul, MulAssign};
+use std::{
+    iter::Product,
+    ops::{Mul, MulAssign},
+};
 
 use crate::{matrix::Matrix, vector::vec2::Vec2};
 
@@ -7,7 +10,7 @@ impl Mul<&Vec2> for &Matrix<2, 2> {
     type Output = Vec2;
 
     fn mul(self, rhs: &Vec2) -> Self::Output {
-        (self * unsafe { rhs.data.matrix }).into()
+        (self * rhs.matrix()).into()
     }
 }
 
@@ -43,7 +46,7 @@ impl Mul<f32> for &Vec2 {
     type Output = Vec2;
 
     fn mul(self, rhs: f32) -> Self::Output {
-        let matrix = unsafe { self.data.matrix * rhs };
+        let matrix = self.matrix() * rhs;
         Vec2::from(matrix)
     }
 }
@@ -59,7 +62,7 @@ impl Mul<f32> for Vec2 {
 
 impl MulAssign<f32> for Vec2 {
     fn mul_assign(&mut self, rhs: f32) {
-        unsafe { self.data.matrix *= rhs }
+        *self = *self * rhs
     }
 }
 
@@ -80,3 +83,63 @@ impl Mul<Vec2> for f32 {
     }
 }
 // Scalar -----------------------------------------------------------------------------------------
+
+// Vector -----------------------------------------------------------------------------------------
+impl Mul<&Vec2> for &Vec2 {
+    type Output = Vec2;
+
+    fn mul(self, rhs: &Vec2) -> Self::Output {
+        self.component_mul(rhs)
+    }
+}
+
+#[allow(clippy::op_ref)]
+impl Mul<&Vec2> for Vec2 {
+    type Output = Vec2;
+
+    fn mul(self, rhs: &Vec2) -> Self::Output {
+        &self * rhs
+    }
+}
+
+#[allow(clippy::op_ref)]
+impl Mul<Vec2> for &Vec2 {
+    type Output = Vec2;
+
+    fn mul(self, rhs: Vec2) -> Self::Output {
+        self * &rhs
+    }
+}
+
+impl Mul<Vec2> for Vec2 {
+    type Output = Vec2;
+
+    fn mul(self, rhs: Vec2) -> Self::Output {
+        &self * &rhs
+    }
+}
+
+impl MulAssign<&Vec2> for Vec2 {
+    fn mul_assign(&mut self, rhs: &Vec2) {
+        *self = *self * rhs
+    }
+}
+
+impl MulAssign<Vec2> for Vec2 {
+    fn mul_assign(&mut self, rhs: Vec2) {
+        *self *= &rhs
+    }
+}
+
+impl Product for Vec2 {
+    fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Vec2::ONE, |acc, vector| acc * vector)
+    }
+}
+
+impl<'a> Product<&'a Vec2> for Vec2 {
+    fn product<I: Iterator<Item = &'a Vec2>>(iter: I) -> Self {
+        iter.fold(Vec2::ONE, |acc, vector| acc * vector)
+    }
+}
+// Vector -----------------------------------------------------------------------------------------
@@ -6,7 +6,7 @@ impl Neg for Vec2 {
     type Output = Vec2;
 
     fn neg(self) -> Self::Output {
-        let matrix = unsafe { -self.data.matrix };
+        let matrix = -self.matrix();
         Vec2::from(matrix)
     }
 }
@@ -7,7 +7,7 @@ impl Sub<&Vec2> for &Vec2 {
     type Output = Vec2;
 
     fn sub(self, rhs: &Vec2) -> Self::Output {
-        let matrix = unsafe { self.data.matrix - rhs.data.matrix };
+        let matrix = self.matrix() - rhs.matrix();
         Vec2::from(matrix)
     }
 }
@@ -40,7 +40,7 @@ impl Sub<Vec2> for Vec2 {
 
 impl SubAssign<&Vec2> for Vec2 {
     fn sub_assign(&mut self, rhs: &Vec2) {
-        unsafe { self.data.matrix -= rhs.data.matrix }
+        *self = *self - rhs
     }
 }
 
@@ -1,4 +1,7 @@
-use std::ops::{Add, AddAssign};
+use std::{
+    iter::Sum,
+    ops::{Add, AddAssign},
+};
 
 use crate::vector::vec3::Vec3;
 
@@ -7,7 +10,7 @@ impl Add<&Vec3> for &Vec3 {
     type Output = Vec3;
 
     fn add(self, rhs: &Vec3) -> Self::Output {
-        let matrix = unsafe { self.data.matrix + rhs.data.matrix };
+        let matrix = self.matrix() + rhs.matrix();
         Vec3::from(matrix)
     }
 }
@@ -40,7 +43,7 @@ impl Add<Vec3> for Vec3 {
 
 impl AddAssign<&Vec3> for Vec3 {
     fn add_assign(&mut self, rhs: &Vec3) {
-        unsafe { self.data.matrix += rhs.data.matrix }
+        *self = *self + rhs
     }
 }
 
@@ -50,3 +53,17 @@ impl AddAssign<Vec3> for Vec3 {
     }
 }
 // Vector -----------------------------------------------------------------------------------------
+
+// Sum --------------------------------------------------------------------------------------------
+impl Sum for Vec3 {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Vec3::ZERO, |acc, vector| acc + vector)
+    }
+}
+
+impl<'a> Sum<&'a Vec3> for Vec3 {
+    fn sum<I: Iterator<Item = &'a Vec3>>(iter: I) -> Self {
+        iter.fold(Vec3::ZERO, |acc, vector| acc + vector)
+    }
+}
+// Sum --------------------------------------------------------------------------------------------
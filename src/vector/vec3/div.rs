@@ -7,7 +7,7 @@ impl Div<f32> for &Vec3 {
     type Output = Vec3;
 
     fn div(self, rhs: f32) -> Self::Output {
-        let matrix = unsafe { self.data.matrix / rhs };
+        let matrix = self.matrix() / rhs;
         Vec3::from(matrix)
     }
 }
@@ -23,7 +23,55 @@ impl Div<f32> for Vec3 {
 
 impl DivAssign<f32> for Vec3 {
     fn div_assign(&mut self, rhs: f32) {
-        unsafe { self.data.matrix /= rhs }
+        *self = *self / rhs
     }
 }
 // Scalar -----------------------------------------------------------------------------------------
+
+// Vector -----------------------------------------------------------------------------------------
+impl Div<&Vec3> for &Vec3 {
+    type Output = Vec3;
+
+    fn div(self, rhs: &Vec3) -> Self::Output {
+        self.component_div(rhs)
+    }
+}
+
+#[allow(clippy::op_ref)]
+impl Div<&Vec3> for Vec3 {
+    type Output = Vec3;
+
+    fn div(self, rhs: &Vec3) -> Self::Output {
+        &self / rhs
+    }
+}
+
+#[allow(clippy::op_ref)]
+impl Div<Vec3> for &Vec3 {
+    type Output = Vec3;
+
+    fn div(self, rhs: Vec3) -> Self::Output {
+        self / &rhs
+    }
+}
+
+impl Div<Vec3> for Vec3 {
+    type Output = Vec3;
+
+    fn div(self, rhs: Vec3) -> Self::Output {
+        &self / &rhs
+    }
+}
+
+impl DivAssign<&Vec3> for Vec3 {
+    fn div_assign(&mut self, rhs: &Vec3) {
+        *self = *self / rhs
+    }
+}
+
+impl DivAssign<Vec3> for Vec3 {
+    fn div_assign(&mut self, rhs: Vec3) {
+        *self /= &rhs
+    }
+}
+// Vector -----------------------------------------------------------------------------------------
@@ -1,7 +1,7 @@
 use std::fmt::Debug;
 use std::ops::{Deref, DerefMut};
 
-use crate::vector::{Vector, vec2::Vec2, vec4::Vec4};
+use crate::vector::{Vector, vec4::Vec4};
 
 pub mod add;
 pub mod div;
@@ -17,20 +17,6 @@ pub struct Vector3 {
     pub z: f32,
 }
 
-#[derive(Clone, Copy)]
-pub union Vector3Union {
-    matrix: Vector<3>,
-    vector: Vector3,
-}
-
-impl Default for Vector3Union {
-    fn default() -> Self {
-        Self {
-            vector: Default::default(),
-        }
-    }
-}
-
 /// A 3D vector with `f32` components.
 ///
 /// `Vec3` provides convenient methods for 3D vector arithmetic, normalization, cross and dot products,
@@ -41,11 +27,18 @@ impl Default for Vector3Union {
 /// use vectorama::vector::vec3::Vec3;
 /// let v = Vec3::new(1.0, 2.0, 3.0);
 /// ```
+#[repr(transparent)]
 #[derive(Default, Clone, Copy)]
 pub struct Vec3 {
-    data: Vector3Union,
+    data: Vector3,
 }
 
+// Fails to compile if `as_array` could not reinterpret a `Vec3` as `[f32; 3]`.
+const _: () = assert!(
+    std::mem::size_of::<Vec3>() == std::mem::size_of::<[f32; 3]>()
+        && std::mem::align_of::<Vec3>() == std::mem::align_of::<[f32; 3]>()
+);
+
 impl Debug for Vec3 {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("Vec3")
@@ -57,6 +50,49 @@ impl Debug for Vec3 {
 }
 
 impl Vec3 {
+    /// A vector with all components set to zero.
+    pub const ZERO: Self = Self::new(0.0, 0.0, 0.0);
+
+    /// A vector with all components set to one.
+    pub const ONE: Self = Self::new(1.0, 1.0, 1.0);
+
+    /// A vector with all components set to negative one.
+    pub const NEG_ONE: Self = Self::new(-1.0, -1.0, -1.0);
+
+    /// A vector with all components set to `f32::MIN`.
+    pub const MIN: Self = Self::new(f32::MIN, f32::MIN, f32::MIN);
+
+    /// A vector with all components set to `f32::MAX`.
+    pub const MAX: Self = Self::new(f32::MAX, f32::MAX, f32::MAX);
+
+    /// A vector with all components set to `f32::NAN`.
+    pub const NAN: Self = Self::new(f32::NAN, f32::NAN, f32::NAN);
+
+    /// A vector with all components set to `f32::INFINITY`.
+    pub const INFINITY: Self = Self::new(f32::INFINITY, f32::INFINITY, f32::INFINITY);
+
+    /// A vector with all components set to `f32::NEG_INFINITY`.
+    pub const NEG_INFINITY: Self =
+        Self::new(f32::NEG_INFINITY, f32::NEG_INFINITY, f32::NEG_INFINITY);
+
+    /// A unit vector pointing along the positive X axis.
+    pub const X: Self = Self::new(1.0, 0.0, 0.0);
+
+    /// A unit vector pointing along the positive Y axis.
+    pub const Y: Self = Self::new(0.0, 1.0, 0.0);
+
+    /// A unit vector pointing along the positive Z axis.
+    pub const Z: Self = Self::new(0.0, 0.0, 1.0);
+
+    /// A unit vector pointing along the negative X axis.
+    pub const NEG_X: Self = Self::new(-1.0, 0.0, 0.0);
+
+    /// A unit vector pointing along the negative Y axis.
+    pub const NEG_Y: Self = Self::new(0.0, -1.0, 0.0);
+
+    /// A unit vector pointing along the negative Z axis.
+    pub const NEG_Z: Self = Self::new(0.0, 0.0, -1.0);
+
     /// Creates a new 3D vector from `x`, `y`, and `z` components.
     ///
     /// # Parameters
@@ -68,49 +104,23 @@ impl Vec3 {
     /// A new `Vec3` with the specified components.
     pub const fn new(x: f32, y: f32, z: f32) -> Self {
         let vector3 = Vector3 { x, y, z };
-        Self {
-            data: Vector3Union { vector: vector3 },
-        }
+        Self { data: vector3 }
     }
 
     /// Returns a vector with all components set to zero.
     ///
     /// # Returns
     /// A zero vector.
-    pub fn zeros() -> Self {
-        Self::from(Vector::zeros())
+    pub const fn zeros() -> Self {
+        Self::ZERO
     }
 
     /// Returns a vector with all components set to one.
     ///
     /// # Returns
     /// A vector with all components equal to 1.0.
-    pub fn ones() -> Self {
-        Self::from(Vector::ones())
-    }
-
-    /// Returns the `x` and `y` components as a `Vec2`.
-    ///
-    /// # Returns
-    /// A `Vec2` containing the x and y components.
-    pub fn xy(&self) -> Vec2 {
-        Vec2::new(self.x, self.y)
-    }
-
-    /// Returns the `y` and `z` components as a `Vec2`.
-    ///
-    /// # Returns
-    /// A `Vec2` containing the y and z components.
-    pub fn yz(&self) -> Vec2 {
-        Vec2::new(self.y, self.z)
-    }
-
-    /// Returns the `x` and `z` components as a `Vec2`.
-    ///
-    /// # Returns
-    /// A `Vec2` containing the x and z components.
-    pub fn xz(&self) -> Vec2 {
-        Vec2::new(self.x, self.z)
+    pub const fn ones() -> Self {
+        Self::ONE
     }
 
     /// Creates a `Vec4` from this vector and a given w component.
@@ -132,7 +142,7 @@ impl Vec3 {
     /// # Returns
     /// The cross product as a `Vec3`.
     pub fn cross(&self, other: &Vec3) -> Vec3 {
-        let matrix = unsafe { self.data.matrix.cross(&other.data.matrix) };
+        let matrix = self.matrix().cross(&other.matrix());
         Vec3::from(matrix)
     }
 
@@ -144,7 +154,7 @@ impl Vec3 {
     /// # Returns
     /// The dot product as a `f32`.
     pub fn dot(&self, other: &Vec3) -> f32 {
-        unsafe { self.data.matrix.dot(&other.data.matrix) }
+        self.matrix().dot(&other.matrix())
     }
 
     /// Computes the magnitude (length) of the vector.
@@ -152,7 +162,7 @@ impl Vec3 {
     /// # Returns
     /// The magnitude as a `f32`.
     pub fn magnitude(&self) -> f32 {
-        unsafe { self.data.matrix.magnitude() }
+        self.matrix().magnitude()
     }
 
     /// Returns a normalized (unit length) version of this vector.
@@ -160,9 +170,26 @@ impl Vec3 {
     /// # Returns
     /// The normalized vector.
     pub fn normalize(&self) -> Vec3 {
-        let matrix = unsafe { self.data.matrix.normalize() };
+        let matrix = self.matrix().normalize();
         Vec3::from(matrix)
     }
+
+    /// Returns a reference to the components as an array.
+    ///
+    /// # Returns
+    /// A reference to the `[x, y, z]` components, borrowed from this vector.
+    pub const fn as_array(&self) -> &[f32; 3] {
+        // SAFETY: `Vec3` is `repr(transparent)` over `Vector3`, which is `repr(C)` with 3 `f32`
+        // fields and therefore has the same size, alignment and layout as `[f32; 3]`; this is
+        // asserted at compile time next to the struct. A safe borrow would need a layout trait
+        // such as zerocopy's implemented on the public `Vector3`, making it part of the API.
+        unsafe { &*(self as *const Self as *const [f32; 3]) }
+    }
+
+    /// Returns the components as a `Vector<3>` (a `Matrix<3, 1>`).
+    fn matrix(&self) -> Vector<3> {
+        Vector::from([self.data.x, self.data.y, self.data.z])
+    }
 }
 
 // Deref and DerefMut -----------------------------------------------------------------------------
@@ -170,13 +197,13 @@ impl Deref for Vec3 {
     type Target = Vector3;
 
     fn deref(&self) -> &Self::Target {
-        unsafe { &self.data.vector }
+        &self.data
     }
 }
 
 impl DerefMut for Vec3 {
     fn deref_mut(&mut self) -> &mut Self::Target {
-        unsafe { &mut self.data.vector }
+        &mut self.data
     }
 }
 // Deref and DerefMut -----------------------------------------------------------------------------
@@ -184,21 +211,25 @@ impl DerefMut for Vec3 {
 // From -------------------------------------------------------------------------------------------
 impl From<Vector<3>> for Vec3 {
     fn from(value: Vector<3>) -> Self {
-        Self {
-            data: Vector3Union { matrix: value },
-        }
+        Self::from(<[f32; 3]>::from(value))
     }
 }
 
 impl From<[f32; 3]> for Vec3 {
     fn from(value: [f32; 3]) -> Self {
-        Self::from(Vector::from(value))
+        Self::new(value[0], value[1], value[2])
+    }
+}
+
+impl From<Vec3> for [f32; 3] {
+    fn from(value: Vec3) -> Self {
+        *value.as_array()
     }
 }
 
 impl From<Vec3> for Vector<3> {
     fn from(value: Vec3) -> Self {
-        unsafe { value.data.matrix }
+        value.matrix()
     }
 }
 // From -------------------------------------------------------------------------------------------
@@ -206,7 +237,7 @@ impl From<Vec3> for Vector<3> {
 // AsRef ------------------------------------------------------------------------------------------
 impl AsRef<[f32]> for Vec3 {
     fn as_ref(&self) -> &[f32] {
-        unsafe { self.data.matrix.as_ref() }
+        self.as_array()
     }
 }
 // AsRef ------------------------------------------------------------------------------------------
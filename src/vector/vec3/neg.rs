@@ -6,7 +6,7 @@ impl Neg for Vec3 {
     type Output = Vec3;
 
     fn neg(self) -> Self::Output {
-        let matrix = unsafe { -self.data.matrix };
+        let matrix = -self.matrix();
         Vec3::from(matrix)
     }
 }
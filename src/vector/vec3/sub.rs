@@ -7,7 +7,7 @@ impl Sub<&Vec3> for &Vec3 {
     type Output = Vec3;
 
     fn sub(self, rhs: &Vec3) -> Self::Output {
-        let matrix = unsafe { self.data.matrix - rhs.data.matrix };
+        let matrix = self.matrix() - rhs.matrix();
         Vec3::from(matrix)
     }
 }
@@ -40,7 +40,7 @@ impl Sub<Vec3> for Vec3 {
 
 impl SubAssign<&Vec3> for Vec3 {
     fn sub_assign(&mut self, rhs: &Vec3) {
-        unsafe { self.data.matrix -= rhs.data.matrix }
+        *self = *self - rhs
     }
 }
 
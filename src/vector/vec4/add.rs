@@ -1,4 +1,7 @@
-use std::ops::{Add, AddAssign};
+use std::{
+    iter::Sum,
+    ops::{Add, AddAssign},
+};
 
 use crate::vector::vec4::Vec4;
 
@@ -7,7 +10,7 @@ impl Add<&Vec4> for &Vec4 {
     type Output = Vec4;
 
     fn add(self, rhs: &Vec4) -> Self::Output {
-        let matrix = unsafe { self.data.matrix + rhs.data.matrix };
+        let matrix = self.matrix() + rhs.matrix();
         Vec4::from(matrix)
     }
 }
@@ -40,7 +43,7 @@ impl Add<Vec4> for Vec4 {
 
 impl AddAssign<&Vec4> for Vec4 {
     fn add_assign(&mut self, rhs: &Vec4) {
-        unsafe { self.data.matrix += rhs.data.matrix }
+        *self = *self + rhs
     }
 }
 
@@ -50,3 +53,17 @@ impl AddAssign<Vec4> for Vec4 {
     }
 }
 // Vector -----------------------------------------------------------------------------------------
+
+// Sum --------------------------------------------------------------------------------------------
+impl Sum for Vec4 {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Vec4::ZERO, |acc, vector| acc + vector)
+    }
+}
+
+impl<'a> Sum<&'a Vec4> for Vec4 {
+    fn sum<I: Iterator<Item = &'a Vec4>>(iter: I) -> Self {
+        iter.fold(Vec4::ZERO, |acc, vector| acc + vector)
+    }
+}
+// Sum --------------------------------------------------------------------------------------------
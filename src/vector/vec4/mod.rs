@@ -3,7 +3,7 @@ use std::{
     ops::{Deref, DerefMut},
 };
 
-use crate::vector::{Vector, vec2::Vec2, vec3::Vec3};
+use crate::vector::Vector;
 
 pub mod add;
 pub mod div;
@@ -20,20 +20,6 @@ pub struct Vector4 {
     pub w: f32,
 }
 
-#[derive(Clone, Copy)]
-pub union Vector4Union {
-    matrix: Vector<4>,
-    vector: Vector4,
-}
-
-impl Default for Vector4Union {
-    fn default() -> Self {
-        Self {
-            vector: Default::default(),
-        }
-    }
-}
-
 /// A 4D vector with `f32` components.
 ///
 /// `Vec4` provides convenient methods for 4D vector arithmetic, normalization, and conversion to and from
@@ -44,11 +30,18 @@ impl Default for Vector4Union {
 /// use vectorama::vector::vec4::Vec4;
 /// let v = Vec4::new(1.0, 2.0, 3.0, 4.0);
 /// ```
+#[repr(transparent)]
 #[derive(Default, Clone, Copy)]
 pub struct Vec4 {
-    data: Vector4Union,
+    data: Vector4,
 }
 
+// Fails to compile if `as_array` could not reinterpret a `Vec4` as `[f32; 4]`.
+const _: () = assert!(
+    std::mem::size_of::<Vec4>() == std::mem::size_of::<[f32; 4]>()
+        && std::mem::align_of::<Vec4>() == std::mem::align_of::<[f32; 4]>()
+);
+
 impl Debug for Vec4 {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("Vec4")
@@ -61,6 +54,60 @@ impl Debug for Vec4 {
 }
 
 impl Vec4 {
+    /// A vector with all components set to zero.
+    pub const ZERO: Self = Self::new(0.0, 0.0, 0.0, 0.0);
+
+    /// A vector with all components set to one.
+    pub const ONE: Self = Self::new(1.0, 1.0, 1.0, 1.0);
+
+    /// A vector with all components set to negative one.
+    pub const NEG_ONE: Self = Self::new(-1.0, -1.0, -1.0, -1.0);
+
+    /// A vector with all components set to `f32::MIN`.
+    pub const MIN: Self = Self::new(f32::MIN, f32::MIN, f32::MIN, f32::MIN);
+
+    /// A vector with all components set to `f32::MAX`.
+    pub const MAX: Self = Self::new(f32::MAX, f32::MAX, f32::MAX, f32::MAX);
+
+    /// A vector with all components set to `f32::NAN`.
+    pub const NAN: Self = Self::new(f32::NAN, f32::NAN, f32::NAN, f32::NAN);
+
+    /// A vector with all components set to `f32::INFINITY`.
+    pub const INFINITY: Self =
+        Self::new(f32::INFINITY, f32::INFINITY, f32::INFINITY, f32::INFINITY);
+
+    /// A vector with all components set to `f32::NEG_INFINITY`.
+    pub const NEG_INFINITY: Self = Self::new(
+        f32::NEG_INFINITY,
+        f32::NEG_INFINITY,
+        f32::NEG_INFINITY,
+        f32::NEG_INFINITY,
+    );
+
+    /// A unit vector pointing along the positive X axis.
+    pub const X: Self = Self::new(1.0, 0.0, 0.0, 0.0);
+
+    /// A unit vector pointing along the positive Y axis.
+    pub const Y: Self = Self::new(0.0, 1.0, 0.0, 0.0);
+
+    /// A unit vector pointing along the positive Z axis.
+    pub const Z: Self = Self::new(0.0, 0.0, 1.0, 0.0);
+
+    /// A unit vector pointing along the positive W axis.
+    pub const W: Self = Self::new(0.0, 0.0, 0.0, 1.0);
+
+    /// A unit vector pointing along the negative X axis.
+    pub const NEG_X: Self = Self::new(-1.0, 0.0, 0.0, 0.0);
+
+    /// A unit vector pointing along the negative Y axis.
+    pub const NEG_Y: Self = Self::new(0.0, -1.0, 0.0, 0.0);
+
+    /// A unit vector pointing along the negative Z axis.
+    pub const NEG_Z: Self = Self::new(0.0, 0.0, -1.0, 0.0);
+
+    /// A unit vector pointing along the negative W axis.
+    pub const NEG_W: Self = Self::new(0.0, 0.0, 0.0, -1.0);
+
     /// Creates a new 4D vector from `x`, `y`, `z`, and `w` components.
     ///
     /// # Parameters
@@ -73,105 +120,23 @@ impl Vec4 {
     /// A new `Vec4` with the specified components.
     pub const fn new(x: f32, y: f32, z: f32, w: f32) -> Self {
         let vector4 = Vector4 { x, y, z, w };
-        Self {
-            data: Vector4Union { vector: vector4 },
-        }
+        Self { data: vector4 }
     }
 
     /// Returns a vector with all components set to zero.
     ///
     /// # Returns
     /// A zero vector.
-    pub fn zeros() -> Self {
-        Self::from(Vector::zeros())
+    pub const fn zeros() -> Self {
+        Self::ZERO
     }
 
     /// Returns a vector with all components set to one.
     ///
     /// # Returns
     /// A vector with all components equal to 1.0.
-    pub fn ones() -> Self {
-        Self::from(Vector::ones())
-    }
-
-    /// Returns the `x` and `y` components as a `Vec2`.
-    ///
-    /// # Returns
-    /// A `Vec2` containing the x and y components.
-    pub fn xy(&self) -> Vec2 {
-        Vec2::new(self.x, self.y)
-    }
-
-    /// Returns the `x` and `z` components as a `Vec2`.
-    ///
-    /// # Returns
-    /// A `Vec2` containing the x and z components.
-    pub fn xz(&self) -> Vec2 {
-        Vec2::new(self.x, self.z)
-    }
-
-    /// Returns the `x` and `w` components as a `Vec2`.
-    ///
-    /// # Returns
-    /// A `Vec2` containing the x and w components.
-    pub fn xw(&self) -> Vec2 {
-        Vec2::new(self.x, self.w)
-    }
-
-    /// Returns the `y` and `z` components as a `Vec2`.
-    ///
-    /// # Returns
-    /// A `Vec2` containing the y and z components.
-    pub fn yz(&self) -> Vec2 {
-        Vec2::new(self.y, self.z)
-    }
-
-    /// Returns the `y` and `w` components as a `Vec2`.
-    ///
-    /// # Returns
-    /// A `Vec2` containing the y and w components.
-    pub fn yw(&self) -> Vec2 {
-        Vec2::new(self.y, self.w)
-    }
-
-    /// Returns the `z` and `w` components as a `Vec2`.
-    ///
-    /// # Returns
-    /// A `Vec2` containing the z and w components.
-    pub fn zw(&self) -> Vec2 {
-        Vec2::new(self.z, self.w)
-    }
-
-    /// Returns the `x`, `y`, and `z` components as a `Vec3`.
-    ///
-    /// # Returns
-    /// A `Vec3` containing the x, y, and z components.
-    pub fn xyz(&self) -> Vec3 {
-        Vec3::new(self.x, self.y, self.z)
-    }
-
-    /// Returns the `x`, `y`, and `w` components as a `Vec3`.
-    ///
-    /// # Returns
-    /// A `Vec3` containing the x, y, and w components.
-    pub fn xyw(&self) -> Vec3 {
-        Vec3::new(self.x, self.y, self.w)
-    }
-
-    /// Returns the `x`, `z`, and `w` components as a `Vec3`.
-    ///
-    /// # Returns
-    /// A `Vec3` containing the x, z, and w components.
-    pub fn xzw(&self) -> Vec3 {
-        Vec3::new(self.x, self.z, self.w)
-    }
-
-    /// Returns the `y`, `z`, and `w` components as a `Vec3`.
-    ///
-    /// # Returns
-    /// A `Vec3` containing the y, z, and w components.
-    pub fn yzw(&self) -> Vec3 {
-        Vec3::new(self.y, self.z, self.w)
+    pub const fn ones() -> Self {
+        Self::ONE
     }
 
     /// Computes the magnitude (length) of the vector.
@@ -179,7 +144,7 @@ impl Vec4 {
     /// # Returns
     /// The magnitude as a `f32`.
     pub fn magnitude(&self) -> f32 {
-        unsafe { self.data.matrix.magnitude() }
+        self.matrix().magnitude()
     }
 
     /// Returns a normalized (unit length) version of this vector.
@@ -187,9 +152,26 @@ impl Vec4 {
     /// # Returns
     /// The normalized vector.
     pub fn normalize(&self) -> Vec4 {
-        let matrix = unsafe { self.data.matrix.normalize() };
+        let matrix = self.matrix().normalize();
         Vec4::from(matrix)
     }
+
+    /// Returns a reference to the components as an array.
+    ///
+    /// # Returns
+    /// A reference to the `[x, y, z, w]` components, borrowed from this vector.
+    pub const fn as_array(&self) -> &[f32; 4] {
+        // SAFETY: `Vec4` is `repr(transparent)` over `Vector4`, which is `repr(C)` with 4 `f32`
+        // fields and therefore has the same size, alignment and layout as `[f32; 4]`; this is
+        // asserted at compile time next to the struct. A safe borrow would need a layout trait
+        // such as zerocopy's implemented on the public `Vector4`, making it part of the API.
+        unsafe { &*(self as *const Self as *const [f32; 4]) }
+    }
+
+    /// Returns the components as a `Vector<4>` (a `Matrix<4, 1>`).
+    fn matrix(&self) -> Vector<4> {
+        Vector::from([self.data.x, self.data.y, self.data.z, self.data.w])
+    }
 }
 
 // Deref and DerefMut -----------------------------------------------------------------------------
@@ -197,13 +179,13 @@ impl Deref for Vec4 {
     type Target = Vector4;
 
     fn deref(&self) -> &Self::Target {
-        unsafe { &self.data.vector }
+        &self.data
     }
 }
 
 impl DerefMut for Vec4 {
     fn deref_mut(&mut self) -> &mut Self::Target {
-        unsafe { &mut self.data.vector }
+        &mut self.data
     }
 }
 // Deref and DerefMut -----------------------------------------------------------------------------
@@ -211,21 +193,25 @@ impl DerefMut for Vec4 {
 // From -------------------------------------------------------------------------------------------
 impl From<Vector<4>> for Vec4 {
     fn from(value: Vector<4>) -> Self {
-        Self {
-            data: Vector4Union { matrix: value },
-        }
+        Self::from(<[f32; 4]>::from(value))
     }
 }
 
 impl From<[f32; 4]> for Vec4 {
     fn from(value: [f32; 4]) -> Self {
-        Self::from(Vector::from(value))
+        Self::new(value[0], value[1], value[2], value[3])
+    }
+}
+
+impl From<Vec4> for [f32; 4] {
+    fn from(value: Vec4) -> Self {
+        *value.as_array()
     }
 }
 
 impl From<Vec4> for Vector<4> {
     fn from(value: Vec4) -> Self {
-        unsafe { value.data.matrix }
+        value.matrix()
     }
 }
 // From -------------------------------------------------------------------------------------------
@@ -233,7 +219,7 @@ impl From<Vec4> for Vector<4> {
 // AsRef ------------------------------------------------------------------------------------------
 impl AsRef<[f32]> for Vec4 {
     fn as_ref(&self) -> &[f32] {
-        unsafe { self.data.matrix.as_ref() }
+        self.as_array()
     }
 }
 // AsRef ------------------------------------------------------------------------------------------
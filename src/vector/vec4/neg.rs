@@ -6,7 +6,7 @@ impl Neg for Vec4 {
     type Output = Vec4;
 
     fn neg(self) -> Self::Output {
-        let matrix = unsafe { -self.data.matrix };
+        let matrix = -self.matrix();
         Vec4::from(matrix)
     }
 }
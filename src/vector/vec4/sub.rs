@@ -7,7 +7,7 @@ impl Sub<&Vec4> for &Vec4 {
     type Output = Vec4;
 
     fn sub(self, rhs: &Vec4) -> Self::Output {
-        let matrix = unsafe { self.data.matrix - rhs.data.matrix };
+        let matrix = self.matrix() - rhs.matrix();
         Vec4::from(matrix)
     }
 }
@@ -40,7 +40,7 @@ impl Sub<Vec4> for Vec4 {
 
 impl SubAssign<&Vec4> for Vec4 {
     fn sub_assign(&mut self, rhs: &Vec4) {
-        unsafe { self.data.matrix -= rhs.data.matrix }
+        *self = *self - rhs
     }
 }
 
@@ -0,0 +1,359 @@
+mod common;
+
+#[cfg(test)]
+mod tests {
+    use crate::common::assert_vec3_eq;
+    use approx::assert_relative_eq;
+    use vectorama::animation::easing::{CubicBezier, Easing};
+    use vectorama::animation::tween::Tween;
+    use vectorama::animation::{Animatable, VectorSpace, spring};
+    use vectorama::quaternion::unit::UnitQuaternion;
+    use vectorama::scale::scale3::Scale3;
+    use vectorama::translation::translation3::Translation3;
+    use vectorama::vector::{vec2::Vec2, vec3::Vec3, vec4::Vec4};
+
+    #[test]
+    fn test_animatable_round_trip() {
+        let a = Vec4::new(1.0, -2.0, 0.5, 3.0);
+        let b = Vec4::new(0.0, 4.0, 1.5, -1.0);
+        assert_eq!(a.offset(b.difference(&a)).as_array(), b.as_array());
+        assert_relative_eq!(a.dot(&b), VectorSpace::dot(&a, &b));
+        assert_relative_eq!(VectorSpace::magnitude(&-3.0f32), 3.0);
+
+        let from = UnitQuaternion::from_euler_angles(0.3, -1.2, 0.4);
+        let to = UnitQuaternion::from_euler_angles(-0.5, 2.0, 0.1);
+        let delta = to.difference(&from);
+        assert_relative_eq!(delta.magnitude(), from.angle_to(&to), epsilon = 1e-5);
+        assert_relative_eq!(from.offset(delta).angle_to(&to), 0.0, epsilon = 1e-3);
+    }
+
+    #[test]
+    fn test_exponential_decay_is_frame_rate_independent() {
+        let one_step = spring::exponential_decay(0.0f32, 10.0, 2.0, 1.0);
+        let mut many_steps = 0.0f32;
+        for _ in 0..100 {
+            many_steps = spring::exponential_decay(many_steps, 10.0, 2.0, 0.01);
+        }
+        assert_relative_eq!(one_step, 10.0 * (1.0 - (-2.0f32).exp()), epsilon = 1e-5);
+        assert_relative_eq!(many_steps, one_step, epsilon = 1e-4);
+
+        // Rotations decay along the shortest arc.
+        let target = UnitQuaternion::from_z_axis(1.0);
+        let rotation = spring::exponential_decay(UnitQuaternion::IDENTITY, target, 2.0, 1.0);
+        let expected = UnitQuaternion::from_z_axis(1.0 - (-2.0f32).exp());
+        assert_relative_eq!(rotation.angle_to(&expected), 0.0, epsilon = 1e-3);
+    }
+
+    #[test]
+    fn test_critically_damped_spring() {
+        let target = Vec2::new(4.0, -2.0);
+
+        // The exact solution gives the same result for any subdivision of the time.
+        let mut velocity = Vec2::ZERO;
+        let one_step =
+            spring::critically_damped_spring(Vec2::ZERO, target, &mut velocity, 3.0, 1.0);
+        let mut many_velocity = Vec2::ZERO;
+        let mut many_steps = Vec2::ZERO;
+        for _ in 0..50 {
+            many_steps =
+                spring::critically_damped_spring(many_steps, target, &mut many_velocity, 3.0, 0.02);
+        }
+        assert_relative_eq!(many_steps.x, one_step.x, epsilon = 1e-4);
+        assert_relative_eq!(many_velocity.y, velocity.y, epsilon = 1e-4);
+
+        // From rest, (1 + ωt) e^(-ωt) of the distance remains and the target is never passed.
+        let remaining = (1.0 + 3.0) * (-3.0f32).exp();
+        assert_relative_eq!(one_step.x, 4.0 * (1.0 - remaining), epsilon = 1e-5);
+        let mut value = 0.0f32;
+        let mut speed = 0.0;
+        for _ in 0..200 {
+            value = spring::critically_damped_spring(value, 1.0, &mut speed, 5.0, 0.05);
+            assert!(value <= 1.0 + 1e-6);
+        }
+        assert_relative_eq!(value, 1.0, epsilon = 1e-5);
+    }
+
+    #[test]
+    fn test_smooth_damp() {
+        let target = Vec3::new(10.0, 0.0, -5.0);
+        let mut position = Vec3::ZERO;
+        let mut velocity = Vec3::ZERO;
+        for _ in 0..240 {
+            let previous = position;
+            position = spring::smooth_damp(
+                position,
+                target,
+                &mut velocity,
+                0.5,
+                f32::INFINITY,
+                1.0 / 60.0,
+            );
+            // Each frame moves monotonically towards the target.
+            assert!(position.distance(&target) <= previous.distance(&target));
+        }
+        assert_vec3_eq(position, target, 1e-3);
+
+        // Roughly frame-rate independent.
+        let mut coarse = 0.0f32;
+        let mut coarse_velocity = 0.0;
+        for _ in 0..10 {
+            coarse =
+                spring::smooth_damp(coarse, 1.0, &mut coarse_velocity, 0.5, f32::INFINITY, 0.05);
+        }
+        let mut fine = 0.0f32;
+        let mut fine_velocity = 0.0;
+        for _ in 0..100 {
+            fine = spring::smooth_damp(fine, 1.0, &mut fine_velocity, 0.5, f32::INFINITY, 0.005);
+        }
+        assert_relative_eq!(coarse, fine, epsilon = 1e-2);
+    }
+
+    #[test]
+    fn test_smooth_damp_max_speed() {
+        let mut position = 0.0f32;
+        let mut velocity = 0.0;
+        for _ in 0..10 {
+            let previous = position;
+            position = spring::smooth_damp(position, 100.0, &mut velocity, 0.1, 2.0, 0.1);
+            assert!(position - previous <= 2.0 * 0.1 + 1e-5);
+        }
+        assert!(velocity <= 2.0 + 1e-5);
+    }
+
+    #[test]
+    fn test_springs_are_stable_at_large_dt() {
+        for dt in [1.0, 10.0, 1000.0] {
+            let mut velocity = Vec3::new(50.0, -20.0, 0.0);
+            let position =
+                spring::smooth_damp(Vec3::ZERO, Vec3::ONE, &mut velocity, 0.1, f32::INFINITY, dt);
+            // The rational approximation of the exponential stays bounded rather than exact.
+            assert!(position.distance(&Vec3::ONE) < 0.05);
+            assert!(velocity.magnitude() < 1.0);
+
+            let mut velocity = Vec3::new(50.0, -20.0, 0.0);
+            let position =
+                spring::critically_damped_spring(Vec3::ZERO, Vec3::ONE, &mut velocity, 10.0, dt);
+            assert_vec3_eq(position, Vec3::ONE, 1e-2);
+            assert!(velocity.magnitude() < 0.05);
+
+            let position = spring::exponential_decay(Vec4::ZERO, Vec4::ONE, 10.0, dt);
+            assert_relative_eq!(position.w, 1.0, epsilon = 1e-4);
+        }
+    }
+
+    #[test]
+    fn test_rotation_springs() {
+        let target = UnitQuaternion::from_euler_angles(0.4, 2.5, -0.3);
+
+        let mut rotation = UnitQuaternion::IDENTITY;
+        let mut angular_velocity = Vec3::ZERO;
+        for _ in 0..120 {
+            rotation = spring::smooth_damp(
+                rotation,
+                target,
+                &mut angular_velocity,
+                0.25,
+                f32::INFINITY,
+                1.0 / 60.0,
+            );
+        }
+        assert!(rotation.angle_to(&target) < 1e-3);
+
+        // A single-axis rotation follows the scalar spring exactly.
+        let target = UnitQuaternion::from_y_axis(1.5);
+        let mut rotation = UnitQuaternion::IDENTITY;
+        let mut angular_velocity = Vec3::ZERO;
+        let mut angle = 0.0f32;
+        let mut speed = 0.0;
+        for _ in 0..30 {
+            rotation = spring::critically_damped_spring(
+                rotation,
+                target,
+                &mut angular_velocity,
+                4.0,
+                0.02,
+            );
+            angle = spring::critically_damped_spring(angle, 1.5, &mut speed, 4.0, 0.02);
+        }
+        let expected = UnitQuaternion::from_y_axis(angle);
+        assert!(rotation.angle_to(&expected) < 1e-3);
+        assert_vec3_eq(angular_velocity, Vec3::new(0.0, speed, 0.0), 1e-3);
+
+        // Large steps land on the target.
+        let mut angular_velocity = Vec3::new(0.0, 0.0, 30.0);
+        let rotation = spring::critically_damped_spring(
+            UnitQuaternion::IDENTITY,
+            target,
+            &mut angular_velocity,
+            8.0,
+            100.0,
+        );
+        assert!(rotation.angle_to(&target) < 1e-3);
+    }
+
+    const PENNER: [(Easing, Easing, Easing); 10] = [
+        (Easing::QuadIn, Easing::QuadOut, Easing::QuadInOut),
+        (Easing::CubicIn, Easing::CubicOut, Easing::CubicInOut),
+        (Easing::QuartIn, Easing::QuartOut, Easing::QuartInOut),
+        (Easing::QuintIn, Easing::QuintOut, Easing::QuintInOut),
+        (Easing::SineIn, Easing::SineOut, Easing::SineInOut),
+        (Easing::ExpoIn, Easing::ExpoOut, Easing::ExpoInOut),
+        (Easing::CircIn, Easing::CircOut, Easing::CircInOut),
+        (Easing::BackIn, Easing::BackOut, Easing::BackInOut),
+        (Easing::ElasticIn, Easing::ElasticOut, Easing::ElasticInOut),
+        (Easing::BounceIn, Easing::BounceOut, Easing::BounceInOut),
+    ];
+
+    #[test]
+    fn test_easing_endpoints_and_symmetry() {
+        for (ease_in, ease_out, ease_in_out) in PENNER {
+            for easing in [ease_in, ease_out, ease_in_out] {
+                assert_relative_eq!(easing.ease(0.0), 0.0, epsilon = 1e-6);
+                assert_relative_eq!(easing.ease(1.0), 1.0, epsilon = 1e-6);
+                // Progress outside [0, 1] is clamped.
+                assert_relative_eq!(easing.ease(-1.0), 0.0, epsilon = 1e-6);
+                assert_relative_eq!(easing.ease(2.0), 1.0, epsilon = 1e-6);
+            }
+            assert_relative_eq!(ease_in_out.ease(0.5), 0.5, epsilon = 1e-6);
+            for t in [0.1, 0.3, 0.45, 0.8] {
+                assert_relative_eq!(
+                    ease_out.ease(t),
+                    1.0 - ease_in.ease(1.0 - t),
+                    epsilon = 1e-6
+                );
+                assert_relative_eq!(
+                    ease_in_out.ease(t),
+                    1.0 - ease_in_out.ease(1.0 - t),
+                    epsilon = 1e-6
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_easing_shapes() {
+        assert_relative_eq!(Easing::Linear.ease(0.3), 0.3);
+        assert_relative_eq!(Easing::CubicIn.ease(0.5), 0.125);
+        assert_relative_eq!(Easing::QuintInOut.ease(0.25), 0.5 * 0.5f32.powi(5));
+        assert_relative_eq!(Easing::SineOut.ease(0.5), std::f32::consts::FRAC_1_SQRT_2);
+        assert_relative_eq!(Easing::ExpoIn.ease(0.5), 2.0f32.powi(-5));
+        assert_relative_eq!(Easing::BounceOut.ease(0.5), 0.765625);
+
+        // Back dips below zero before heading to the end, and overshoots on the way out.
+        assert!(Easing::BackIn.ease(0.3) < 0.0);
+        assert!(Easing::BackOut.ease(0.7) > 1.0);
+        assert!((0..=100).any(|i| Easing::ElasticOut.ease(i as f32 / 100.0) > 1.0));
+    }
+
+    #[test]
+    fn test_back_and_elastic_in_out_match_penner() {
+        // Reference values from Penner's equations.
+        let back = [
+            (0.1, -0.0375186),
+            (0.25, -0.0996818),
+            (0.4, 0.0899258),
+            (0.75, 1.0996818),
+        ];
+        for (t, expected) in back {
+            assert_relative_eq!(Easing::BackInOut.ease(t), expected, epsilon = 1e-5);
+        }
+        let elastic = [
+            (0.25, 0.0119694),
+            (0.4, -0.1174616),
+            (0.6, 1.1174616),
+            (0.9, 0.9996608),
+        ];
+        for (t, expected) in elastic {
+            assert_relative_eq!(Easing::ElasticInOut.ease(t), expected, epsilon = 1e-5);
+        }
+    }
+
+    #[test]
+    fn test_cubic_bezier() {
+        let linear = CubicBezier::new(0.0, 0.0, 1.0, 1.0);
+        for t in [0.0, 0.2, 0.5, 0.9, 1.0] {
+            assert_relative_eq!(linear.ease(t), t, epsilon = 1e-5);
+        }
+
+        // x1 and x2 are clamped to keep the curve a function of time.
+        let clamped = CubicBezier::new(-1.0, 0.0, 2.0, 1.0);
+        assert_eq!((clamped.x1, clamped.x2), (0.0, 1.0));
+
+        // Reference values from a browser's `ease` timing function.
+        let ease = Easing::from(CubicBezier::EASE);
+        assert_relative_eq!(ease.ease(0.25), 0.4085, epsilon = 1e-3);
+        assert_relative_eq!(ease.ease(0.5), 0.8024, epsilon = 1e-3);
+        assert_relative_eq!(CubicBezier::EASE_IN_OUT.ease(0.5), 0.5, epsilon = 1e-5);
+        assert_relative_eq!(
+            CubicBezier::EASE_IN.ease(0.3),
+            1.0 - CubicBezier::EASE_OUT.ease(0.7),
+            epsilon = 1e-5
+        );
+
+        // Overshooting y control points leave [0, 1].
+        let overshoot = CubicBezier::new(0.3, 1.5, 0.6, 1.5);
+        assert!(overshoot.ease(0.7) > 1.0);
+        for t in 0..=20 {
+            let t = t as f32 / 20.0;
+            let value = CubicBezier::EASE.ease(t);
+            assert!(value >= CubicBezier::EASE.ease((t - 0.05).max(0.0)));
+        }
+    }
+
+    #[test]
+    fn test_tween_vectors_and_transforms() {
+        let mut tween = Tween::new(
+            Vec3::new(1.0, 2.0, 3.0),
+            Vec3::new(3.0, 2.0, -1.0),
+            2.0,
+            Easing::Linear,
+        );
+        assert_eq!(tween.value().as_array(), &[1.0, 2.0, 3.0]);
+        assert_vec3_eq(tween.advance(0.5), Vec3::new(1.5, 2.0, 2.0), 1e-6);
+        assert_relative_eq!(tween.progress(), 0.25);
+        assert_vec3_eq(tween.sample(1.0), Vec3::new(2.0, 2.0, 1.0), 1e-6);
+        assert_relative_eq!(tween.elapsed(), 0.5);
+
+        assert_vec3_eq(tween.advance(10.0), Vec3::new(3.0, 2.0, -1.0), 1e-6);
+        assert!(tween.is_finished());
+        assert_relative_eq!(tween.elapsed(), 2.0);
+        tween.reset();
+        assert!(!tween.is_finished());
+
+        let tween = Tween::new(
+            Translation3::from(Vec3::ZERO),
+            Translation3::from(Vec3::new(4.0, 0.0, 0.0)),
+            1.0,
+            Easing::QuadOut,
+        );
+        assert_relative_eq!(tween.sample(0.5).x, 3.0);
+
+        let tween = Tween::new(
+            Scale3::from(Vec3::ONE),
+            Scale3::from(Vec3::new(2.0, 3.0, 1.0)),
+            1.0,
+            Easing::CubicIn,
+        );
+        assert_vec3_eq(*tween.sample(0.5), Vec3::new(1.125, 1.25, 1.0), 1e-6);
+
+        let tween = Tween::new(Vec2::ZERO, Vec2::ONE, 0.0, Easing::BackIn);
+        assert!(tween.is_finished());
+        assert_eq!(tween.value().as_array(), &[1.0, 1.0]);
+    }
+
+    #[test]
+    fn test_tween_rotation() {
+        let start = UnitQuaternion::from_x_axis(0.2);
+        let end = UnitQuaternion::from_euler_angles(1.0, -0.5, 2.0);
+        let mut tween = Tween::new(start, end, 1.0, Easing::SineInOut);
+        let eased = Easing::SineInOut.ease(0.3);
+        let rotation = tween.advance(0.3);
+        assert!(rotation.angle_to(&start.slerp(&end, eased)) < 1e-3);
+        assert_relative_eq!(
+            rotation.angle_to(&start),
+            eased * start.angle_to(&end),
+            epsilon = 1e-3
+        );
+        assert!(tween.advance(1.0).angle_to(&end) < 1e-3);
+    }
+}
@@ -0,0 +1,9 @@
+use approx::assert_relative_eq;
+use vectorama::vector::vec3::Vec3;
+
+/// Asserts that every component of `actual` is within `epsilon` of `expected`.
+pub fn assert_vec3_eq(actual: Vec3, expected: Vec3, epsilon: f32) {
+    assert_relative_eq!(actual.x, expected.x, epsilon = epsilon);
+    assert_relative_eq!(actual.y, expected.y, epsilon = epsilon);
+    assert_relative_eq!(actual.z, expected.z, epsilon = epsilon);
+}
@@ -0,0 +1,567 @@
+mod common;
+
+#[cfg(test)]
+mod tests {
+    use crate::common::assert_vec3_eq;
+    use approx::assert_relative_eq;
+    use std::f32::consts::{FRAC_PI_2, FRAC_PI_4, FRAC_PI_8};
+    use vectorama::curve::b_spline::BSpline;
+    use vectorama::curve::bezier::{Bezier, CubicBezierCurve, QuadraticBezierCurve};
+    use vectorama::curve::catmull_rom::{CatmullRom, Parameterization};
+    use vectorama::curve::frame::Frame;
+    use vectorama::curve::hermite::{Hermite, HermiteSpline};
+    use vectorama::curve::nurbs::Nurbs;
+    use vectorama::curve::{Curve, CurvePoint};
+    use vectorama::quaternion::unit::UnitQuaternion;
+    use vectorama::vector::{vec2::Vec2, vec3::Vec3};
+
+    fn assert_vec2_eq(actual: Vec2, expected: Vec2, epsilon: f32) {
+        assert_relative_eq!(actual.x, expected.x, epsilon = epsilon);
+        assert_relative_eq!(actual.y, expected.y, epsilon = epsilon);
+    }
+
+    fn quarter_circle() -> Nurbs {
+        let points = [
+            Vec3::new(1.0, 0.0, 0.0),
+            Vec3::new(1.0, 1.0, 0.0),
+            Vec3::new(0.0, 1.0, 0.0),
+        ];
+        Nurbs::clamped(2, &points, &[1.0, 0.5f32.sqrt(), 1.0]).unwrap()
+    }
+
+    fn twisted() -> CubicBezierCurve<Vec3> {
+        CubicBezierCurve::new([
+            Vec3::new(0.0, 0.0, 0.0),
+            Vec3::new(1.0, 2.0, 0.0),
+            Vec3::new(2.0, -1.0, 2.0),
+            Vec3::new(3.0, 1.0, 3.0),
+        ])
+    }
+
+    fn assert_orthonormal(frame: &Frame) {
+        assert_relative_eq!(frame.tangent.magnitude(), 1.0, epsilon = 1e-4);
+        assert_relative_eq!(frame.normal.magnitude(), 1.0, epsilon = 1e-4);
+        assert!(frame.tangent.dot(&frame.normal).abs() < 1e-4);
+        assert_vec3_eq(frame.binormal, frame.tangent.cross(&frame.normal), 1e-5);
+    }
+
+    fn waypoints() -> Vec<Vec2> {
+        vec![
+            Vec2::new(0.0, 0.0),
+            Vec2::new(1.0, 2.0),
+            Vec2::new(1.5, 2.1),
+            Vec2::new(5.0, 0.0),
+            Vec2::new(6.0, 3.0),
+        ]
+    }
+
+    fn s_curve() -> CubicBezierCurve<Vec2> {
+        CubicBezierCurve::new([
+            Vec2::new(0.0, 0.0),
+            Vec2::new(2.0, 3.0),
+            Vec2::new(-1.0, -2.0),
+            Vec2::new(3.0, 1.0),
+        ])
+    }
+
+    /// Evaluates the cubic Bernstein form directly.
+    fn bernstein(curve: &CubicBezierCurve<Vec2>, t: f32) -> Vec2 {
+        let s = 1.0 - t;
+        let [p0, p1, p2, p3] = curve.points;
+        p0 * (s * s * s) + p1 * (3.0 * s * s * t) + p2 * (3.0 * s * t * t) + p3 * (t * t * t)
+    }
+
+    #[test]
+    fn test_evaluate() {
+        let curve = s_curve();
+        assert_eq!(curve.degree(), 3);
+        for t in [0.0, 0.2, 0.5, 0.75, 1.0] {
+            assert_vec2_eq(curve.evaluate(t), bernstein(&curve, t), 1e-5);
+        }
+
+        let quadratic = QuadraticBezierCurve::new([
+            Vec3::ZERO,
+            Vec3::new(1.0, 2.0, 0.0),
+            Vec3::new(2.0, 0.0, 4.0),
+        ]);
+        let midpoint = quadratic.evaluate(0.5);
+        assert_eq!(midpoint.as_array(), &[1.0, 1.0, 1.0]);
+
+        // A curve with collinear, evenly spaced control points is a straight line at unit speed.
+        let line = Bezier::from([0.0, 1.0, 2.0, 3.0, 4.0, 5.0].map(|x| Vec2::new(x, 0.0)));
+        assert_vec2_eq(line.evaluate(0.3), Vec2::new(1.5, 0.0), 1e-5);
+        assert_vec2_eq(line.derivative(0.8), Vec2::new(5.0, 0.0), 1e-5);
+    }
+
+    #[test]
+    fn test_derivatives() {
+        let curve = s_curve();
+        let h = 1e-2;
+        for t in [0.1, 0.4, 0.9] {
+            let central = (bernstein(&curve, t + h) - bernstein(&curve, t - h)) * (0.5 / h);
+            assert_vec2_eq(curve.derivative(t), central, 1e-2);
+            let second = (curve.derivative(t + h) - curve.derivative(t - h)) * (0.5 / h);
+            assert_vec2_eq(curve.second_derivative(t), second, 1e-2);
+        }
+        // The endpoint tangents point along the first and last legs of the control polygon.
+        assert_vec2_eq(curve.derivative(0.0), Vec2::new(6.0, 9.0), 1e-5);
+        assert_vec2_eq(curve.derivative(1.0), Vec2::new(12.0, 9.0), 1e-5);
+        // The third derivative of a cubic is constant and the fourth vanishes.
+        let third = curve.nth_derivative(3, 0.0);
+        assert_vec2_eq(curve.nth_derivative(3, 0.7), third, 1e-4);
+        assert_vec2_eq(curve.nth_derivative(4, 0.5), Vec2::ZERO, 0.0);
+        assert_vec2_eq(curve.nth_derivative(0, 0.5), curve.evaluate(0.5), 0.0);
+    }
+
+    #[test]
+    fn test_split() {
+        let curve = s_curve();
+        let (left, right) = curve.split(0.3);
+        assert_vec2_eq(left.points[0], curve.points[0], 0.0);
+        assert_vec2_eq(right.points[3], curve.points[3], 0.0);
+        assert_vec2_eq(left.points[3], curve.evaluate(0.3), 1e-6);
+        assert_vec2_eq(right.points[0], curve.evaluate(0.3), 1e-6);
+        for u in [0.25, 0.5, 0.9] {
+            assert_vec2_eq(left.evaluate(u), curve.evaluate(0.3 * u), 1e-5);
+            assert_vec2_eq(right.evaluate(u), curve.evaluate(0.3 + 0.7 * u), 1e-5);
+        }
+    }
+
+    #[test]
+    fn test_bounding_box() {
+        let curve = s_curve();
+        let (min, max) = curve.bounding_box();
+        let mut sampled_min = curve.points[0];
+        let mut sampled_max = curve.points[0];
+        for i in 0..=1000 {
+            let point = curve.evaluate(i as f32 / 1000.0);
+            sampled_min = CurvePoint::min(&sampled_min, &point);
+            sampled_max = CurvePoint::max(&sampled_max, &point);
+        }
+        assert_vec2_eq(min, sampled_min, 1e-4);
+        assert_vec2_eq(max, sampled_max, 1e-4);
+        // Tighter than the control polygon.
+        assert!(max.y < 3.0 && min.y > -2.0);
+
+        // Axes without extrema and degenerate curves are bounded by their endpoints.
+        let flat = QuadraticBezierCurve::new([Vec3::ZERO, Vec3::new(1.0, 0.0, 5.0), Vec3::ONE]);
+        let (min, max) = flat.bounding_box();
+        assert_eq!(min.as_array(), &[0.0, 0.0, 0.0]);
+        assert_eq!(max.y, 1.0);
+        assert_relative_eq!(max.z, 25.0 / 9.0, epsilon = 1e-5);
+        let point = Bezier::new([Vec2::new(1.0, 2.0)]);
+        assert_eq!(point.bounding_box().1.as_array(), &[1.0, 2.0]);
+    }
+
+    #[test]
+    fn test_closest_point() {
+        let curve = s_curve();
+        for t in [0.0, 0.15, 0.5, 0.8, 1.0] {
+            let on_curve = curve.evaluate(t);
+            let (found, point) = curve.closest_point(&on_curve);
+            assert_relative_eq!(found, t, epsilon = 1e-3);
+            assert_vec2_eq(point, on_curve, 1e-4);
+        }
+
+        // Off the curve, the offset is perpendicular to the tangent and no sample is closer.
+        let target = Vec2::new(1.5, 2.0);
+        let (t, point) = curve.closest_point(&target);
+        let offset = point - target;
+        assert!(offset.dot(&curve.derivative(t)).abs() < 1e-3);
+        for i in 0..=500 {
+            let sample = curve.evaluate(i as f32 / 500.0);
+            assert!(offset.magnitude() <= sample.distance(&target) + 1e-5);
+        }
+
+        // Points beyond the ends project onto the endpoints.
+        let (t, _) = curve.closest_point(&Vec2::new(-5.0, -5.0));
+        assert_eq!(t, 0.0);
+    }
+
+    #[test]
+    fn test_flatten() {
+        let curve = s_curve();
+        for tolerance in [0.1, 0.01, 0.001] {
+            let polyline = curve.flatten(tolerance);
+            assert_vec2_eq(polyline[0], curve.points[0], 0.0);
+            assert_vec2_eq(*polyline.last().unwrap(), curve.points[3], 0.0);
+            for i in 0..=200 {
+                let point = curve.evaluate(i as f32 / 200.0);
+                let distance = polyline
+                    .windows(2)
+                    .map(|segment| point.distance_to_segment(&segment[0], &segment[1]))
+                    .fold(f32::INFINITY, f32::min);
+                assert!(distance <= tolerance + 1e-5);
+            }
+        }
+        assert!(curve.flatten(0.001).len() > curve.flatten(0.1).len());
+
+        // A straight curve needs a single segment.
+        let line =
+            QuadraticBezierCurve::new([Vec2::ZERO, Vec2::new(1.0, 1.0), Vec2::new(2.0, 2.0)]);
+        assert_eq!(line.flatten(1e-3).len(), 2);
+    }
+
+    #[test]
+    fn test_hermite() {
+        let segment = Hermite::new(
+            Vec2::new(0.0, 0.0),
+            Vec2::new(3.0, 0.0),
+            Vec2::new(2.0, 1.0),
+            Vec2::new(0.0, 6.0),
+        );
+        assert_vec2_eq(segment.evaluate(0.0), segment.start, 0.0);
+        assert_vec2_eq(segment.evaluate(1.0), segment.end, 1e-6);
+        assert_vec2_eq(segment.derivative(0.0), segment.start_tangent, 1e-6);
+        assert_vec2_eq(segment.derivative(1.0), segment.end_tangent, 1e-6);
+
+        let bezier = segment.to_bezier();
+        for t in [0.1, 0.5, 0.7] {
+            assert_vec2_eq(bezier.evaluate(t), segment.evaluate(t), 1e-5);
+            assert_vec2_eq(bezier.derivative(t), segment.derivative(t), 1e-4);
+        }
+
+        let points = waypoints();
+        let tangents = vec![Vec2::new(1.0, 1.0); points.len()];
+        assert!(HermiteSpline::new(points.clone(), tangents[1..].to_vec()).is_none());
+        assert!(HermiteSpline::new(vec![Vec2::ZERO], vec![Vec2::ZERO]).is_none());
+        let spline = HermiteSpline::new(points.clone(), tangents).unwrap();
+        assert_eq!(spline.segment_count(), 4);
+        assert_eq!(spline.domain(), (0.0, 4.0));
+        for (i, point) in points.iter().enumerate() {
+            assert_vec2_eq(spline.evaluate(i as f32), *point, 1e-5);
+            assert_vec2_eq(spline.derivative(i as f32), Vec2::new(1.0, 1.0), 1e-4);
+        }
+        assert_vec2_eq(spline.evaluate(2.5), spline.segment(2).evaluate(0.5), 0.0);
+    }
+
+    #[test]
+    fn test_catmull_rom() {
+        let points = waypoints();
+        assert!(CatmullRom::new(points[..3].to_vec(), Parameterization::Uniform).is_none());
+        assert!(CatmullRom::through(&points[..1], Parameterization::Uniform).is_none());
+
+        for parameterization in [
+            Parameterization::Uniform,
+            Parameterization::Centripetal,
+            Parameterization::Chordal,
+        ] {
+            let spline = CatmullRom::new(points.clone(), parameterization).unwrap();
+            assert_eq!(spline.segment_count(), 2);
+            assert_eq!(spline.parameterization(), parameterization);
+            // Interpolates the inner points with a continuous tangent direction.
+            for i in 0..=2 {
+                assert_vec2_eq(spline.evaluate(i as f32), points[i + 1], 1e-5);
+            }
+            let incoming = spline.segment(0).derivative(1.0);
+            let outgoing = spline.segment(1).derivative(0.0);
+            assert_vec2_eq(incoming.normalize(), outgoing.normalize(), 1e-5);
+
+            let path = CatmullRom::through(&points, parameterization).unwrap();
+            assert_eq!(path.domain(), (0.0, 4.0));
+            for (i, point) in points.iter().enumerate() {
+                assert_vec2_eq(path.evaluate(i as f32), *point, 1e-5);
+            }
+        }
+
+        // Uniform tangents are half the difference of the neighbours and continuous in length.
+        let uniform = CatmullRom::new(points.clone(), Parameterization::Uniform).unwrap();
+        assert_vec2_eq(uniform.derivative(0.0), (points[2] - points[0]) * 0.5, 1e-5);
+        let incoming = uniform.segment(0).derivative(1.0);
+        assert_vec2_eq(incoming, uniform.segment(1).derivative(0.0), 1e-5);
+
+        // The centripetal spline stays closer to the short leg between the close points.
+        let leg = |parameterization| {
+            let spline = CatmullRom::new(points.clone(), parameterization).unwrap();
+            spline
+                .evaluate(1.5)
+                .distance_to_segment(&points[2], &points[3])
+        };
+        assert!(leg(Parameterization::Centripetal) < leg(Parameterization::Uniform));
+        assert!(leg(Parameterization::Chordal) < leg(Parameterization::Centripetal));
+
+        // Repeated points stay finite.
+        let repeated = vec![Vec3::ZERO, Vec3::ZERO, Vec3::ONE, Vec3::ONE];
+        let spline = CatmullRom::new(repeated, Parameterization::Centripetal).unwrap();
+        let midpoint = spline.evaluate(0.5);
+        assert!(midpoint.x.is_finite());
+        assert_vec3_eq(spline.evaluate(1.0), Vec3::ONE, 1e-6);
+    }
+
+    #[test]
+    fn test_b_spline() {
+        let points = waypoints();
+        assert!(BSpline::new(0, points.clone(), vec![0.0; 6]).is_none());
+        assert!(BSpline::new(2, points.clone(), vec![0.0; 7]).is_none());
+        assert!(
+            BSpline::new(
+                2,
+                points.clone(),
+                vec![0.0, 1.0, 2.0, 1.0, 4.0, 5.0, 6.0, 7.0]
+            )
+            .is_none()
+        );
+        assert!(BSpline::uniform(5, points.clone()).is_none());
+        let mut knots: Vec<f32> = (0..8).map(|i| i as f32).collect();
+        knots[1] = f32::NAN;
+        assert!(BSpline::new(2, points.clone(), knots.clone()).is_none());
+        knots[1] = 1.0;
+        knots[7] = f32::INFINITY;
+        assert!(BSpline::new(2, points.clone(), knots).is_none());
+
+        // A uniform cubic B-spline sits at (P0 + 4 P1 + P2) / 6 at the knots.
+        let spline = BSpline::uniform(3, points.clone()).unwrap();
+        assert_eq!(spline.domain(), (3.0, 5.0));
+        let expected = (points[0] + points[1] * 4.0 + points[2]) * (1.0 / 6.0);
+        assert_vec2_eq(spline.evaluate(3.0), expected, 1e-5);
+        let expected = (points[2] + points[3] * 4.0 + points[4]) * (1.0 / 6.0);
+        assert_vec2_eq(spline.evaluate(5.0), expected, 1e-5);
+        assert_vec2_eq(spline.evaluate(9.0), expected, 1e-5);
+
+        // A clamped spline with degree + 1 points is a Bézier curve.
+        let bezier = s_curve();
+        let clamped = BSpline::clamped(3, bezier.points.to_vec()).unwrap();
+        assert_eq!(clamped.knots(), &[0.0, 0.0, 0.0, 0.0, 1.0, 1.0, 1.0, 1.0]);
+        for t in [0.0, 0.3, 0.8, 1.0] {
+            assert_vec2_eq(clamped.evaluate(t), bezier.evaluate(t), 1e-5);
+            assert_vec2_eq(clamped.derivative(t), bezier.derivative(t), 1e-3);
+        }
+
+        // Non-uniform knots, including a repeated interior knot.
+        let knots = vec![0.0, 0.0, 0.0, 0.5, 0.5, 2.0, 2.0, 2.0];
+        let spline = BSpline::new(2, points.clone(), knots).unwrap();
+        assert_eq!(spline.degree(), 2);
+        assert_vec2_eq(spline.evaluate(0.0), points[0], 1e-6);
+        assert_vec2_eq(spline.evaluate(2.0), points[4], 1e-6);
+        // A knot of multiplicity equal to the degree makes the curve pass through a point.
+        assert_vec2_eq(spline.evaluate(0.5), points[2], 1e-6);
+        let h = 1e-3;
+        for u in [0.2, 1.0, 1.7] {
+            let central = (spline.evaluate(u + h) - spline.evaluate(u - h)) * (0.5 / h);
+            assert_vec2_eq(spline.derivative(u), central, 2e-2);
+        }
+    }
+
+    #[test]
+    fn test_nurbs() {
+        let points = [
+            Vec3::new(1.0, 0.0, 0.0),
+            Vec3::new(1.0, 1.0, 0.0),
+            Vec3::new(0.0, 1.0, 0.0),
+        ];
+        let weights = [1.0, 0.5f32.sqrt(), 1.0];
+        assert!(Nurbs::clamped(2, &points, &weights[..2]).is_none());
+        assert!(Nurbs::clamped(2, &points, &[1.0, 0.0, 1.0]).is_none());
+        assert!(Nurbs::new(2, &points, &weights, vec![0.0, 0.0, 0.0, 1.0, 1.0]).is_none());
+        let knots = vec![0.0, 0.0, 0.0, f32::NAN, 1.0, 1.0];
+        assert!(Nurbs::new(2, &points, &weights, knots).is_none());
+
+        // Conics are exact: this is a quarter of the unit circle.
+        let arc = Nurbs::clamped(2, &points, &weights).unwrap();
+        assert_eq!(arc.degree(), 2);
+        assert_eq!(arc.domain(), (0.0, 1.0));
+        assert_vec3_eq(arc.evaluate(0.0), points[0], 1e-6);
+        assert_vec3_eq(arc.evaluate(1.0), points[2], 1e-6);
+        let h = 1e-3;
+        for u in [0.0, 0.25, 0.5, 0.9] {
+            let point = arc.evaluate(u);
+            assert_relative_eq!(point.magnitude(), 1.0, epsilon = 1e-5);
+            let derivative = arc.derivative(u);
+            assert!(derivative.dot(&point).abs() < 1e-4);
+            let central = (arc.evaluate(u + h) - arc.evaluate(u - h)) * (0.5 / h);
+            if u > 0.0 {
+                assert_vec3_eq(derivative, central, 1e-2);
+            }
+        }
+
+        // Unit weights reduce to a plain B-spline.
+        let plain = Nurbs::clamped(2, &points, &[1.0; 3]).unwrap();
+        let spline = BSpline::clamped(2, points.to_vec()).unwrap();
+        assert_vec3_eq(plain.evaluate(0.4), spline.evaluate(0.4), 1e-6);
+        assert_vec3_eq(plain.derivative(0.4), spline.derivative(0.4), 1e-5);
+        assert_eq!(plain.homogeneous().points()[1].w, 1.0);
+    }
+
+    #[test]
+    fn test_curve_length() {
+        let line = Bezier::new([0.0, 1.0, 3.0, 6.0].map(|x| Vec2::new(x, x)));
+        assert_relative_eq!(line.length(), 6.0 * 2.0f32.sqrt(), epsilon = 1e-4);
+        assert_relative_eq!(
+            line.length_between(1.0, 0.0),
+            -line.length(),
+            epsilon = 1e-4
+        );
+
+        assert_relative_eq!(quarter_circle().length(), FRAC_PI_2, epsilon = 1e-5);
+        assert_relative_eq!(
+            quarter_circle().length_between(0.0, 0.5),
+            FRAC_PI_4,
+            epsilon = 1e-5
+        );
+
+        // Splines measure across all their segments.
+        let points = [0.0, 1.0, 2.0, 3.0].map(|x| Vec3::new(x, 0.0, 0.0));
+        let path = CatmullRom::through(&points, Parameterization::Uniform).unwrap();
+        assert_relative_eq!(path.length(), 3.0, epsilon = 1e-4);
+        let spline = BSpline::clamped(2, points.to_vec()).unwrap();
+        assert_relative_eq!(spline.length(), 3.0, epsilon = 1e-4);
+
+        // The default second derivative matches the exact one.
+        let curve = twisted();
+        let exact = Bezier::second_derivative(&curve, 0.4);
+        let spline = BSpline::clamped(3, curve.points.to_vec()).unwrap();
+        assert_vec3_eq(Curve::second_derivative(&spline, 0.4), exact, 1e-2);
+    }
+
+    #[test]
+    fn test_arc_length_parameterization() {
+        let circle = quarter_circle();
+        let table = circle.arc_length(8);
+        assert_relative_eq!(table.length(), FRAC_PI_2, epsilon = 1e-5);
+        let point = table.evaluate(FRAC_PI_8);
+        assert_vec3_eq(
+            point,
+            Vec3::new(FRAC_PI_8.cos(), FRAC_PI_8.sin(), 0.0),
+            1e-4,
+        );
+        assert_relative_eq!(table.parameter_at(-1.0), 0.0);
+        assert_relative_eq!(table.parameter_at(10.0), 1.0);
+
+        let curve = s_curve();
+        let table = curve.arc_length(4);
+        for t in [0.0, 0.1, 0.37, 0.8, 1.0] {
+            let length = table.length_at(t);
+            assert_relative_eq!(length, curve.length_between(0.0, t), epsilon = 1e-4);
+            assert_relative_eq!(table.parameter_at(length), t, epsilon = 1e-4);
+        }
+
+        // Evenly spaced along the curve rather than in the parameter.
+        let parameters = table.uniform_parameters(11);
+        assert_eq!(parameters.len(), 11);
+        assert_eq!((parameters[0], parameters[10]), (0.0, 1.0));
+        for (i, &t) in parameters.iter().enumerate() {
+            assert_relative_eq!(
+                table.length_at(t),
+                table.length() * i as f32 / 10.0,
+                epsilon = 1e-3
+            );
+        }
+        assert!(parameters.windows(2).all(|pair| pair[0] < pair[1]));
+        assert_eq!(
+            table.curve().points[3].as_array(),
+            curve.points[3].as_array()
+        );
+    }
+
+    #[test]
+    fn test_frenet_frame() {
+        let circle = quarter_circle();
+        for t in [0.0, 0.3, 1.0] {
+            let frame = circle.frenet_frame(t).unwrap();
+            assert_orthonormal(&frame);
+            // The normal points at the centre and the binormal out of the plane.
+            assert_vec3_eq(frame.normal, -frame.position, 1e-4);
+            assert_vec3_eq(frame.binormal, Vec3::new(0.0, 0.0, 1.0), 1e-4);
+
+            let orientation = frame.orientation();
+            let forward = orientation.rotate_vector(Vec3::new(0.0, 0.0, -1.0));
+            assert_vec3_eq(forward, frame.tangent, 1e-4);
+            assert_vec3_eq(
+                orientation.rotate_vector(Vec3::new(0.0, 1.0, 0.0)),
+                frame.normal,
+                1e-4,
+            );
+            assert_vec3_eq(
+                orientation.rotate_vector(Vec3::new(1.0, 0.0, 0.0)),
+                frame.binormal,
+                1e-4,
+            );
+        }
+
+        // Planar 2D curves get frames in the XY plane.
+        let arch =
+            QuadraticBezierCurve::new([Vec2::ZERO, Vec2::new(1.0, 2.0), Vec2::new(2.0, 0.0)]);
+        let frame = arch.frenet_frame(0.5).unwrap();
+        assert_vec3_eq(frame.position, Vec3::new(1.0, 1.0, 0.0), 1e-6);
+        assert_vec3_eq(frame.normal, Vec3::new(0.0, -1.0, 0.0), 1e-5);
+
+        let frame = twisted().frenet_frame(0.6).unwrap();
+        assert_orthonormal(&frame);
+
+        // Straight curves have no principal normal.
+        let line = QuadraticBezierCurve::new([Vec3::ZERO, Vec3::ONE, Vec3::ONE * 2.0]);
+        assert!(line.frenet_frame(0.5).is_none());
+    }
+
+    #[test]
+    fn test_rotation_minimizing_frames() {
+        // Along a straight line the normal never turns.
+        let line = QuadraticBezierCurve::new([
+            Vec3::ZERO,
+            Vec3::new(1.0, 1.0, 0.0),
+            Vec3::new(2.0, 2.0, 0.0),
+        ]);
+        let parameters: Vec<f32> = (0..=10).map(|i| i as f32 / 10.0).collect();
+        let frames = line.rotation_minimizing_frames(&parameters, Vec3::new(0.0, 1.0, 1.0));
+        assert_eq!(frames.len(), 11);
+        for frame in &frames {
+            assert_orthonormal(frame);
+            assert_vec3_eq(frame.normal, frames[0].normal, 1e-5);
+        }
+        assert_vec3_eq(
+            frames[0].normal.cross(&Vec3::new(-0.5, 0.5, 1.0)),
+            Vec3::ZERO,
+            1e-5,
+        );
+
+        // On a planar curve, a normal starting out of the plane stays out of the plane.
+        let circle = quarter_circle();
+        let table = circle.arc_length(8);
+        let frames = circle
+            .rotation_minimizing_frames(&table.uniform_parameters(32), Vec3::new(0.0, 0.0, 1.0));
+        for frame in &frames {
+            assert_orthonormal(frame);
+            assert_vec3_eq(frame.normal, Vec3::new(0.0, 0.0, 1.0), 1e-4);
+        }
+        // A normal in the plane agrees with the Frenet frame.
+        let frames = circle.rotation_minimizing_frames(&parameters, Vec3::new(-1.0, 0.0, 0.0));
+        for (frame, &t) in frames.iter().zip(&parameters) {
+            let frenet = circle.frenet_frame(t).unwrap();
+            assert_vec3_eq(frame.normal, frenet.normal, 1e-3);
+        }
+
+        // Along a twisted curve the frames stay orthonormal and turn smoothly.
+        let curve = twisted();
+        let parameters: Vec<f32> = (0..=200).map(|i| i as f32 / 200.0).collect();
+        let frames = curve.rotation_minimizing_frames(&parameters, curve.points[0]);
+        for pair in frames.windows(2) {
+            assert_orthonormal(&pair[1]);
+            let turn = pair[0].orientation().angle_to(&pair[1].orientation());
+            assert!(turn < 0.1);
+        }
+        // Closely spaced samples of a small curve give the same frames as the full-size curve.
+        let curve = s_curve();
+        let small = CubicBezierCurve::new(curve.points.map(|point| point * 0.01));
+        let parameters: Vec<f32> = (0..=200).map(|i| i as f32 / 200.0).collect();
+        let frames = curve.rotation_minimizing_frames(&parameters, Vec3::Y);
+        let small_frames = small.rotation_minimizing_frames(&parameters, Vec3::Y);
+        for (frame, small_frame) in frames.iter().zip(&small_frames) {
+            assert_orthonormal(small_frame);
+            assert_vec3_eq(small_frame.normal, frame.normal, 1e-3);
+        }
+
+        // An initial normal along the tangent falls back to an arbitrary perpendicular.
+        let frames = curve.rotation_minimizing_frames(&[0.0, 0.5], Vec3::new(1.0, 2.0, 0.0));
+        assert_orthonormal(&frames[0]);
+        assert_relative_eq!(
+            frames[0]
+                .orientation()
+                .angle_to(&UnitQuaternion::look_rotation(
+                    frames[0].tangent,
+                    frames[0].normal
+                )),
+            0.0,
+            epsilon = 1e-3
+        );
+    }
+}
@@ -0,0 +1,219 @@
+mod common;
+
+#[cfg(test)]
+mod tests {
+    use std::f32::consts::FRAC_PI_2;
+
+    use crate::common::assert_vec3_eq;
+    use approx::assert_relative_eq;
+    use vectorama::Transform3;
+    use vectorama::dual_quaternion::{DualQuaternion, unit::UnitDualQuaternion};
+    use vectorama::matrix::Matrix;
+    use vectorama::quaternion::{Quaternion, unit::UnitQuaternion};
+    use vectorama::translation::translation3::Translation3;
+    use vectorama::vector::vec3::Vec3;
+
+    fn assert_same_transform(a: UnitDualQuaternion, b: UnitDualQuaternion) {
+        for point in [Vec3::ZERO, Vec3::X, Vec3::Y, Vec3::Z] {
+            assert_vec3_eq(a.transform_point(&point), b.transform_point(&point), 1e-4);
+        }
+    }
+
+    fn screw() -> UnitDualQuaternion {
+        let rotation = UnitQuaternion::from_axis_angle(Vec3::new(1.0, 2.0, -0.5), 1.3);
+        UnitDualQuaternion::from_rotation_translation(rotation, Translation3::new(2.0, -1.0, 0.5))
+    }
+
+    #[test]
+    fn test_dual_quaternion_conjugates() {
+        let real = Quaternion::new(Vec3::new(1.0, 2.0, 3.0), 4.0);
+        let dual = Quaternion::new(Vec3::new(-1.0, 0.5, 2.0), 3.0);
+        let dq = DualQuaternion::new(real, dual);
+
+        let conjugate = dq.conjugate();
+        assert_eq!(conjugate.real.vector.as_array(), &[-1.0, -2.0, -3.0]);
+        assert_eq!(conjugate.dual.vector.as_array(), &[1.0, -0.5, -2.0]);
+        assert_relative_eq!(conjugate.dual.scalar, 3.0);
+
+        let dual_conjugate = dq.dual_conjugate();
+        assert_eq!(dual_conjugate.real.vector.as_array(), &[1.0, 2.0, 3.0]);
+        assert_eq!(dual_conjugate.dual.vector.as_array(), &[1.0, -0.5, -2.0]);
+        assert_relative_eq!(dual_conjugate.dual.scalar, -3.0);
+
+        let combined = dq.combined_conjugate();
+        assert_eq!(combined.real.vector.as_array(), &[-1.0, -2.0, -3.0]);
+        assert_eq!(combined.dual.vector.as_array(), &[-1.0, 0.5, 2.0]);
+        assert_relative_eq!(combined.dual.scalar, -3.0);
+    }
+
+    #[test]
+    fn test_dual_quaternion_arithmetic() {
+        let a = DualQuaternion::new(Quaternion::IDENTITY, Quaternion::new(Vec3::X, 1.0));
+        let b = DualQuaternion::new(Quaternion::new(Vec3::Y, 0.0), Quaternion::ZERO);
+
+        let sum = a + b;
+        assert_eq!(sum.real.vector.as_array(), &[0.0, 1.0, 0.0]);
+        assert_relative_eq!(sum.real.scalar, 1.0);
+        let difference = sum - b;
+        assert_eq!(difference.dual.vector.as_array(), &[1.0, 0.0, 0.0]);
+        let negated = -a;
+        assert_relative_eq!(negated.real.scalar, -1.0);
+        let scaled = 2.0 * a;
+        assert_relative_eq!(scaled.dual.scalar, 2.0);
+
+        // (1 + ε d) (r + 0) = r + ε d r
+        let product = a * b;
+        let expected = Quaternion::new(Vec3::X, 1.0) * Quaternion::new(Vec3::Y, 0.0);
+        assert_eq!(product.dual.vector.as_array(), expected.vector.as_array());
+        assert_relative_eq!(product.dual.scalar, expected.scalar);
+    }
+
+    #[test]
+    fn test_dual_quaternion_normalize() {
+        let dq = *screw() * 3.0 + DualQuaternion::new(Quaternion::ZERO, Quaternion::IDENTITY);
+        let unit = UnitDualQuaternion::from(dq);
+        assert_relative_eq!(unit.real.magnitude(), 1.0, epsilon = 1e-6);
+        assert_relative_eq!(unit.real.dot(&unit.dual), 0.0, epsilon = 1e-6);
+    }
+
+    #[test]
+    fn test_unit_dual_quaternion_parts() {
+        let rotation = UnitQuaternion::from_z_axis(FRAC_PI_2);
+        let translation = Translation3::new(1.0, 2.0, 3.0);
+        let dq = UnitDualQuaternion::from_rotation_translation(rotation, translation);
+
+        assert_relative_eq!(dq.rotation().dot(&rotation).abs(), 1.0, epsilon = 1e-6);
+        assert_vec3_eq(*dq.translation(), *translation, 1e-4);
+        assert_vec3_eq(dq.transform_point(&Vec3::X), Vec3::new(1.0, 3.0, 3.0), 1e-4);
+        assert_vec3_eq(dq.transform_vector(&Vec3::X), Vec3::Y, 1e-4);
+        assert_vec3_eq(dq * Vec3::Y, Vec3::new(0.0, 2.0, 3.0), 1e-4);
+        assert_vec3_eq(
+            dq.inverse_transform_point(&Vec3::new(1.0, 3.0, 3.0)),
+            Vec3::X,
+            1e-4,
+        );
+
+        assert_vec3_eq(
+            UnitDualQuaternion::from(translation) * Vec3::ZERO,
+            *translation,
+            1e-4,
+        );
+        assert_vec3_eq(UnitDualQuaternion::from(rotation) * Vec3::X, Vec3::Y, 1e-4);
+    }
+
+    #[test]
+    fn test_unit_dual_quaternion_composition_and_inverse() {
+        let a = screw();
+        let b = UnitDualQuaternion::from_rotation_translation(
+            UnitQuaternion::from_x_axis(-0.7),
+            Translation3::new(0.0, 3.0, -1.0),
+        );
+        let p = Vec3::new(0.3, -2.0, 1.5);
+        assert_vec3_eq((a * b) * p, a * (b * p), 1e-4);
+
+        let mut c = a;
+        c *= b;
+        assert_same_transform(c, a * b);
+
+        assert_same_transform(a * a.inverse(), UnitDualQuaternion::IDENTITY);
+        assert_same_transform(a.try_inverse().unwrap() * a, UnitDualQuaternion::identity());
+    }
+
+    #[test]
+    fn test_unit_dual_quaternion_homogeneous_matrix() {
+        let a = screw();
+        let b = UnitDualQuaternion::from_rotation(UnitQuaternion::from_y_axis(0.4));
+        let p = Vec3::new(1.0, -0.5, 2.0);
+
+        let matrix = Matrix::<4, 4>::from(a);
+        assert_vec3_eq(matrix.transform_point3(p), a.transform_point(&p), 1e-4);
+        assert_vec3_eq(
+            a.to_homogeneous().transform_vector3(p),
+            a.transform_vector(&p),
+            1e-4,
+        );
+
+        let product = a.homogeneous_matrix() * b.homogeneous_matrix();
+        assert_vec3_eq(
+            product.transform_point3(p),
+            (a * b).transform_point(&p),
+            1e-4,
+        );
+    }
+
+    #[test]
+    fn test_unit_dual_quaternion_sclerp() {
+        let start = screw();
+        let end = UnitDualQuaternion::from_rotation_translation(
+            UnitQuaternion::from_z_axis(-0.4),
+            Translation3::new(-1.0, 0.0, 4.0),
+        );
+        assert_same_transform(start.sclerp(&end, 0.0), start);
+        assert_same_transform(start.sclerp(&end, 1.0), end);
+
+        // Two half steps along the screw reach the end.
+        let half = start.sclerp(&end, 0.5);
+        let delta = start.inverse() * half;
+        assert_same_transform(start * delta * delta, end);
+
+        // A pure translation interpolates linearly.
+        let moved = UnitDualQuaternion::from_translation(Translation3::new(2.0, 0.0, -4.0));
+        let halfway = UnitDualQuaternion::IDENTITY.sclerp(&moved, 0.5);
+        assert_vec3_eq(*halfway.translation(), Vec3::new(1.0, 0.0, -2.0), 1e-4);
+
+        // A pure rotation matches slerp.
+        let a = UnitQuaternion::from_x_axis(0.2);
+        let b = UnitQuaternion::from_y_axis(1.1);
+        let interpolated = UnitDualQuaternion::from(a).sclerp(&UnitDualQuaternion::from(b), 0.3);
+        assert_relative_eq!(
+            interpolated.rotation().dot(&a.slerp(&b, 0.3)).abs(),
+            1.0,
+            epsilon = 1e-5
+        );
+        assert_vec3_eq(*interpolated.translation(), Vec3::ZERO, 1e-4);
+
+        // Flipping the sign of the target does not change the path.
+        let flipped = UnitDualQuaternion::from(-*end);
+        assert_same_transform(start.sclerp(&flipped, 0.3), start.sclerp(&end, 0.3));
+    }
+
+    #[test]
+    fn test_unit_dual_quaternion_powf() {
+        let dq = screw();
+        assert_same_transform(dq.powf(0.0), UnitDualQuaternion::IDENTITY);
+        assert_same_transform(dq.powf(1.0), dq);
+        assert_same_transform(dq.powf(2.0), dq * dq);
+        assert_same_transform(dq.powf(-1.0), dq.inverse());
+    }
+
+    #[test]
+    fn test_unit_dual_quaternion_blend() {
+        let moved = UnitDualQuaternion::from_translation(Translation3::new(0.0, 2.0, 0.0));
+        let identity = UnitDualQuaternion::IDENTITY;
+        let blend = UnitDualQuaternion::blend(&[identity, moved], &[0.5, 0.5]).unwrap();
+        assert_vec3_eq(*blend.translation(), Vec3::new(0.0, 1.0, 0.0), 1e-4);
+
+        // Blending rotations about the same axis stays rigid and follows the shorter arc.
+        let a = UnitDualQuaternion::from(UnitQuaternion::from_z_axis(0.2));
+        let b =
+            UnitDualQuaternion::from(-*UnitDualQuaternion::from(UnitQuaternion::from_z_axis(0.6)));
+        let blend = UnitDualQuaternion::blend(&[a, b], &[1.0, 1.0]).unwrap();
+        assert_vec3_eq(
+            blend * Vec3::X,
+            Vec3::new(0.4f32.cos(), 0.4f32.sin(), 0.0),
+            1e-4,
+        );
+
+        let single = UnitDualQuaternion::blend(&[screw()], &[0.25]).unwrap();
+        assert_same_transform(single, screw());
+
+        assert!(UnitDualQuaternion::blend(&[], &[]).is_none());
+        assert!(UnitDualQuaternion::blend(&[moved], &[0.0]).is_none());
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_unit_dual_quaternion_blend_length_mismatch() {
+        UnitDualQuaternion::blend(&[UnitDualQuaternion::IDENTITY], &[]);
+    }
+}
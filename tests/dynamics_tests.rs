@@ -0,0 +1,168 @@
+mod common;
+
+#[cfg(test)]
+mod tests {
+    use crate::common::assert_vec3_eq;
+    use approx::assert_relative_eq;
+    use vectorama::dynamics::rigid_body::RigidBody;
+    use vectorama::matrix::Matrix;
+    use vectorama::quaternion::unit::UnitQuaternion;
+    use vectorama::vector::vec3::Vec3;
+
+    const GRAVITY: Vec3 = Vec3::new(0.0, -9.81, 0.0);
+
+    fn gravity(body: &RigidBody) -> (Vec3, Vec3) {
+        (GRAVITY * body.mass, Vec3::ZERO)
+    }
+
+    fn spring(body: &RigidBody) -> (Vec3, Vec3) {
+        (-body.position * 4.0, Vec3::ZERO)
+    }
+
+    fn free(_: &RigidBody) -> (Vec3, Vec3) {
+        (Vec3::ZERO, Vec3::ZERO)
+    }
+
+    fn asymmetric_body() -> RigidBody {
+        let inertia = Matrix::from_rows([[1.0, 0.0, 0.0], [0.0, 2.0, 0.0], [0.0, 0.0, 3.0]]);
+        let mut body = RigidBody::new(1.0, inertia);
+        body.orientation = UnitQuaternion::from_euler_angles(0.2, 0.5, -0.3);
+        body.angular_velocity = Vec3::new(0.3, 2.0, 0.4);
+        body
+    }
+
+    #[test]
+    fn test_rigid_body_projectile() {
+        let mut launch = RigidBody::new(2.0, Matrix::identity());
+        launch.velocity = Vec3::new(3.0, 5.0, 0.0);
+        let dt = 0.01;
+        let t = 1.0;
+        let expected = launch.velocity * t + GRAVITY * (0.5 * t * t);
+
+        // Constant acceleration is integrated exactly by Verlet and RK4.
+        let (mut verlet, mut rk4, mut euler) = (launch, launch, launch);
+        for _ in 0..100 {
+            verlet.step_verlet(dt, gravity);
+            rk4.step_rk4(dt, gravity);
+            euler.step_semi_implicit_euler(dt, gravity);
+        }
+        assert_vec3_eq(verlet.position, expected, 1e-3);
+        assert_vec3_eq(rk4.position, expected, 1e-3);
+        assert_vec3_eq(verlet.velocity, launch.velocity + GRAVITY * t, 1e-3);
+        assert_vec3_eq(rk4.velocity, launch.velocity + GRAVITY * t, 1e-3);
+
+        // Semi-implicit Euler overshoots by half a step of velocity change per unit of time.
+        let euler_error = GRAVITY * (0.5 * dt * t);
+        assert_vec3_eq(euler.position, expected + euler_error, 1e-3);
+    }
+
+    #[test]
+    fn test_rigid_body_spring() {
+        let mut start = RigidBody::new(1.0, Matrix::identity());
+        start.position = Vec3::X;
+        // With stiffness 4 and unit mass the angular frequency is 2.
+        let energy =
+            |body: &RigidBody| body.kinetic_energy() + 2.0 * body.position.dot(&body.position);
+
+        let (mut verlet, mut rk4, mut euler) = (start, start, start);
+        let dt = 0.01;
+        for _ in 0..1000 {
+            verlet.step_verlet(dt, spring);
+            rk4.step_rk4(dt, spring);
+            euler.step_semi_implicit_euler(dt, spring);
+        }
+        let t: f32 = 10.0;
+        assert_relative_eq!(rk4.position.x, (2.0 * t).cos(), epsilon = 1e-3);
+        assert_relative_eq!(rk4.velocity.x, -2.0 * (2.0 * t).sin(), epsilon = 1e-3);
+        assert_relative_eq!(verlet.position.x, (2.0 * t).cos(), epsilon = 1e-2);
+
+        // The symplectic integrators keep the energy bounded.
+        assert_relative_eq!(energy(&verlet), energy(&start), epsilon = 1e-3);
+        assert_relative_eq!(energy(&euler), energy(&start), epsilon = 5e-2);
+    }
+
+    #[test]
+    fn test_rigid_body_constant_torque() {
+        let mut body = RigidBody::new(1.0, Matrix::identity() * 2.0);
+        let torque = |_: &RigidBody| (Vec3::ZERO, Vec3::new(0.0, 0.0, 1.0));
+        for _ in 0..100 {
+            body.step_rk4(0.01, torque);
+        }
+        // α = τ / I = 0.5, so after one second ω = 0.5 and θ = 0.25.
+        assert_vec3_eq(body.angular_velocity, Vec3::new(0.0, 0.0, 0.5), 1e-4);
+        assert_relative_eq!(body.orientation.angle(), 0.25, epsilon = 1e-4);
+    }
+
+    #[test]
+    fn test_rigid_body_principal_axis_spin() {
+        let mut body = asymmetric_body();
+        body.orientation = UnitQuaternion::IDENTITY;
+        body.angular_velocity = Vec3::new(0.0, 0.0, 3.0);
+        for _ in 0..50 {
+            body.step_semi_implicit_euler(0.02, free);
+        }
+        assert_vec3_eq(body.angular_velocity, Vec3::new(0.0, 0.0, 3.0), 1e-4);
+        let expected = UnitQuaternion::from_z_axis(3.0);
+        assert_relative_eq!(body.orientation.angle_to(&expected), 0.0, epsilon = 1e-3);
+    }
+
+    #[test]
+    fn test_rigid_body_free_precession_conserves_momentum() {
+        let start = asymmetric_body();
+        let momentum = start.angular_momentum();
+        let energy = start.kinetic_energy();
+
+        let mut body = start;
+        for _ in 0..500 {
+            body.step_rk4(0.01, free);
+        }
+        // The spin axis wanders, but the world-frame momentum and the energy do not.
+        assert!(body.angular_velocity.angle_between(&start.angular_velocity) > 1e-2);
+        assert_vec3_eq(body.angular_momentum(), momentum, 1e-3);
+        assert_relative_eq!(body.kinetic_energy(), energy, epsilon = 1e-3);
+    }
+
+    #[test]
+    fn test_rigid_body_ignores_loads_without_mass_or_inertia() {
+        let mut body = RigidBody::new(0.0, Matrix::zeros());
+        body.angular_velocity = Vec3::Y;
+        let load = |_: &RigidBody| (Vec3::X, Vec3::Z);
+        body.step_verlet(0.1, load);
+
+        assert_vec3_eq(body.velocity, Vec3::ZERO, 1e-6);
+        assert_vec3_eq(body.angular_velocity, Vec3::Y, 1e-6);
+        let (acceleration, angular_acceleration) = body.accelerations(Vec3::X, Vec3::Z);
+        assert_vec3_eq(acceleration, Vec3::ZERO, 1e-6);
+        assert_vec3_eq(angular_acceleration, Vec3::ZERO, 1e-6);
+    }
+
+    #[test]
+    fn test_rigid_body_small_inertia_responds_to_torque() {
+        // A 1 cm sphere of 1 g: I = 2/5 m r² = 4e-8.
+        let mut body = RigidBody::new(1e-3, Matrix::identity() * 4e-8);
+        body.orientation = UnitQuaternion::from_euler_angles(0.2, 0.5, -0.3);
+        let (_, angular_acceleration) = body.accelerations(Vec3::ZERO, Vec3::new(0.0, 4e-8, 0.0));
+        assert_vec3_eq(angular_acceleration, Vec3::new(0.0, 1.0, 0.0), 1e-5);
+
+        // The full asymmetric body agrees with the world-frame equation α = I⁻¹ (τ - ω × I ω).
+        let body = asymmetric_body();
+        let torque = Vec3::new(0.5, -1.0, 2.0);
+        let world_inertia = body.world_inertia();
+        let gyroscopic = body
+            .angular_velocity
+            .cross(&(world_inertia * body.angular_velocity));
+        let expected = world_inertia.try_inverse().unwrap() * (torque - gyroscopic);
+        assert_vec3_eq(body.accelerations(Vec3::ZERO, torque).1, expected, 1e-4);
+    }
+
+    #[test]
+    fn test_rigid_body_momentum_and_energy() {
+        let mut body = RigidBody::new(2.0, Matrix::identity() * 3.0);
+        body.velocity = Vec3::new(1.0, 2.0, 0.0);
+        body.angular_velocity = Vec3::new(0.0, 0.0, 2.0);
+
+        assert_vec3_eq(body.linear_momentum(), Vec3::new(2.0, 4.0, 0.0), 1e-6);
+        assert_vec3_eq(body.angular_momentum(), Vec3::new(0.0, 0.0, 6.0), 1e-6);
+        assert_relative_eq!(body.kinetic_energy(), 0.5 * (2.0 * 5.0 + 3.0 * 4.0));
+    }
+}
@@ -0,0 +1,116 @@
+#[cfg(test)]
+mod tests {
+    use vectorama::vector::{vec2::Vec2, vec3::Vec3};
+    use vectorama::{BVec3, BVec4, IVec2, IVec3, UVec2, UVec3};
+
+    #[test]
+    fn test_ivec3_arithmetic() {
+        let a = IVec3::new(1, -2, 7);
+        let b = IVec3::new(3, 4, 2);
+        assert_eq!(a + b, IVec3::new(4, 2, 9));
+        assert_eq!(a - b, IVec3::new(-2, -6, 5));
+        assert_eq!(a * b, IVec3::new(3, -8, 14));
+        assert_eq!(a / 2, IVec3::new(0, -1, 3));
+        assert_eq!(a % b, IVec3::new(1, -2, 1));
+        assert_eq!(-a, IVec3::new(-1, 2, -7));
+        assert_eq!(a.dot(&b), 9);
+        assert_eq!(a.abs(), IVec3::new(1, 2, 7));
+        assert_eq!(a.signum(), IVec3::new(1, -1, 1));
+
+        let mut c = a;
+        c += IVec3::ONE;
+        c *= 2;
+        assert_eq!(c, IVec3::new(4, -2, 16));
+    }
+
+    #[test]
+    fn test_ivec2_wrapping_saturating_checked() {
+        let a = IVec2::new(i32::MAX, i32::MIN);
+        assert_eq!(
+            a.wrapping_add(&IVec2::ONE),
+            IVec2::new(i32::MIN, i32::MIN + 1)
+        );
+        assert_eq!(
+            a.saturating_add(&IVec2::ONE),
+            IVec2::new(i32::MAX, i32::MIN + 1)
+        );
+        assert_eq!(
+            a.saturating_sub(&IVec2::ONE),
+            IVec2::new(i32::MAX - 1, i32::MIN)
+        );
+        assert_eq!(a.checked_add(&IVec2::ONE), None);
+        assert_eq!(a.checked_sub(&IVec2::NEG_ONE), None);
+        assert_eq!(IVec2::Y.checked_add(&IVec2::X), Some(IVec2::ONE));
+        assert_eq!(IVec2::MIN.wrapping_neg(), IVec2::MIN);
+    }
+
+    #[test]
+    fn test_uvec3_saturating_sub() {
+        let a = UVec3::new(1, 5, 0);
+        let b = UVec3::new(2, 3, 0);
+        assert_eq!(a.saturating_sub(&b), UVec3::new(0, 2, 0));
+        assert_eq!(a.checked_sub(&b), None);
+        assert_eq!(a.max_element(), 5);
+        assert_eq!(a.min_element(), 0);
+    }
+
+    #[test]
+    fn test_float_to_integer_conversions() {
+        let v = Vec3::new(2.9, -2.9, 0.0);
+        assert_eq!(v.as_ivec3(), IVec3::new(2, -2, 0));
+        assert_eq!(v.try_as_ivec3(), Some(IVec3::new(2, -2, 0)));
+        assert_eq!(Vec3::new(f32::NAN, 0.0, 0.0).try_as_ivec3(), None);
+        assert_eq!(Vec3::new(0.0, f32::INFINITY, 0.0).try_as_ivec3(), None);
+        assert_eq!(Vec3::new(0.0, 0.0, 3.0e9).try_as_ivec3(), None);
+        assert_eq!(
+            Vec3::new(0.0, 0.0, 3.0e9).as_ivec3(),
+            IVec3::new(0, 0, i32::MAX)
+        );
+        assert_eq!(Vec3::new(-1.0, 0.0, 0.0).try_as_uvec3(), None);
+        assert_eq!(Vec3::new(-0.5, 0.0, 0.0).try_as_uvec3(), Some(UVec3::ZERO));
+        assert_eq!(
+            Vec2::new(3.0e9, 1.0).try_as_uvec2(),
+            Some(UVec2::new(3_000_000_000, 1))
+        );
+    }
+
+    #[test]
+    fn test_integer_to_float_conversions() {
+        let exact = IVec3::new(1 << 24, -3, 0);
+        let converted = exact.try_as_vec3().unwrap();
+        assert_eq!(converted.as_array(), &[16_777_216.0, -3.0, 0.0]);
+        let inexact = IVec3::new((1 << 24) + 1, 0, 0);
+        assert!(inexact.try_as_vec3().is_none());
+        assert_eq!(inexact.as_vec3().as_array(), &[16_777_216.0, 0.0, 0.0]);
+
+        assert_eq!(IVec2::new(-1, 2).try_as_uvec2(), None);
+        assert_eq!(IVec2::new(1, 2).try_as_uvec2(), Some(UVec2::new(1, 2)));
+        assert_eq!(UVec2::new(u32::MAX, 0).try_as_ivec2(), None);
+    }
+
+    #[test]
+    fn test_bvec_operations() {
+        let a = BVec4::new(true, false, true, false);
+        let b = BVec4::new(true, true, false, false);
+        assert_eq!(a & b, BVec4::new(true, false, false, false));
+        assert_eq!(a | b, BVec4::new(true, true, true, false));
+        assert_eq!(a ^ b, BVec4::new(false, true, true, false));
+        assert_eq!(!a, BVec4::new(false, true, false, true));
+        assert_eq!(a.bitmask(), 0b0101);
+        assert!(a.any());
+        assert!(!a.all());
+        assert!(BVec4::TRUE.all());
+        assert!(!BVec4::FALSE.any());
+        assert_eq!(<[bool; 4]>::from(a), [true, false, true, false]);
+    }
+
+    #[test]
+    fn test_integer_comparisons_and_select() {
+        let a = IVec3::new(1, 5, 3);
+        let b = IVec3::new(2, 4, 3);
+        assert_eq!(a.cmplt(&b), BVec3::new(true, false, false));
+        assert_eq!(a.cmpge(&b), BVec3::new(false, true, true));
+        assert_eq!(a.cmpeq(&b), BVec3::new(false, false, true));
+        assert_eq!(IVec3::select(a.cmpgt(&b), a, b), IVec3::new(2, 5, 3));
+    }
+}
@@ -1,7 +1,10 @@
 #[cfg(test)]
 mod tests {
     use approx::assert_relative_eq;
-    use vectorama::{matrix::*, vector::vec3::Vec3};
+    use vectorama::{
+        matrix::*, quaternion::unit::UnitQuaternion, scale::scale3::Scale3,
+        translation::translation3::Translation3, vector::vec3::Vec3,
+    };
 
     #[test]
     fn test_matrix_zeros() {
@@ -174,6 +177,7 @@ mod tests {
     }
 
     #[test]
+    #[allow(clippy::op_ref)]
     fn test_matrix_scalar_division() {
         #[rustfmt::skip]
         let a = [
@@ -570,11 +574,129 @@ mod tests {
         let matrix = Matrix::<3, 3>::from(data);
 
         // Test each column
-        for col in 0..3 {
+        for (col, expected) in data.iter().enumerate() {
             let column = matrix.column(col);
             for row in 0..3 {
-                assert_relative_eq!(column[row], data[col][row], epsilon = f32::EPSILON);
+                assert_relative_eq!(column[row], expected[row], epsilon = f32::EPSILON);
             }
         }
     }
+
+    #[test]
+    fn test_matrix_const_identity() {
+        const BIAS: Matrix<4, 4> = Matrix::IDENTITY;
+        let identity: Matrix<4, 4> = Matrix::identity();
+        for m in 0..4 {
+            for n in 0..4 {
+                assert_relative_eq!(BIAS[(m, n)], identity[(m, n)], epsilon = f32::EPSILON);
+            }
+        }
+    }
+
+    #[test]
+    fn test_matrix_from_cols_and_rows() {
+        const COLS: Matrix<2, 3> = Matrix::from_cols([
+            [1.0, 4.0], // Column 0
+            [2.0, 5.0], // Column 1
+            [3.0, 6.0], // Column 2
+        ]);
+        const ROWS: Matrix<2, 3> = Matrix::from_rows([
+            [1.0, 2.0, 3.0], // Row 0
+            [4.0, 5.0, 6.0], // Row 1
+        ]);
+        for m in 0..2 {
+            for n in 0..3 {
+                assert_relative_eq!(COLS[(m, n)], ROWS[(m, n)], epsilon = f32::EPSILON);
+                assert_relative_eq!(ROWS[(m, n)], (m * 3 + n + 1) as f32, epsilon = f32::EPSILON);
+            }
+        }
+    }
+
+    #[test]
+    fn test_mat4_transform_point_and_vector() {
+        let m = Matrix::<4, 4>::from_rotation_translation(
+            UnitQuaternion::from_axis_angle(Vec3::Z, std::f32::consts::FRAC_PI_2),
+            Vec3::new(1.0, 2.0, 3.0),
+        );
+        let p = m.transform_point3(Vec3::X);
+        assert_relative_eq!(p.x, 1.0, epsilon = 1e-6);
+        assert_relative_eq!(p.y, 3.0, epsilon = 1e-6);
+        assert_relative_eq!(p.z, 3.0, epsilon = 1e-6);
+        let v = m.transform_vector3(Vec3::X);
+        assert_relative_eq!(v.x, 0.0, epsilon = 1e-6);
+        assert_relative_eq!(v.y, 1.0, epsilon = 1e-6);
+        assert_relative_eq!(v.z, 0.0, epsilon = 1e-6);
+
+        let projection = Matrix::<4, 4>::perspective(1.0, std::f32::consts::FRAC_PI_2, 1.0, 10.0);
+        let near = projection.project_point3(Vec3::new(1.0, 0.5, -1.0));
+        assert_relative_eq!(near.x, 1.0, epsilon = 1e-6);
+        assert_relative_eq!(near.y, 0.5, epsilon = 1e-6);
+        assert_relative_eq!(near.z, -1.0, epsilon = 1e-6);
+        assert!(!projection.is_affine());
+        assert!(m.is_affine());
+    }
+
+    #[test]
+    fn test_mat4_affine_constructors() {
+        let t = Matrix::<4, 4>::from_translation(Translation3::new(1.0, 2.0, 3.0));
+        assert_relative_eq!(t[(0, 3)], 1.0, epsilon = f32::EPSILON);
+        assert_relative_eq!(t[(2, 3)], 3.0, epsilon = f32::EPSILON);
+
+        let s = Matrix::<4, 4>::from_scale(Scale3::new(2.0, 3.0, 4.0));
+        assert_relative_eq!(s[(1, 1)], 3.0, epsilon = f32::EPSILON);
+        assert_relative_eq!(s[(3, 3)], 1.0, epsilon = f32::EPSILON);
+
+        let r = Matrix::<4, 4>::from_axis_angle(Vec3::Y, 0.5);
+        let q = Matrix::<4, 4>::from_rotation(UnitQuaternion::from_y_axis(0.5));
+        for n in 0..4 {
+            for m in 0..4 {
+                assert_relative_eq!(r[(m, n)], q[(m, n)], epsilon = 1e-6);
+            }
+        }
+    }
+
+    #[test]
+    fn test_mat4_scale_rotation_translation_round_trip() {
+        let rotation = UnitQuaternion::from_euler_angles(0.3, -0.8, 1.2);
+        let m = Matrix::<4, 4>::from_scale_rotation_translation(
+            Vec3::new(-2.0, 0.5, 3.0),
+            rotation,
+            Vec3::new(4.0, -5.0, 6.0),
+        );
+        let (scale, decomposed, translation) = m.to_scale_rotation_translation();
+        let rebuilt =
+            Matrix::<4, 4>::from_scale_rotation_translation(*scale, decomposed, *translation);
+        for n in 0..4 {
+            for m_ in 0..4 {
+                assert_relative_eq!(rebuilt[(m_, n)], m[(m_, n)], epsilon = 1e-5);
+            }
+        }
+        assert_relative_eq!(scale.x, -2.0, epsilon = 1e-5);
+        assert_relative_eq!(scale.y, 0.5, epsilon = 1e-5);
+        assert_relative_eq!(scale.z, 3.0, epsilon = 1e-5);
+        assert_relative_eq!(translation.y, -5.0, epsilon = f32::EPSILON);
+    }
+
+    #[test]
+    fn test_mat4_normal_matrix() {
+        let m = Matrix::<4, 4>::from_scale(Vec3::new(2.0, 1.0, 1.0))
+            * Matrix::<4, 4>::from_translation(Vec3::new(5.0, 0.0, 0.0));
+        let normal = m.normal_matrix();
+        // A plane with normal (1, 1, 0) stretched along X tilts towards Y.
+        let n = normal * Vec3::new(1.0, 1.0, 0.0);
+        assert_relative_eq!(n.x, 0.5, epsilon = 1e-6);
+        assert_relative_eq!(n.y, 1.0, epsilon = 1e-6);
+        let tangent = m.transform_vector3(Vec3::new(1.0, -1.0, 0.0));
+        assert_relative_eq!(tangent.dot(&n), 0.0, epsilon = 1e-6);
+
+        // Small uniform scales still give the inverse-transpose.
+        let small = Matrix::<4, 4>::from_scale(Vec3::new(2e-3, 1e-3, 1e-3)).normal_matrix();
+        let n = small * Vec3::new(1.0, 1.0, 0.0);
+        assert_relative_eq!(n.x, 500.0, max_relative = 1e-5);
+        assert_relative_eq!(n.y, 1000.0, max_relative = 1e-5);
+
+        let singular = Matrix::<4, 4>::from_scale(Vec3::new(0.0, 1.0, 1.0)).normal_matrix();
+        let n = singular * Vec3::X;
+        assert_relative_eq!(n.x, 1.0, epsilon = 1e-6);
+    }
 }
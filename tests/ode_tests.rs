@@ -0,0 +1,193 @@
+#[cfg(test)]
+mod tests {
+    use approx::assert_relative_eq;
+    use vectorama::dynamics::ode::{self, Rk45};
+    use vectorama::vector::Vector;
+
+    fn decay(_: f32, y: &Vector<1>) -> Vector<1> {
+        -*y
+    }
+
+    fn oscillator(_: f32, y: &Vector<2>) -> Vector<2> {
+        Vector::from([y[1], -y[0]])
+    }
+
+    /// Integrates `dy/dt = -y` from `y(0) = 1` to `t = 1` and returns the absolute error.
+    fn decay_error<S>(step: S, steps: usize) -> f32
+    where
+        S: Fn(fn(f32, &Vector<1>) -> Vector<1>, f32, &Vector<1>, f32) -> Vector<1>,
+    {
+        let dt = 1.0 / steps as f32;
+        let mut y = Vector::from([1.0]);
+        for i in 0..steps {
+            y = step(decay, i as f32 * dt, &y, dt);
+        }
+        (y[0] - (-1.0f32).exp()).abs()
+    }
+
+    #[test]
+    fn test_fixed_step_orders_of_accuracy() {
+        // Halving the step divides the error by about 2^order.
+        let ratio = decay_error(ode::euler, 50) / decay_error(ode::euler, 100);
+        assert_relative_eq!(ratio, 2.0, epsilon = 0.1);
+        let ratio = decay_error(ode::midpoint, 50) / decay_error(ode::midpoint, 100);
+        assert_relative_eq!(ratio, 4.0, epsilon = 0.2);
+        let ratio = decay_error(ode::rk4, 5) / decay_error(ode::rk4, 10);
+        assert_relative_eq!(ratio, 16.0, epsilon = 2.0);
+    }
+
+    #[test]
+    fn test_fixed_step_time_dependence() {
+        // dy/dt = 3t², so y(t) = t³; RK4 integrates cubics exactly.
+        let derivative = |t: f32, _: &Vector<1>| Vector::from([3.0 * t * t]);
+        let mut y = Vector::from([0.0]);
+        for i in 0..4 {
+            y = ode::rk4(derivative, i as f32 * 0.5, &y, 0.5);
+        }
+        assert_relative_eq!(y[0], 8.0, epsilon = 1e-5);
+
+        let y = ode::midpoint(derivative, 1.0, &Vector::from([1.0]), 1.0);
+        assert_relative_eq!(y[0], 1.0 + 3.0 * 1.5 * 1.5);
+        let y = ode::euler(derivative, 1.0, &Vector::from([1.0]), 1.0);
+        assert_relative_eq!(y[0], 4.0);
+    }
+
+    #[test]
+    fn test_rk45_step() {
+        let solver = Rk45::default();
+        let state = Vector::from([1.0, 0.0]);
+
+        let small = solver.step(oscillator, 0.0, &state, 0.1);
+        assert!(small.accepted);
+        assert!(small.next_dt > 0.1);
+        assert_relative_eq!(small.state[0], 0.1f32.cos(), epsilon = 1e-6);
+        assert_relative_eq!(small.state[1], -(0.1f32.sin()), epsilon = 1e-6);
+
+        let large = solver.step(oscillator, 0.0, &state, 3.0);
+        assert!(!large.accepted);
+        assert!(large.error > 1.0);
+        assert!(large.next_dt < 3.0);
+    }
+
+    #[test]
+    fn test_rk45_integrate() {
+        let solver = Rk45::new(1e-6, 1e-7);
+        let (y, report) = solver.integrate(oscillator, 0.0, &Vector::from([1.0, 0.0]), 10.0);
+        assert_relative_eq!(y[0], 10.0f32.cos(), epsilon = 1e-4);
+        assert_relative_eq!(y[1], -(10.0f32.sin()), epsilon = 1e-4);
+
+        assert!(report.accepted_steps > 0);
+        assert_eq!(report.forced_steps, 0);
+        let attempts = report.accepted_steps + report.rejected_steps;
+        assert_eq!(report.evaluations, 7 * attempts);
+        assert!(report.min_dt > 0.0 && report.min_dt <= report.max_dt);
+        assert!(report.next_dt > 0.0);
+
+        // A looser tolerance takes fewer steps.
+        let (_, loose) =
+            Rk45::new(1e-3, 1e-4).integrate(oscillator, 0.0, &Vector::from([1.0, 0.0]), 10.0);
+        assert!(loose.accepted_steps < report.accepted_steps);
+    }
+
+    #[test]
+    fn test_rk45_rejects_oversized_first_step() {
+        let solver = Rk45::default();
+        let (y, report) = solver.integrate_with_step(decay, 0.0, &Vector::from([1.0]), 2.0, 100.0);
+        assert!(report.rejected_steps > 0);
+        assert_relative_eq!(y[0], (-2.0f32).exp(), epsilon = 1e-4);
+    }
+
+    #[test]
+    fn test_rk45_step_limits() {
+        let solver = Rk45 {
+            max_step: 0.25,
+            ..Rk45::default()
+        };
+        let (_, report) = solver.integrate(decay, 0.0, &Vector::from([1.0]), 2.0);
+        assert!(report.max_dt <= 0.25);
+        assert!(report.accepted_steps >= 8);
+
+        // Steps at the minimum size are accepted even when they miss the tolerance.
+        let solver = Rk45 {
+            min_step: 0.5,
+            ..Rk45::new(1e-12, 1e-12)
+        };
+        let (_, report) = solver.integrate(oscillator, 0.0, &Vector::from([1.0, 0.0]), 2.0);
+        assert_eq!(report.forced_steps, 4);
+        assert_eq!(report.rejected_steps, 0);
+    }
+
+    #[test]
+    fn test_rk45_integrates_backwards() {
+        let solver = Rk45::default();
+        let start = Vector::from([(-2.0f32).exp()]);
+        let (y, report) = solver.integrate(decay, 2.0, &start, 0.0);
+        assert_relative_eq!(y[0], 1.0, epsilon = 1e-4);
+        assert!(report.next_dt < 0.0);
+
+        let (y, report) = solver.integrate(decay, 1.0, &start, 1.0);
+        assert_relative_eq!(y[0], start[0]);
+        assert_eq!(report.evaluations, 0);
+    }
+
+    #[test]
+    fn test_rk45_stalls_on_non_finite_derivative() {
+        // The derivative blows up past t = 1, so every step crossing it is rejected until the
+        // step reaches its minimum size.
+        let singular = |t: f32, y: &Vector<1>| {
+            if t > 1.0 {
+                Vector::from([f32::NAN])
+            } else {
+                -*y
+            }
+        };
+        let solver = Rk45 {
+            min_step: 1e-3,
+            ..Rk45::default()
+        };
+        let (y, report) = solver.integrate(singular, 0.0, &Vector::from([1.0]), 2.0);
+        assert!(report.stalled);
+        assert!(report.rejected_steps > 0);
+        assert!(report.reached <= 1.0 && report.reached > 0.9);
+        assert!(y[0].is_finite());
+
+        let step = solver.step(singular, 0.5, &Vector::from([1.0]), 1.0);
+        assert!(!step.accepted);
+        assert_relative_eq!(step.next_dt, 0.2);
+    }
+
+    #[test]
+    fn test_rk45_stalls_when_time_cannot_advance() {
+        // At t = 100 the smallest step does not change the time in f32.
+        let solver = Rk45::new(0.0, 1e-30);
+        let (_, report) = solver.integrate(decay, 100.0, &Vector::from([1.0]), 101.0);
+        assert!(report.stalled);
+        assert!(report.reached >= 100.0 && report.reached < 101.0);
+
+        let (_, report) = Rk45::default().integrate(decay, 0.0, &Vector::from([1.0]), 1.0);
+        assert!(!report.stalled);
+        assert_eq!(report.reached, 1.0);
+    }
+
+    #[test]
+    fn test_rk45_tolerates_invalid_step_limits() {
+        // A maximum below the minimum counts as the minimum instead of panicking.
+        let solver = Rk45 {
+            max_step: 1e-7,
+            ..Rk45::default()
+        };
+        let (_, report) = solver.integrate(decay, 0.0, &Vector::from([1.0]), 1e-3);
+        assert_eq!(report.max_dt, 1e-6);
+        assert_eq!(report.reached, 1e-3);
+
+        // NaN limits mean no limit.
+        let solver = Rk45 {
+            min_step: f32::NAN,
+            max_step: f32::NAN,
+            ..Rk45::default()
+        };
+        let (y, report) = solver.integrate(decay, 0.0, &Vector::from([1.0]), 1.0);
+        assert!(!report.stalled);
+        assert_relative_eq!(y[0], (-1.0f32).exp(), epsilon = 1e-5);
+    }
+}
@@ -1,6 +1,10 @@
+mod common;
+
 #[cfg(test)]
 mod tests {
+    use crate::common::assert_vec3_eq;
     use approx::assert_relative_eq;
+    use vectorama::matrix::Matrix;
     use vectorama::quaternion::{Quaternion, unit::UnitQuaternion};
     use vectorama::vector::vec3::Vec3;
 
@@ -313,4 +317,504 @@ mod tests {
         assert_relative_eq!(uq_mid.vector.z, expected.vector.z, epsilon = 1e-6);
         assert_relative_eq!(uq_mid.scalar, expected.scalar, epsilon = 1e-6);
     }
+
+    #[test]
+    fn test_quaternion_const_identity() {
+        const Q: Quaternion = Quaternion::IDENTITY;
+        const UQ: UnitQuaternion = UnitQuaternion::IDENTITY;
+        assert_relative_eq!(Q.vector.magnitude(), 0.0, epsilon = f32::EPSILON);
+        assert_relative_eq!(Q.scalar, 1.0, epsilon = f32::EPSILON);
+        assert_relative_eq!(UQ.vector.magnitude(), 0.0, epsilon = f32::EPSILON);
+        assert_relative_eq!(UQ.scalar, 1.0, epsilon = f32::EPSILON);
+    }
+
+    fn assert_quaternion_eq(a: Quaternion, b: Quaternion, epsilon: f32) {
+        assert_relative_eq!(a.vector.x, b.vector.x, epsilon = epsilon);
+        assert_relative_eq!(a.vector.y, b.vector.y, epsilon = epsilon);
+        assert_relative_eq!(a.vector.z, b.vector.z, epsilon = epsilon);
+        assert_relative_eq!(a.scalar, b.scalar, epsilon = epsilon);
+    }
+
+    #[test]
+    fn test_quaternion_add_sub_neg_scalar() {
+        let a = Quaternion::new(Vec3::new(1.0, 2.0, 3.0), 4.0);
+        let b = Quaternion::new(Vec3::new(0.5, -1.0, 2.0), -3.0);
+        assert_quaternion_eq(a + b, Quaternion::new(Vec3::new(1.5, 1.0, 5.0), 1.0), 0.0);
+        assert_quaternion_eq(a - b, Quaternion::new(Vec3::new(0.5, 3.0, 1.0), 7.0), 0.0);
+        assert_quaternion_eq(-a, Quaternion::new(Vec3::new(-1.0, -2.0, -3.0), -4.0), 0.0);
+        assert_quaternion_eq(a * 2.0, 2.0 * a, 0.0);
+        assert_quaternion_eq(a * 2.0, a + a, 0.0);
+
+        let mut c = a;
+        c += b;
+        c -= b;
+        c *= 3.0;
+        c /= 3.0;
+        assert_quaternion_eq(c, a, 1e-6);
+    }
+
+    #[test]
+    fn test_quaternion_div() {
+        let a = Quaternion::new(Vec3::new(1.0, 2.0, 3.0), 4.0);
+        let b = Quaternion::new(Vec3::new(0.5, -1.0, 2.0), -3.0);
+        assert_quaternion_eq((a / b) * b, a, 1e-5);
+        assert_quaternion_eq(a / a, Quaternion::IDENTITY, 1e-6);
+        let mut c = a;
+        c /= b;
+        assert_quaternion_eq(c, a / b, 0.0);
+    }
+
+    #[test]
+    fn test_quaternion_exp_ln_powf() {
+        let q = Quaternion::new(Vec3::new(0.3, -0.2, 0.5), 1.5);
+        assert_quaternion_eq(q.ln().exp(), q, 1e-5);
+        assert_quaternion_eq(q.powf(2.0), q * q, 1e-4);
+        assert_quaternion_eq(q.powf(0.5) * q.powf(0.5), q, 1e-5);
+        assert_quaternion_eq(q.powf(-1.0), q.inverse(), 1e-5);
+
+        // Pure real quaternions stay real.
+        let real = Quaternion::new(Vec3::ZERO, 2.0);
+        assert_quaternion_eq(real.ln(), Quaternion::new(Vec3::ZERO, 2f32.ln()), 1e-6);
+        assert_quaternion_eq(real.exp(), Quaternion::new(Vec3::ZERO, 2f32.exp()), 1e-5);
+        let negative = Quaternion::new(Vec3::ZERO, -1.0);
+        assert_quaternion_eq(negative.ln().exp(), negative, 1e-6);
+        assert_quaternion_eq(Quaternion::ZERO.powf(2.0), Quaternion::ZERO, 0.0);
+
+        // Tiny imaginary parts keep their direction, even next to a negative scalar.
+        let small = Quaternion::new(Vec3::new(0.0, 1e-8, 0.0), 1.0);
+        assert_relative_eq!(small.ln().vector.y, 1e-8, max_relative = 1e-5);
+        let nearly_negative = Quaternion::new(Vec3::new(0.0, 1e-8, 0.0), -1.0);
+        let log = nearly_negative.ln();
+        assert_relative_eq!(log.vector.y, std::f32::consts::PI, epsilon = 1e-6);
+        assert_relative_eq!(log.vector.x, 0.0);
+        let root = nearly_negative.powf(0.5);
+        assert_relative_eq!(root.vector.y, 1.0, epsilon = 1e-6);
+        assert_relative_eq!(root.vector.x, 0.0);
+    }
+
+    #[test]
+    fn test_unit_quaternion_log_exp_maps() {
+        let axis = Vec3::new(1.0, 2.0, -2.0).normalize();
+        let q = UnitQuaternion::from_axis_angle(axis, 1.2);
+        let log = q.log();
+        assert_relative_eq!(log.magnitude(), 1.2, epsilon = 1e-5);
+        assert_relative_eq!(log.normalize().dot(&axis), 1.0, epsilon = 1e-5);
+        assert_quaternion_eq(*UnitQuaternion::from_scaled_axis(log), *q, 1e-6);
+
+        // The log map picks the shortest rotation even for the negated quaternion.
+        let negated = UnitQuaternion::from(-*q);
+        assert_relative_eq!(negated.log().magnitude(), 1.2, epsilon = 1e-5);
+
+        let tiny = UnitQuaternion::from_scaled_axis(Vec3::new(1e-8, 0.0, 0.0));
+        assert_relative_eq!(tiny.log().x, 1e-8, epsilon = 1e-12);
+        assert_relative_eq!(UnitQuaternion::IDENTITY.log().magnitude(), 0.0);
+    }
+
+    #[test]
+    fn test_unit_quaternion_powf() {
+        let q = UnitQuaternion::from_axis_angle(Vec3::Y, 1.0);
+        let (axis, angle) = q.powf(0.25).to_axis_angle();
+        assert_relative_eq!(angle, 0.25, epsilon = 1e-5);
+        assert_relative_eq!(axis.y, 1.0, epsilon = 1e-5);
+        let inverse = q.powf(-1.0) * q;
+        assert_quaternion_eq(*inverse, Quaternion::IDENTITY, 1e-6);
+    }
+
+    #[test]
+    fn test_unit_quaternion_rotation_between() {
+        let cases = [
+            (Vec3::X, Vec3::Y),
+            (Vec3::new(1.0, 2.0, 3.0), Vec3::new(-2.0, 0.5, 1.0)),
+            (Vec3::X, Vec3::X * 5.0),
+            (Vec3::X, Vec3::NEG_X),
+            (Vec3::new(0.0, 0.0, 2.0), Vec3::NEG_Z),
+            (Vec3::new(1.0, 1.0, 0.0), Vec3::new(-1.0, -1.0, 0.0)),
+        ];
+        for (from, to) in cases {
+            let q = UnitQuaternion::rotation_between(from, to);
+            assert_vec3_eq(q * from.normalize(), to.normalize(), 1e-5);
+        }
+        let q = UnitQuaternion::rotation_between(Vec3::X, Vec3::Y);
+        assert_relative_eq!(q.angle(), std::f32::consts::FRAC_PI_2, epsilon = 1e-6);
+        let identity = UnitQuaternion::rotation_between(Vec3::ZERO, Vec3::Y);
+        assert_relative_eq!(identity.angle(), 0.0);
+        let identity = UnitQuaternion::rotation_between(Vec3::new(f32::NAN, 0.0, 0.0), Vec3::Y);
+        assert_relative_eq!(identity.angle(), 0.0);
+
+        // Short vectors are valid directions.
+        for scale in [1e-4, 1e-20] {
+            let q = UnitQuaternion::rotation_between(Vec3::X * scale, Vec3::new(0.0, scale, scale));
+            assert_vec3_eq(q * Vec3::X, Vec3::new(0.0, 1.0, 1.0).normalize(), 1e-5);
+        }
+    }
+
+    #[test]
+    fn test_unit_quaternion_look_rotation_matches_look_at() {
+        let eye = Vec3::new(1.0, 2.0, 3.0);
+        let forward = Vec3::new(-1.0, -0.5, -2.0);
+        let up = Vec3::Y;
+        let q = UnitQuaternion::look_rotation(forward, up);
+        assert_vec3_eq(q * Vec3::NEG_Z, forward.normalize(), 1e-5);
+        assert!((q * Vec3::Y).dot(&up) > 0.0);
+
+        let view = Matrix::<4, 4>::look_at(eye, eye + forward, up);
+        let inverse = q.inverse().rotation_matrix();
+        for n in 0..3 {
+            for m in 0..3 {
+                assert_relative_eq!(view[(m, n)], inverse[(m, n)], epsilon = 1e-5);
+            }
+        }
+
+        let straight_up = UnitQuaternion::look_rotation(Vec3::Y, Vec3::Y);
+        assert_vec3_eq(straight_up * Vec3::NEG_Z, Vec3::Y, 1e-5);
+    }
+
+    #[test]
+    fn test_unit_quaternion_angle_axis_and_scaled_axis() {
+        let axis = Vec3::new(0.0, 3.0, 4.0).normalize();
+        let q = UnitQuaternion::from_scaled_axis(axis * 2.0);
+        assert_relative_eq!(q.angle(), 2.0, epsilon = 1e-5);
+        assert_vec3_eq(q.axis().unwrap(), axis, 1e-5);
+
+        // The negated quaternion describes the same rotation.
+        let negated = UnitQuaternion::from(-*q);
+        assert_relative_eq!(negated.angle(), 2.0, epsilon = 1e-5);
+        assert_vec3_eq(negated.axis().unwrap(), axis, 1e-5);
+
+        // Angles above 180 degrees report the shorter rotation around the opposite axis.
+        let long = UnitQuaternion::from_axis_angle(axis, 5.0);
+        assert_relative_eq!(
+            long.angle(),
+            2.0 * std::f32::consts::PI - 5.0,
+            epsilon = 1e-5
+        );
+        assert_vec3_eq(long.axis().unwrap(), -axis, 1e-5);
+
+        assert!(UnitQuaternion::IDENTITY.axis().is_none());
+    }
+
+    #[test]
+    fn test_unit_quaternion_angle_to_and_rotate_towards() {
+        let a = UnitQuaternion::from_axis_angle(Vec3::Z, 0.2);
+        let b = UnitQuaternion::from_axis_angle(Vec3::Z, 1.2);
+        assert_relative_eq!(a.angle_to(&b), 1.0, epsilon = 1e-5);
+
+        let step = a.rotate_towards(&b, 0.25);
+        assert_relative_eq!(a.angle_to(&step), 0.25, epsilon = 1e-5);
+        assert_relative_eq!(step.angle_to(&b), 0.75, epsilon = 1e-5);
+
+        let arrived = a.rotate_towards(&b, 2.0);
+        assert_relative_eq!(arrived.angle_to(&b), 0.0, epsilon = 1e-5);
+    }
+
+    #[test]
+    #[allow(clippy::op_ref)]
+    fn test_unit_quaternion_mul_vec3() {
+        let q = UnitQuaternion::from_axis_angle(Vec3::Z, std::f32::consts::FRAC_PI_2);
+        let v = Vec3::new(1.0, 0.0, 2.0);
+        assert_vec3_eq(q * v, Vec3::new(0.0, 1.0, 2.0), 1e-6);
+        assert_vec3_eq(&q * &v, q.rotate_vector(v), 0.0);
+    }
+
+    fn assert_same_rotation(a: UnitQuaternion, b: UnitQuaternion) {
+        assert_relative_eq!(a.dot(&b).abs(), 1.0, epsilon = 1e-5);
+    }
+
+    #[test]
+    fn test_unit_quaternion_swing_twist() {
+        let twist = UnitQuaternion::from_axis_angle(Vec3::Y, 0.7);
+        let swing = UnitQuaternion::from_axis_angle(Vec3::new(1.0, 0.0, 1.0), 0.5);
+        let q = swing * twist;
+        let (s, t) = q.swing_twist(Vec3::Y);
+        assert_same_rotation(s, swing);
+        assert_same_rotation(t, twist);
+        assert_same_rotation(s * t, q);
+        assert_relative_eq!(s.vector.dot(&Vec3::Y), 0.0, epsilon = 1e-6);
+        assert_relative_eq!(q.twist_angle(Vec3::Y), 0.7, epsilon = 1e-5);
+        assert_relative_eq!(q.twist_angle(Vec3::NEG_Y), -0.7, epsilon = 1e-5);
+    }
+
+    #[test]
+    fn test_unit_quaternion_swing_twist_singularities() {
+        // Swinging the axis by 180 degrees leaves the twist undefined: all of it is swing.
+        let flip = UnitQuaternion::from_axis_angle(Vec3::X, std::f32::consts::PI);
+        let (s, t) = flip.swing_twist(Vec3::Z);
+        assert_same_rotation(s, flip);
+        assert_same_rotation(t, UnitQuaternion::IDENTITY);
+
+        // A 180 degree twist around the axis itself is pure twist.
+        let half_turn = UnitQuaternion::from_axis_angle(Vec3::Z, std::f32::consts::PI);
+        let (s, t) = half_turn.swing_twist(Vec3::Z);
+        assert_same_rotation(s, UnitQuaternion::IDENTITY);
+        assert_same_rotation(t, half_turn);
+        assert_relative_eq!(
+            half_turn.twist_angle(Vec3::Z).abs(),
+            std::f32::consts::PI,
+            epsilon = 1e-5
+        );
+
+        // Just short of the singularity the decomposition stays consistent.
+        let near = UnitQuaternion::from_axis_angle(Vec3::X, std::f32::consts::PI - 1e-3)
+            * UnitQuaternion::from_axis_angle(Vec3::Z, 0.3);
+        let (s, t) = near.swing_twist(Vec3::Z);
+        assert_same_rotation(s * t, near);
+        assert_relative_eq!(near.twist_angle(Vec3::Z), 0.3, epsilon = 1e-3);
+    }
+
+    #[test]
+    fn test_unit_quaternion_clamp_twist_and_swing() {
+        let swing = UnitQuaternion::from_axis_angle(Vec3::X, 1.0);
+        let twist = UnitQuaternion::from_axis_angle(Vec3::Y, 1.5);
+        let q = swing * twist;
+
+        let clamped = q.clamp_twist(Vec3::Y, -0.5, 0.5);
+        assert_relative_eq!(clamped.twist_angle(Vec3::Y), 0.5, epsilon = 1e-5);
+        assert_same_rotation(clamped.swing_twist(Vec3::Y).0, swing);
+
+        let clamped = q.clamp_swing(Vec3::Y, 0.25);
+        let (s, t) = clamped.swing_twist(Vec3::Y);
+        assert_relative_eq!(s.angle(), 0.25, epsilon = 1e-5);
+        assert_vec3_eq(s.axis().unwrap(), Vec3::X, 1e-5);
+        assert_same_rotation(t, twist);
+
+        let clamped = q.clamp_swing_twist(Vec3::Y, 0.25, -2.0, -1.0);
+        let (s, _) = clamped.swing_twist(Vec3::Y);
+        assert_relative_eq!(s.angle(), 0.25, epsilon = 1e-5);
+        assert_relative_eq!(clamped.twist_angle(Vec3::Y), -1.0, epsilon = 1e-5);
+
+        // Rotations already inside the limits are unchanged.
+        assert_same_rotation(q.clamp_swing_twist(Vec3::Y, 2.0, -2.0, 2.0), q);
+    }
+
+    #[test]
+    fn test_unit_quaternion_nlerp() {
+        let a = UnitQuaternion::from_axis_angle(Vec3::Z, 0.2);
+        let b = UnitQuaternion::from_axis_angle(Vec3::Z, 1.0);
+        assert_same_rotation(a.nlerp(&b, 0.5), a.slerp(&b, 0.5));
+        assert_same_rotation(a.nlerp(&b, 0.0), a);
+        assert_same_rotation(a.nlerp(&b, 1.0), b);
+
+        // The negated target describes the same rotation, so the shorter arc is still taken.
+        let negated = UnitQuaternion::from(-*b);
+        assert_same_rotation(a.nlerp(&negated, 0.5), a.slerp(&b, 0.5));
+    }
+
+    #[test]
+    fn test_unit_quaternion_slerp_unclamped() {
+        let a = UnitQuaternion::from_axis_angle(Vec3::Y, 0.3);
+        let b = UnitQuaternion::from_axis_angle(Vec3::Y, 0.8);
+        assert_same_rotation(a.slerp_unclamped(&b, 0.4), a.slerp(&b, 0.4));
+        let extrapolated = a.slerp_unclamped(&b, 2.0);
+        assert_relative_eq!(extrapolated.twist_angle(Vec3::Y), 1.3, epsilon = 1e-5);
+        let backwards = a.slerp_unclamped(&b, -1.0);
+        assert_relative_eq!(backwards.twist_angle(Vec3::Y), -0.2, epsilon = 1e-5);
+    }
+
+    #[test]
+    fn test_unit_quaternion_squad() {
+        let keys = [
+            UnitQuaternion::from_euler_angles(0.0, 0.0, 0.0),
+            UnitQuaternion::from_euler_angles(0.5, 0.3, 0.0),
+            UnitQuaternion::from_euler_angles(0.2, 1.2, -0.4),
+            UnitQuaternion::from_euler_angles(-0.3, 1.8, 0.1),
+        ];
+        let segment = |i: usize, t: f32| {
+            let previous = keys[i.saturating_sub(1)];
+            let next = keys[(i + 2).min(keys.len() - 1)];
+            UnitQuaternion::squad_spline(&previous, &keys[i], &keys[i + 1], &next, t)
+        };
+
+        for i in 0..3 {
+            assert_same_rotation(segment(i, 0.0), keys[i]);
+            assert_same_rotation(segment(i, 1.0), keys[i + 1]);
+        }
+
+        // Angular velocity is continuous across the interior keyframes.
+        let h = 1e-3;
+        for i in 0..2 {
+            let before = (segment(i, 1.0 - h).inverse() * keys[i + 1]).log() / h;
+            let after = (keys[i + 1].inverse() * segment(i + 1, h)).log() / h;
+            assert_vec3_eq(before, after, 1e-2);
+        }
+
+        let controls = (
+            UnitQuaternion::squad_control_point(&keys[0], &keys[1], &keys[2]),
+            UnitQuaternion::squad_control_point(&keys[1], &keys[2], &keys[3]),
+        );
+        assert_same_rotation(
+            keys[1].squad(&keys[2], &controls.0, &controls.1, 0.3),
+            segment(1, 0.3),
+        );
+    }
+
+    #[test]
+    fn test_unit_quaternion_bezier() {
+        let a = UnitQuaternion::from_axis_angle(Vec3::X, 0.0);
+        let b = UnitQuaternion::from_axis_angle(Vec3::X, 0.4);
+        let c = UnitQuaternion::from_axis_angle(Vec3::Y, 0.9);
+        let d = UnitQuaternion::from_axis_angle(Vec3::Z, 1.2);
+
+        assert_same_rotation(UnitQuaternion::bezier(&[a, b], 0.3), a.slerp(&b, 0.3));
+        assert_same_rotation(UnitQuaternion::bezier(&[c], 0.7), c);
+        assert_same_rotation(a.cubic_bezier(&b, &c, &d, 0.0), a);
+        assert_same_rotation(a.cubic_bezier(&b, &c, &d, 1.0), d);
+
+        // Quadratic De Casteljau evaluated by hand.
+        let t = 0.6;
+        let expected = a.slerp(&b, t).slerp(&b.slerp(&c, t), t);
+        assert_same_rotation(UnitQuaternion::bezier(&[a, b, c], t), expected);
+    }
+
+    #[test]
+    fn test_unit_quaternion_average() {
+        let a = UnitQuaternion::from_axis_angle(Vec3::Z, 0.2);
+        let b = UnitQuaternion::from_axis_angle(Vec3::Z, 0.6);
+        let c = UnitQuaternion::from_axis_angle(Vec3::Z, 1.0);
+
+        // Rotations about a shared axis average to the mean angle.
+        let average = UnitQuaternion::average(&[a, b, c]).unwrap();
+        assert!(average.geodesic_distance(&b) < 1e-4);
+
+        // The sign of each input does not matter.
+        let flipped = UnitQuaternion::from(-*c);
+        let average = UnitQuaternion::average(&[a, b, flipped]).unwrap();
+        assert!(average.geodesic_distance(&b) < 1e-4);
+
+        let weighted = UnitQuaternion::weighted_average(&[a, c], &[1.0, 0.0]).unwrap();
+        assert!(weighted.geodesic_distance(&a) < 1e-4);
+        let weighted = UnitQuaternion::weighted_average(&[a, c], &[2.5, 2.5]).unwrap();
+        assert!(weighted.geodesic_distance(&b) < 1e-4);
+
+        assert!(UnitQuaternion::average(&[]).is_none());
+        assert!(UnitQuaternion::weighted_average(&[a], &[0.0]).is_none());
+    }
+
+    #[test]
+    fn test_unit_quaternion_average_is_order_independent() {
+        let rotations = [
+            UnitQuaternion::from_euler_angles(0.1, 0.3, -0.2),
+            UnitQuaternion::from_euler_angles(-0.4, 0.2, 0.5),
+            UnitQuaternion::from_euler_angles(0.3, -0.1, 0.1),
+            UnitQuaternion::from_euler_angles(0.2, 0.4, -0.3),
+        ];
+        let mut reversed = rotations;
+        reversed.reverse();
+
+        let forward = UnitQuaternion::average(&rotations).unwrap();
+        let backward = UnitQuaternion::average(&reversed).unwrap();
+        assert!(forward.geodesic_distance(&backward) < 1e-4);
+
+        let approximate = UnitQuaternion::approximate_average(&rotations).unwrap();
+        assert!(forward.geodesic_distance(&approximate) < 1e-2);
+        assert!(UnitQuaternion::approximate_average(&[]).is_none());
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_unit_quaternion_weighted_average_length_mismatch() {
+        let q = UnitQuaternion::identity();
+        UnitQuaternion::weighted_average(&[q, q], &[1.0]);
+    }
+
+    #[test]
+    fn test_unit_quaternion_rotation_statistics() {
+        let mean = UnitQuaternion::from_axis_angle(Vec3::Y, 0.5);
+        let a = mean * UnitQuaternion::from_axis_angle(Vec3::X, 0.1);
+        let b = mean * UnitQuaternion::from_axis_angle(Vec3::Z, -0.3);
+
+        assert_relative_eq!(mean.geodesic_distance(&a), 0.1, epsilon = 1e-5);
+        assert_relative_eq!(a.geodesic_distance(&mean), 0.1, epsilon = 1e-5);
+        let flipped = UnitQuaternion::from(-*b);
+        assert_relative_eq!(flipped.geodesic_distance(&b), 0.0, epsilon = 1e-3);
+
+        let rotations = [a, b];
+        let variance = UnitQuaternion::angular_variance(&rotations, &mean).unwrap();
+        assert_relative_eq!(variance, (0.01 + 0.09) / 2.0, epsilon = 1e-5);
+        let deviation = UnitQuaternion::angular_deviation(&rotations, &mean).unwrap();
+        assert_relative_eq!(deviation, variance.sqrt(), epsilon = 1e-6);
+        let spread = UnitQuaternion::angular_spread(&rotations, &mean).unwrap();
+        assert_relative_eq!(spread, 0.3, epsilon = 1e-5);
+
+        assert!(UnitQuaternion::angular_variance(&[], &mean).is_none());
+        assert!(UnitQuaternion::angular_spread(&[], &mean).is_none());
+    }
+
+    #[test]
+    fn test_unit_quaternion_integrate() {
+        let orientation = UnitQuaternion::from_euler_angles(0.3, -0.2, 0.7);
+        let angular_velocity = Vec3::new(0.0, 0.0, 2.0);
+
+        let exact = orientation.integrate(angular_velocity, 0.25);
+        assert_same_rotation(exact, UnitQuaternion::from_z_axis(0.5) * orientation);
+
+        // Many small first-order steps converge to the exact result.
+        let mut stepped = orientation;
+        for _ in 0..1000 {
+            stepped = stepped.integrate_first_order(angular_velocity, 0.25 / 1000.0);
+        }
+        assert!(stepped.angle_to(&exact) < 1e-3);
+
+        // A single first-order step is only approximately right.
+        let single = orientation.integrate_first_order(angular_velocity, 0.25);
+        assert!(single.angle_to(&exact) < 0.05);
+    }
+
+    #[test]
+    fn test_unit_quaternion_angular_velocity_between() {
+        let from = UnitQuaternion::from_euler_angles(0.1, 0.4, -0.3);
+        let angular_velocity = Vec3::new(0.5, -1.0, 0.25);
+        let to = from.integrate(angular_velocity, 0.5);
+
+        let recovered = UnitQuaternion::angular_velocity_between(&from, &to, 0.5);
+        assert_vec3_eq(recovered, angular_velocity, 1e-4);
+
+        // The sign of the target does not change the result.
+        let flipped = UnitQuaternion::from(-*to);
+        let recovered = UnitQuaternion::angular_velocity_between(&from, &flipped, 0.5);
+        assert_vec3_eq(recovered, angular_velocity, 1e-4);
+    }
+
+    #[test]
+    fn test_unit_quaternion_derivative() {
+        let orientation = UnitQuaternion::from_euler_angles(-0.6, 0.2, 0.9);
+        let angular_velocity = Vec3::new(1.5, 0.5, -2.0);
+
+        // Compare with a central finite difference of the exact integration.
+        let h = 1e-3;
+        let forward = *orientation.integrate(angular_velocity, h);
+        let backward = *orientation.integrate(angular_velocity, -h);
+        let finite_difference = (forward - backward) / (2.0 * h);
+        let derivative = orientation.derivative(angular_velocity);
+        assert_quaternion_eq(derivative, finite_difference, 1e-3);
+
+        let recovered = orientation.angular_velocity_from_derivative(&derivative);
+        assert_vec3_eq(recovered, angular_velocity, 1e-5);
+    }
+
+    #[test]
+    fn test_unit_quaternion_rotate_inertia() {
+        let inertia = Matrix::from_rows([[1.0, 0.0, 0.0], [0.0, 2.0, 0.0], [0.0, 0.0, 3.0]]);
+
+        // A quarter turn around Z swaps the X and Y principal moments.
+        let rotated =
+            UnitQuaternion::from_z_axis(std::f32::consts::FRAC_PI_2).rotate_inertia(&inertia);
+        let expected = [[2.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 3.0]];
+        for (m, row) in expected.iter().enumerate() {
+            for (n, value) in row.iter().enumerate() {
+                assert_relative_eq!(rotated[(m, n)], *value, epsilon = 1e-5);
+            }
+        }
+
+        // The world-frame tensor maps world angular velocity to world angular momentum.
+        let orientation = UnitQuaternion::from_euler_angles(0.4, -0.8, 0.3);
+        let world = orientation.rotate_inertia(&inertia);
+        let body_velocity = Vec3::new(0.2, -1.0, 0.7);
+        let body_momentum = inertia * body_velocity;
+        let world_momentum = world * orientation.rotate_vector(body_velocity);
+        assert_vec3_eq(
+            world_momentum,
+            orientation.rotate_vector(body_momentum),
+            1e-5,
+        );
+    }
 }
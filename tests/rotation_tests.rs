@@ -0,0 +1,124 @@
+#[cfg(test)]
+mod tests {
+    use std::f32::consts::{FRAC_PI_2, FRAC_PI_4, PI};
+
+    use approx::assert_relative_eq;
+    use vectorama::matrix::Matrix;
+    use vectorama::rotation::rotation2::Rotation2;
+    use vectorama::vector::vec2::Vec2;
+
+    #[test]
+    fn test_rotation2_from_angle_and_angle() {
+        let r = Rotation2::from_angle(0.7);
+        assert_relative_eq!(r.angle(), 0.7, epsilon = 1e-6);
+        assert_relative_eq!(r.cos(), 0.7f32.cos(), epsilon = 1e-6);
+        assert_relative_eq!(r.sin(), 0.7f32.sin(), epsilon = 1e-6);
+        assert_relative_eq!(Rotation2::IDENTITY.angle(), 0.0, epsilon = f32::EPSILON);
+        assert_relative_eq!(
+            Rotation2::from_angle(3.0 * PI / 2.0).angle(),
+            -FRAC_PI_2,
+            epsilon = 1e-5
+        );
+    }
+
+    #[test]
+    fn test_rotation2_rotate_vector() {
+        let r = Rotation2::from_angle(FRAC_PI_2);
+        let v = r.rotate_vector(Vec2::new(1.0, 2.0));
+        assert_relative_eq!(v.x, -2.0, epsilon = 1e-6);
+        assert_relative_eq!(v.y, 1.0, epsilon = 1e-6);
+
+        let back = r.inverse_rotate_vector(v);
+        assert_relative_eq!(back.x, 1.0, epsilon = 1e-6);
+        assert_relative_eq!(back.y, 2.0, epsilon = 1e-6);
+
+        let w = r * Vec2::X;
+        assert_relative_eq!(w.x, 0.0, epsilon = 1e-6);
+        assert_relative_eq!(w.y, 1.0, epsilon = 1e-6);
+    }
+
+    #[test]
+    fn test_rotation2_composition_and_inverse() {
+        let a = Rotation2::from_angle(0.3);
+        let b = Rotation2::from_angle(0.5);
+        assert_relative_eq!((a * b).angle(), 0.8, epsilon = 1e-6);
+        assert_relative_eq!((a * a.inverse()).angle(), 0.0, epsilon = 1e-6);
+
+        let mut c = a;
+        c *= b;
+        assert_relative_eq!(c.angle(), 0.8, epsilon = 1e-6);
+        assert_relative_eq!(a.angle_to(&b), 0.2, epsilon = 1e-6);
+    }
+
+    #[test]
+    fn test_rotation2_slerp_takes_shorter_arc() {
+        let a = Rotation2::from_angle(0.25);
+        let b = Rotation2::from_angle(1.25);
+        assert_relative_eq!(a.slerp(&b, 0.5).angle(), 0.75, epsilon = 1e-6);
+        assert_relative_eq!(a.slerp(&b, 0.0).angle(), 0.25, epsilon = 1e-6);
+        assert_relative_eq!(a.slerp(&b, 1.0).angle(), 1.25, epsilon = 1e-6);
+
+        // From 170 to -170 degrees the short way crosses 180 degrees.
+        let a = Rotation2::from_angle(170f32.to_radians());
+        let b = Rotation2::from_angle(-170f32.to_radians());
+        assert_relative_eq!(a.slerp(&b, 0.5).angle().abs(), PI, epsilon = 1e-5);
+    }
+
+    #[test]
+    fn test_rotation2_rotation_between() {
+        let r = Rotation2::rotation_between(Vec2::new(2.0, 0.0), Vec2::new(0.0, 3.0));
+        assert_relative_eq!(r.angle(), FRAC_PI_2, epsilon = 1e-6);
+
+        let r = Rotation2::rotation_between(Vec2::new(1.0, 1.0), Vec2::X);
+        assert_relative_eq!(r.angle(), -FRAC_PI_4, epsilon = 1e-6);
+
+        let r = Rotation2::rotation_between(Vec2::ZERO, Vec2::X);
+        assert_relative_eq!(r.angle(), 0.0, epsilon = f32::EPSILON);
+
+        // Short vectors are valid directions, however small their products get.
+        let r = Rotation2::rotation_between(Vec2::new(1e-4, 0.0), Vec2::new(0.0, 1e-4));
+        assert_relative_eq!(r.angle(), FRAC_PI_2, epsilon = 1e-6);
+        let r = Rotation2::rotation_between(Vec2::new(1e-20, 1e-20), Vec2::new(-1e-20, 0.0));
+        assert_relative_eq!(r.angle(), 3.0 * FRAC_PI_4, epsilon = 1e-6);
+    }
+
+    #[test]
+    fn test_rotation2_new_normalized_small_and_invalid() {
+        let r = Rotation2::new_normalized(1e-5, 1e-5);
+        assert_relative_eq!(r.angle(), FRAC_PI_4, epsilon = 1e-6);
+        let r = Rotation2::new_normalized(0.0, -1e-30);
+        assert_relative_eq!(r.angle(), -FRAC_PI_2, epsilon = 1e-6);
+
+        for (cos, sin) in [(0.0, 0.0), (f32::NAN, 1.0), (f32::INFINITY, 1.0)] {
+            let r = Rotation2::new_normalized(cos, sin);
+            assert_relative_eq!(r.angle(), 0.0, epsilon = f32::EPSILON);
+        }
+    }
+
+    #[test]
+    fn test_rotation2_matrices() {
+        let r = Rotation2::from_angle(0.4);
+        let m = r.rotation_matrix();
+        let v = Vec2::new(1.0, -2.0);
+        let expected = r * v;
+        let rotated = m * v;
+        assert_relative_eq!(rotated.x, expected.x, epsilon = 1e-6);
+        assert_relative_eq!(rotated.y, expected.y, epsilon = 1e-6);
+
+        let h = r.homogeneous_matrix();
+        assert_relative_eq!(h[(0, 0)], m[(0, 0)], epsilon = f32::EPSILON);
+        assert_relative_eq!(h[(1, 0)], m[(1, 0)], epsilon = f32::EPSILON);
+        assert_relative_eq!(h[(0, 1)], m[(0, 1)], epsilon = f32::EPSILON);
+        assert_relative_eq!(h[(2, 2)], 1.0, epsilon = f32::EPSILON);
+        assert_relative_eq!(h[(0, 2)], 0.0, epsilon = f32::EPSILON);
+
+        assert_relative_eq!(Rotation2::from(m).angle(), 0.4, epsilon = 1e-6);
+        assert_relative_eq!(Rotation2::from(h).angle(), 0.4, epsilon = 1e-6);
+
+        let scaled = m * 3.0;
+        assert_relative_eq!(Rotation2::from(scaled).angle(), 0.4, epsilon = 1e-6);
+
+        let back: Matrix<2, 2> = r.into();
+        assert_relative_eq!(back[(1, 0)], 0.4f32.sin(), epsilon = 1e-6);
+    }
+}
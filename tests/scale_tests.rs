@@ -51,4 +51,58 @@ mod tests {
         assert_relative_eq!(s2.y, 3.0, epsilon = f32::EPSILON);
         assert_relative_eq!(s2.z, 4.0, epsilon = f32::EPSILON);
     }
+
+    #[test]
+    fn test_scale3_const_new() {
+        const HALF: Scale3 = Scale3::new(0.5, 0.5, 0.5);
+        let result = HALF * (Scale3::IDENTITY * Vec3::new(2.0, 4.0, 6.0));
+        assert_relative_eq!(result.x, 1.0, epsilon = f32::EPSILON);
+        assert_relative_eq!(result.y, 2.0, epsilon = f32::EPSILON);
+        assert_relative_eq!(result.z, 3.0, epsilon = f32::EPSILON);
+    }
+
+    #[test]
+    fn test_scale3_composition_inverse_and_lerp() {
+        let a = Scale3::new(2.0, 3.0, 4.0);
+        let b = Scale3::new(0.5, 2.0, -1.0);
+        let c = a * b;
+        assert_relative_eq!(c.x, 1.0, epsilon = f32::EPSILON);
+        assert_relative_eq!(c.y, 6.0, epsilon = f32::EPSILON);
+        assert_relative_eq!(c.z, -4.0, epsilon = f32::EPSILON);
+
+        let inverse = a.try_inverse().unwrap();
+        let v = inverse * (a * Vec3::new(1.0, -2.0, 3.0));
+        assert_relative_eq!(v.x, 1.0, epsilon = 1e-6);
+        assert_relative_eq!(v.y, -2.0, epsilon = 1e-6);
+        assert_relative_eq!(v.z, 3.0, epsilon = 1e-6);
+        assert!(Scale3::new(1.0, 0.0, 1.0).try_inverse().is_none());
+        assert!(Scale3::new(1.0, 1e-39, 1.0).try_inverse().is_none());
+
+        // Tiny but representable factors still invert.
+        let tiny = Scale3::new(1e-8, 2e-8, 1.0).try_inverse().unwrap();
+        assert_relative_eq!(tiny.x, 1e8, max_relative = 1e-6);
+        assert_relative_eq!(tiny.y, 5e7, max_relative = 1e-6);
+
+        let mut d = a;
+        d *= b;
+        assert_relative_eq!(d.y, 6.0, epsilon = f32::EPSILON);
+
+        let mid = Scale3::IDENTITY.lerp(&a, 0.5);
+        assert_relative_eq!(mid.x, 1.5, epsilon = f32::EPSILON);
+        assert_relative_eq!(mid.z, 2.5, epsilon = f32::EPSILON);
+    }
+
+    #[test]
+    fn test_scale2_composition_and_inverse() {
+        let a = Scale2::new(2.0, -4.0);
+        let b = a * a.try_inverse().unwrap();
+        assert_relative_eq!(b.x, 1.0, epsilon = f32::EPSILON);
+        assert_relative_eq!(b.y, 1.0, epsilon = f32::EPSILON);
+        assert!(Scale2::new(0.0, 1.0).try_inverse().is_none());
+        let tiny = Scale2::new(1e-8, -1.0).try_inverse().unwrap();
+        assert_relative_eq!(tiny.x, 1e8, max_relative = 1e-6);
+        let v = (a * Scale2::new(0.5, 0.5)) * Vec2::ONE;
+        assert_relative_eq!(v.x, 1.0, epsilon = f32::EPSILON);
+        assert_relative_eq!(v.y, -2.0, epsilon = f32::EPSILON);
+    }
 }
@@ -0,0 +1,284 @@
+#[cfg(test)]
+mod tests {
+    use std::f32::consts::FRAC_PI_2;
+
+    use approx::assert_relative_eq;
+    use vectorama::matrix::Matrix;
+    use vectorama::quaternion::unit::UnitQuaternion;
+    use vectorama::rotation::rotation2::Rotation2;
+    use vectorama::scale::{scale2::Scale2, scale3::Scale3};
+    use vectorama::transform::{affine2::Affine2, isometry2::Isometry2, similarity2::Similarity2};
+    use vectorama::translation::{translation2::Translation2, translation3::Translation3};
+    use vectorama::vector::{vec2::Vec2, vec3::Vec3};
+    use vectorama::{Transform2, Transform3};
+
+    fn assert_vec2_eq(actual: Vec2, expected: Vec2) {
+        assert_relative_eq!(actual.x, expected.x, epsilon = 1e-5);
+        assert_relative_eq!(actual.y, expected.y, epsilon = 1e-5);
+    }
+
+    #[test]
+    fn test_isometry2_transform_point_and_vector() {
+        let iso = Isometry2::new(Vec2::new(1.0, 2.0), FRAC_PI_2);
+        assert_vec2_eq(iso.transform_point(&Vec2::X), Vec2::new(1.0, 3.0));
+        assert_vec2_eq(iso.transform_vector(&Vec2::X), Vec2::Y);
+        assert_vec2_eq(iso * Vec2::Y, Vec2::new(0.0, 2.0));
+        assert_vec2_eq(iso.inverse_transform_point(&Vec2::new(1.0, 3.0)), Vec2::X);
+        assert_vec2_eq(iso.inverse_transform_vector(&Vec2::Y), Vec2::X);
+    }
+
+    #[test]
+    fn test_isometry2_composition_and_inverse() {
+        let a = Isometry2::new(Vec2::new(1.0, -2.0), 0.3);
+        let b = Isometry2::new(Vec2::new(0.5, 4.0), -1.1);
+        let p = Vec2::new(2.0, 3.0);
+        assert_vec2_eq((a * b) * p, a * (b * p));
+
+        let identity = a * a.inverse();
+        assert_vec2_eq(identity * p, p);
+        assert_relative_eq!(identity.rotation.angle(), 0.0, epsilon = 1e-6);
+
+        let mut c = a;
+        c *= b;
+        assert_vec2_eq(c * p, a * (b * p));
+    }
+
+    #[test]
+    fn test_isometry2_homogeneous_matrix_round_trip() {
+        let iso = Isometry2::new(Vec2::new(3.0, -1.0), 0.8);
+        let matrix = iso.homogeneous_matrix();
+        let expected = Translation2::new(3.0, -1.0).homogeneous_matrix()
+            * Rotation2::from_angle(0.8).homogeneous_matrix();
+        for n in 0..3 {
+            for m in 0..3 {
+                assert_relative_eq!(matrix[(m, n)], expected[(m, n)], epsilon = 1e-6);
+            }
+        }
+
+        let back = Isometry2::from(matrix);
+        assert_relative_eq!(back.rotation.angle(), 0.8, epsilon = 1e-6);
+        assert_relative_eq!(back.translation.x, 3.0, epsilon = 1e-6);
+        assert_relative_eq!(back.translation.y, -1.0, epsilon = 1e-6);
+    }
+
+    #[test]
+    fn test_isometry2_lerp_slerp() {
+        let a = Isometry2::new(Vec2::ZERO, 0.0);
+        let b = Isometry2::new(Vec2::new(2.0, 4.0), 1.0);
+        let mid = a.lerp_slerp(&b, 0.5);
+        assert_vec2_eq(*mid.translation, Vec2::new(1.0, 2.0));
+        assert_relative_eq!(mid.rotation.angle(), 0.5, epsilon = 1e-6);
+    }
+
+    #[test]
+    fn test_similarity2_transform_and_inverse() {
+        let sim = Similarity2::new(Vec2::new(1.0, 0.0), FRAC_PI_2, 2.0);
+        assert_vec2_eq(sim * Vec2::X, Vec2::new(1.0, 2.0));
+        assert_vec2_eq(sim.transform_vector(&Vec2::X), Vec2::new(0.0, 2.0));
+
+        let inverse = sim.try_inverse().unwrap();
+        assert_vec2_eq(inverse * Vec2::new(1.0, 2.0), Vec2::X);
+        assert!(Similarity2::from_scaling(0.0).try_inverse().is_none());
+        assert!(Similarity2::from_scaling(1e-39).try_inverse().is_none());
+        let small = Similarity2::from_scaling(1e-8).try_inverse().unwrap();
+        assert_relative_eq!(small.scale, 1e8, max_relative = 1e-6);
+
+        let other = Similarity2::new(Vec2::new(-3.0, 0.5), 0.4, 0.5);
+        let p = Vec2::new(0.7, -1.3);
+        assert_vec2_eq((sim * other) * p, sim * (other * p));
+        assert_relative_eq!((sim * other).scale, 1.0, epsilon = 1e-6);
+    }
+
+    #[test]
+    fn test_similarity2_homogeneous_matrix_round_trip() {
+        let sim = Similarity2::new(Vec2::new(2.0, 5.0), -0.6, 3.0);
+        let matrix = sim.homogeneous_matrix();
+        let p = Vec2::new(1.5, -0.5);
+        let expected = sim * p;
+        let transformed = matrix * p.xyz(1.0);
+        assert_relative_eq!(transformed.x, expected.x, epsilon = 1e-5);
+        assert_relative_eq!(transformed.y, expected.y, epsilon = 1e-5);
+
+        let back = Similarity2::from(matrix);
+        assert_relative_eq!(back.scale, 3.0, epsilon = 1e-5);
+        assert_relative_eq!(back.rotation().angle(), -0.6, epsilon = 1e-6);
+        assert_vec2_eq(*back.translation(), Vec2::new(2.0, 5.0));
+    }
+
+    #[test]
+    fn test_affine2_composition_and_inverse() {
+        let a = Affine2::from(Translation2::new(1.0, 2.0))
+            * Affine2::from(Rotation2::from_angle(0.5))
+            * Affine2::from_shear(0.3)
+            * Affine2::from(Scale2::new(2.0, -1.0));
+        let p = Vec2::new(0.4, 1.7);
+        let inverse = a.try_inverse().unwrap();
+        assert_vec2_eq(inverse * (a * p), p);
+        assert_vec2_eq(a.transform_vector(&Vec2::ZERO), Vec2::ZERO);
+
+        let singular = Affine2::from(Scale2::new(0.0, 1.0));
+        assert!(singular.try_inverse().is_none());
+    }
+
+    #[test]
+    fn test_affine2_decompose() {
+        let translation = Translation2::new(-1.0, 3.0);
+        let rotation = Rotation2::from_angle(1.2);
+        let scale = Scale2::new(2.0, 0.5);
+        let a = Affine2::from(translation)
+            * Affine2::from(rotation)
+            * Affine2::from(scale)
+            * Affine2::from_shear(0.25);
+
+        let (t, r, s, shear) = a.decompose();
+        assert_vec2_eq(*t, *translation);
+        assert_relative_eq!(r.angle(), 1.2, epsilon = 1e-5);
+        assert_vec2_eq(*s, *scale);
+        assert_relative_eq!(shear, 0.25, epsilon = 1e-5);
+
+        let b = Affine2::from_scale_rotation_translation(scale, rotation, translation);
+        let (s, r, t) = b.to_scale_rotation_translation();
+        assert_vec2_eq(*s, *scale);
+        assert_relative_eq!(r.angle(), 1.2, epsilon = 1e-5);
+        assert_vec2_eq(*t, *translation);
+    }
+
+    #[test]
+    fn test_affine2_matrix_interop() {
+        let sim = Similarity2::new(Vec2::new(1.0, 1.0), 0.2, 1.5);
+        let a = Affine2::from(sim);
+        let p = Vec2::new(-2.0, 0.5);
+        assert_vec2_eq(a * p, sim * p);
+
+        let matrix = a.homogeneous_matrix();
+        assert_relative_eq!(matrix[(2, 0)], 0.0, epsilon = f32::EPSILON);
+        assert_relative_eq!(matrix[(2, 2)], 1.0, epsilon = f32::EPSILON);
+        let from_translation = Affine2::from(Translation2::new(4.0, 5.0).homogeneous_matrix());
+        assert_vec2_eq(from_translation * Vec2::ZERO, Vec2::new(4.0, 5.0));
+        assert_relative_eq!(a.determinant(), 1.5 * 1.5, epsilon = 1e-5);
+    }
+
+    #[test]
+    fn test_mixed_products_2d() {
+        let t = Translation2::new(1.0, 2.0);
+        let r = Rotation2::from_angle(FRAC_PI_2);
+        let s = Scale2::new(2.0, 3.0);
+        let p = Vec2::new(1.0, 1.0);
+
+        let tr: Isometry2 = t * r;
+        assert_vec2_eq(tr * p, t * (r * p));
+        let rt: Isometry2 = r * t;
+        assert_vec2_eq(rt * p, r * (t * p));
+        assert_vec2_eq((tr * t) * p, tr * (t * p));
+        assert_vec2_eq((r * tr) * p, r * (tr * p));
+
+        let ts: Affine2 = t * s;
+        assert_vec2_eq(ts * p, t * (s * p));
+        let st: Affine2 = s * t;
+        assert_vec2_eq(st * p, s * (t * p));
+        let rs: Affine2 = r * s;
+        assert_vec2_eq(rs * p, r * (s * p));
+        assert_vec2_eq((tr * s) * p, tr * (s * p));
+
+        let m = s * t.homogeneous_matrix();
+        let transformed = m * p.xyz(1.0);
+        assert_vec2_eq(transformed.xy(), s * (t * p));
+        let m = t.homogeneous_matrix() * r;
+        let transformed = m * p.xyz(1.0);
+        assert_vec2_eq(transformed.xy(), t * (r * p));
+    }
+
+    #[test]
+    fn test_mixed_products_3d() {
+        let t = Translation3::new(1.0, 2.0, 3.0);
+        let q = UnitQuaternion::from_axis_angle(Vec3::Y, 0.7);
+        let s = Scale3::new(2.0, 0.5, -1.0);
+        let p = Vec3::new(0.3, -1.2, 2.0);
+
+        let check = |m: Matrix<4, 4>, expected: Vec3| {
+            let transformed = m * p.xyzw(1.0);
+            assert_relative_eq!(transformed.x, expected.x, epsilon = 1e-5);
+            assert_relative_eq!(transformed.y, expected.y, epsilon = 1e-5);
+            assert_relative_eq!(transformed.z, expected.z, epsilon = 1e-5);
+            assert_relative_eq!(transformed.w, 1.0, epsilon = 1e-6);
+        };
+        check(t * q, t * q.rotate_vector(p));
+        check(q * t, q.rotate_vector(t * p));
+        check(t * s, t * (s * p));
+        check(s * t, s * (t * p));
+        check(q * s, q.rotate_vector(s * p));
+        check(s * q, s * q.rotate_vector(p));
+        check(t * (q * s), t * q.rotate_vector(s * p));
+        check((t * q) * s, t * q.rotate_vector(s * p));
+        check(Matrix::<4, 4>::identity() * t, t * p);
+    }
+
+    fn round_trip_3d<T: Transform3>(transform: &T, point: Vec3) {
+        let inverse = transform.try_inverse().unwrap();
+        let back = inverse.transform_point(&transform.transform_point(&point));
+        assert_relative_eq!(back.x, point.x, epsilon = 1e-5);
+        assert_relative_eq!(back.y, point.y, epsilon = 1e-5);
+        assert_relative_eq!(back.z, point.z, epsilon = 1e-5);
+
+        let via_matrix = transform.to_homogeneous().transform_point(&point);
+        let direct = transform.transform_point(&point);
+        assert_relative_eq!(via_matrix.x, direct.x, epsilon = 1e-5);
+        assert_relative_eq!(via_matrix.y, direct.y, epsilon = 1e-5);
+        assert_relative_eq!(via_matrix.z, direct.z, epsilon = 1e-5);
+    }
+
+    fn round_trip_2d<T: Transform2>(transform: &T, point: Vec2) {
+        let inverse = transform.try_inverse().unwrap();
+        assert_vec2_eq(
+            inverse.transform_point(&transform.transform_point(&point)),
+            point,
+        );
+        assert_vec2_eq(
+            transform.to_homogeneous().transform_point(&point),
+            transform.transform_point(&point),
+        );
+        assert_vec2_eq(
+            transform.to_homogeneous().transform_vector(&point),
+            transform.transform_vector(&point),
+        );
+    }
+
+    #[test]
+    fn test_transform3_trait() {
+        let p = Vec3::new(0.5, -1.0, 2.0);
+        round_trip_3d(&Translation3::new(1.0, 2.0, 3.0), p);
+        round_trip_3d(&Scale3::new(2.0, 0.5, -4.0), p);
+        round_trip_3d(
+            &UnitQuaternion::from_axis_angle(Vec3::new(1.0, 1.0, 0.0), 0.9),
+            p,
+        );
+        round_trip_3d(&Matrix::<4, 4>::perspective(1.5, 1.0, 0.1, 100.0), p);
+
+        let t = Translation3::new(1.0, 0.0, 0.0);
+        assert_relative_eq!(t.transform_vector(&Vec3::X).x, 1.0, epsilon = f32::EPSILON);
+        assert!(Transform3::try_inverse(&Scale3::new(0.0, 1.0, 1.0)).is_none());
+
+        let q = UnitQuaternion::from_axis_angle(Vec3::Z, FRAC_PI_2);
+        let combined = t.then(&q);
+        let moved = combined.transform_point(&Vec3::ZERO);
+        assert_relative_eq!(moved.x, 0.0, epsilon = 1e-6);
+        assert_relative_eq!(moved.y, 1.0, epsilon = 1e-6);
+    }
+
+    #[test]
+    fn test_transform2_trait() {
+        let p = Vec2::new(0.5, -1.5);
+        round_trip_2d(&Translation2::new(1.0, 2.0), p);
+        round_trip_2d(&Scale2::new(2.0, -0.5), p);
+        round_trip_2d(&Rotation2::from_angle(0.4), p);
+        round_trip_2d(&Isometry2::new(Vec2::new(3.0, 1.0), -0.7), p);
+        round_trip_2d(&Similarity2::new(Vec2::new(-1.0, 2.0), 1.1, 0.5), p);
+        round_trip_2d(&(Affine2::from_shear(0.5) * Rotation2::from_angle(0.2)), p);
+        round_trip_2d(&Scale2::new(2.0, 3.0).homogeneous_matrix(), p);
+
+        let moved = Rotation2::from_angle(FRAC_PI_2)
+            .then(&Translation2::new(1.0, 0.0))
+            .transform_point(&Vec2::X);
+        assert_vec2_eq(moved, Vec2::new(1.0, 1.0));
+    }
+}
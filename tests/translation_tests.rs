@@ -70,4 +70,47 @@ mod tests {
         assert_relative_eq!(result.y, 7.0, epsilon = f32::EPSILON);
         assert_relative_eq!(result.z, 9.0, epsilon = f32::EPSILON);
     }
+
+    #[test]
+    fn test_translation3_const_new() {
+        const OFFSET: Translation3 = Translation3::new(1.0, 2.0, 3.0);
+        let result = OFFSET * Translation3::IDENTITY.translate(&Vec3::ZERO);
+        assert_relative_eq!(result.x, 1.0, epsilon = f32::EPSILON);
+        assert_relative_eq!(result.y, 2.0, epsilon = f32::EPSILON);
+        assert_relative_eq!(result.z, 3.0, epsilon = f32::EPSILON);
+    }
+
+    #[test]
+    fn test_translation3_composition_inverse_and_lerp() {
+        let a = Translation3::new(1.0, 2.0, 3.0);
+        let b = Translation3::new(-4.0, 0.5, 1.0);
+        let c = a * b;
+        assert_relative_eq!(c.x, -3.0, epsilon = f32::EPSILON);
+        assert_relative_eq!(c.y, 2.5, epsilon = f32::EPSILON);
+        assert_relative_eq!(c.z, 4.0, epsilon = f32::EPSILON);
+
+        let identity = a * a.inverse();
+        assert_relative_eq!(identity.magnitude(), 0.0, epsilon = f32::EPSILON);
+
+        let mut d = a;
+        d *= b;
+        assert_relative_eq!(d.x, c.x, epsilon = f32::EPSILON);
+
+        let mid = a.lerp(&b, 0.5);
+        assert_relative_eq!(mid.x, -1.5, epsilon = f32::EPSILON);
+        assert_relative_eq!(mid.y, 1.25, epsilon = f32::EPSILON);
+        assert_relative_eq!(mid.z, 2.0, epsilon = f32::EPSILON);
+    }
+
+    #[test]
+    fn test_translation2_composition_and_inverse() {
+        let a = Translation2::new(1.0, 2.0);
+        let b = Translation2::new(3.0, -1.0);
+        let v = (a * b) * Vec2::ZERO;
+        assert_relative_eq!(v.x, 4.0, epsilon = f32::EPSILON);
+        assert_relative_eq!(v.y, 1.0, epsilon = f32::EPSILON);
+        let back = a.inverse() * (a * Vec2::ONE);
+        assert_relative_eq!(back.x, 1.0, epsilon = f32::EPSILON);
+        assert_relative_eq!(back.y, 1.0, epsilon = f32::EPSILON);
+    }
 }
@@ -1,7 +1,7 @@
 #[cfg(test)]
 mod tests {
     use approx::assert_relative_eq;
-    use vectorama::vector::vec2::Vec2;
+    use vectorama::vector::{Vector, vec2::Vec2};
 
     #[test]
     fn test_vec2_new_and_fields() {
@@ -101,4 +101,65 @@ mod tests {
         assert_relative_eq!(vec3.y, 2.0, epsilon = f32::EPSILON);
         assert_relative_eq!(vec3.z, 3.0, epsilon = f32::EPSILON);
     }
+
+    #[test]
+    fn test_vec2_constants() {
+        const RIGHT: Vec2 = Vec2::X;
+        assert_relative_eq!(RIGHT.x, 1.0, epsilon = f32::EPSILON);
+        assert_relative_eq!(RIGHT.y, 0.0, epsilon = f32::EPSILON);
+        assert_relative_eq!(Vec2::NEG_Y.y, -1.0, epsilon = f32::EPSILON);
+        assert_relative_eq!(Vec2::zeros().magnitude(), 0.0, epsilon = f32::EPSILON);
+    }
+
+    #[test]
+    fn test_vec2_array_and_matrix_views() {
+        let mut v = Vec2::new(1.0, 2.0);
+        let slice: &[f32] = v.as_ref();
+        assert_eq!(slice, &[1.0, 2.0]);
+        assert_eq!(v.as_array(), &[1.0, 2.0]);
+
+        let matrix = Vector::<2>::from(v);
+        for m in 0..2 {
+            assert_relative_eq!(matrix[m], slice[m], epsilon = f32::EPSILON);
+        }
+        let back = Vec2::from(matrix);
+        assert_relative_eq!(back.x, 1.0, epsilon = f32::EPSILON);
+        assert_relative_eq!(back.y, 2.0, epsilon = f32::EPSILON);
+
+        v.x = 10.0;
+        assert_relative_eq!(v.as_array()[0], 10.0, epsilon = f32::EPSILON);
+    }
+
+    #[test]
+    fn test_vec2_swizzles() {
+        let mut v = Vec2::new(1.0, 2.0);
+        assert_eq!(v.yx().as_array(), &[2.0, 1.0]);
+        assert_eq!(v.xxyy().as_array(), &[1.0, 1.0, 2.0, 2.0]);
+        assert_eq!(v.gr().as_array(), v.ts().as_array());
+        v.set_yx(Vec2::new(3.0, 4.0));
+        assert_eq!(v.as_array(), &[4.0, 3.0]);
+    }
+
+    #[test]
+    fn test_vec2_component_wise() {
+        let a = Vec2::new(1.5, -2.0);
+        let b = Vec2::new(2.0, 4.0);
+        assert_eq!((a * b).as_array(), &[3.0, -8.0]);
+        assert_eq!((a / b).as_array(), &[0.75, -0.5]);
+        assert_eq!(a.abs().max(&b).as_array(), &[2.0, 4.0]);
+        assert_eq!(a.fract().as_array(), &[0.5, 0.0]);
+        let sum: Vec2 = [a, b].into_iter().sum();
+        assert_eq!(sum.as_array(), &[3.5, 2.0]);
+    }
+
+    #[test]
+    fn test_vec2_perp_and_angle() {
+        use std::f32::consts::FRAC_PI_2;
+        let v = Vec2::new(2.0, 1.0);
+        let p = v.perp();
+        assert_eq!(p.as_array(), &[-1.0, 2.0]);
+        assert_relative_eq!(v.dot(&p), 0.0, epsilon = f32::EPSILON);
+        assert_relative_eq!(v.angle_between(&p), FRAC_PI_2, epsilon = 1e-6);
+        assert_relative_eq!(v.distance(&p), 10.0f32.sqrt(), epsilon = 1e-6);
+    }
 }
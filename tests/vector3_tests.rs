@@ -1,7 +1,7 @@
 #[cfg(test)]
 mod tests {
     use approx::assert_relative_eq;
-    use vectorama::vector::vec3::Vec3;
+    use vectorama::vector::{Vector, vec2::Vec2, vec3::Vec3};
 
     #[test]
     fn test_vec3_new_and_fields() {
@@ -140,4 +140,259 @@ mod tests {
         assert_relative_eq!(vec4.z, 3.0, epsilon = f32::EPSILON);
         assert_relative_eq!(vec4.w, 4.0, epsilon = f32::EPSILON);
     }
+
+    #[test]
+    fn test_vec3_constants() {
+        const UP: Vec3 = Vec3::Y;
+        assert_relative_eq!(UP.x, 0.0, epsilon = f32::EPSILON);
+        assert_relative_eq!(UP.y, 1.0, epsilon = f32::EPSILON);
+        assert_relative_eq!(UP.z, 0.0, epsilon = f32::EPSILON);
+
+        let forward = Vec3::NEG_Z;
+        assert_relative_eq!(forward.z, -1.0, epsilon = f32::EPSILON);
+        assert_relative_eq!(Vec3::ZERO.magnitude(), 0.0, epsilon = f32::EPSILON);
+        assert_relative_eq!(Vec3::ONE.x, Vec3::ones().x, epsilon = f32::EPSILON);
+        assert!(Vec3::NAN.x.is_nan() && Vec3::NAN.y.is_nan() && Vec3::NAN.z.is_nan());
+    }
+
+    #[test]
+    fn test_vec3_array_and_matrix_views() {
+        let mut v = Vec3::new(1.0, 2.0, 3.0);
+        let slice: &[f32] = v.as_ref();
+        assert_eq!(slice, &[1.0, 2.0, 3.0]);
+        assert_eq!(v.as_array(), &[1.0, 2.0, 3.0]);
+
+        let matrix = Vector::<3>::from(v);
+        for m in 0..3 {
+            assert_relative_eq!(matrix[m], slice[m], epsilon = f32::EPSILON);
+        }
+        let back = Vec3::from(matrix);
+        assert_relative_eq!(back.x, 1.0, epsilon = f32::EPSILON);
+        assert_relative_eq!(back.y, 2.0, epsilon = f32::EPSILON);
+        assert_relative_eq!(back.z, 3.0, epsilon = f32::EPSILON);
+
+        v.x = 10.0;
+        assert_relative_eq!(v.as_array()[0], 10.0, epsilon = f32::EPSILON);
+    }
+
+    #[test]
+    fn test_vec3_swizzles() {
+        let v = Vec3::new(1.0, 2.0, 3.0);
+        assert_eq!(v.zyx().as_array(), &[3.0, 2.0, 1.0]);
+        assert_eq!(v.zzxy().as_array(), &[3.0, 3.0, 1.0, 2.0]);
+        assert_eq!(v.bgr().as_array(), v.zyx().as_array());
+        assert_eq!(v.pts().as_array(), v.zyx().as_array());
+    }
+
+    #[test]
+    fn test_vec3_set_swizzles() {
+        let mut v = Vec3::new(1.0, 2.0, 3.0);
+        v.set_xz(Vec2::new(4.0, 5.0));
+        assert_eq!(v.as_array(), &[4.0, 2.0, 5.0]);
+        v.set_bgr(Vec3::new(6.0, 7.0, 8.0));
+        assert_eq!(v.as_array(), &[8.0, 7.0, 6.0]);
+    }
+
+    #[test]
+    fn test_vec3_component_mul_and_div() {
+        let a = Vec3::new(1.0, 2.0, 3.0);
+        let b = Vec3::new(4.0, 5.0, 6.0);
+        assert_eq!((a * b).as_array(), &[4.0, 10.0, 18.0]);
+        assert_eq!((b / a).as_array(), &[4.0, 2.5, 2.0]);
+
+        let mut c = a;
+        c *= b;
+        c /= b;
+        assert_eq!(c.as_array(), a.as_array());
+    }
+
+    #[test]
+    fn test_vec3_min_max_clamp() {
+        let a = Vec3::new(1.0, 5.0, -3.0);
+        let b = Vec3::new(2.0, 4.0, -6.0);
+        assert_eq!(a.min(&b).as_array(), &[1.0, 4.0, -6.0]);
+        assert_eq!(a.max(&b).as_array(), &[2.0, 5.0, -3.0]);
+        assert_eq!(
+            a.clamp(&Vec3::ZERO, &Vec3::ONE).as_array(),
+            &[1.0, 1.0, 0.0]
+        );
+        assert_relative_eq!(a.min_element(), -3.0, epsilon = f32::EPSILON);
+        assert_relative_eq!(a.max_element(), 5.0, epsilon = f32::EPSILON);
+    }
+
+    #[test]
+    fn test_vec3_rounding_glsl_semantics() {
+        let v = Vec3::new(-1.25, 0.0, 2.5);
+        assert_eq!(v.abs().as_array(), &[1.25, 0.0, 2.5]);
+        assert_eq!(v.floor().as_array(), &[-2.0, 0.0, 2.0]);
+        assert_eq!(v.ceil().as_array(), &[-1.0, 0.0, 3.0]);
+        assert_eq!(v.round().as_array(), &[-1.0, 0.0, 3.0]);
+        // GLSL fract is x - floor(x), so negative inputs map into [0, 1)
+        assert_eq!(v.fract().as_array(), &[0.75, 0.0, 0.5]);
+        // GLSL sign maps zero to zero
+        assert_eq!(v.signum().as_array(), &[-1.0, 0.0, 1.0]);
+        assert_eq!(
+            Vec3::new(2.0, 4.0, -0.5).recip().as_array(),
+            &[0.5, 0.25, -2.0]
+        );
+    }
+
+    #[test]
+    fn test_vec3_lerp_mix_step_smoothstep() {
+        let a = Vec3::new(0.0, 10.0, -2.0);
+        let b = Vec3::new(10.0, 20.0, 2.0);
+        assert_eq!(a.lerp(&b, 0.25).as_array(), &[2.5, 12.5, -1.0]);
+        assert_eq!(
+            a.mix(&b, &Vec3::new(0.0, 0.5, 1.0)).as_array(),
+            &[0.0, 15.0, 2.0]
+        );
+
+        let x = Vec3::new(0.5, 1.0, 1.5);
+        assert_eq!(x.step(&Vec3::ONE).as_array(), &[0.0, 1.0, 1.0]);
+
+        let s = x.smoothstep(&Vec3::ZERO, &Vec3::new(2.0, 2.0, 2.0));
+        assert_relative_eq!(s.x, 0.15625, epsilon = 1e-6);
+        assert_relative_eq!(s.y, 0.5, epsilon = 1e-6);
+        assert_relative_eq!(s.z, 0.84375, epsilon = 1e-6);
+        let clamped = Vec3::new(-1.0, 3.0, 1.0).smoothstep(&Vec3::ZERO, &Vec3::ONE);
+        assert_eq!(clamped.as_array(), &[0.0, 1.0, 1.0]);
+    }
+
+    #[test]
+    fn test_vec3_sum_and_product() {
+        let vectors = [
+            Vec3::new(1.0, 2.0, 3.0),
+            Vec3::new(4.0, 5.0, 6.0),
+            Vec3::new(-1.0, 0.5, 2.0),
+        ];
+        let sum: Vec3 = vectors.iter().sum();
+        assert_eq!(sum.as_array(), &[4.0, 7.5, 11.0]);
+        let product: Vec3 = vectors.into_iter().product();
+        assert_eq!(product.as_array(), &[-4.0, 5.0, 36.0]);
+        let empty: Vec3 = std::iter::empty::<Vec3>().sum();
+        assert_eq!(empty.as_array(), Vec3::ZERO.as_array());
+    }
+
+    #[test]
+    fn test_vec3_distance() {
+        let a = Vec3::new(1.0, 2.0, 3.0);
+        let b = Vec3::new(4.0, 6.0, 15.0);
+        assert_relative_eq!(a.distance_squared(&b), 169.0, epsilon = f32::EPSILON);
+        assert_relative_eq!(a.distance(&b), 13.0, epsilon = f32::EPSILON);
+    }
+
+    #[test]
+    fn test_vec3_reflect() {
+        let incident = Vec3::new(1.0, -1.0, 0.0);
+        let reflected = incident.reflect(&Vec3::Y);
+        assert_eq!(reflected.as_array(), &[1.0, 1.0, 0.0]);
+    }
+
+    #[test]
+    fn test_vec3_refract() {
+        // Straight through the surface, no bending regardless of eta
+        let straight = Vec3::NEG_Y.refract(&Vec3::Y, 1.0 / 1.5).unwrap();
+        assert_relative_eq!(straight.y, -1.0, epsilon = 1e-6);
+
+        // Snell's law: sin(theta_t) = eta * sin(theta_i)
+        let incident = Vec3::new(1.0, -1.0, 0.0).normalize();
+        let eta = 1.0 / 1.33;
+        let refracted = incident.refract(&Vec3::Y, eta).unwrap();
+        assert_relative_eq!(refracted.magnitude(), 1.0, epsilon = 1e-6);
+        assert_relative_eq!(refracted.x, eta * incident.x, epsilon = 1e-6);
+
+        // Grazing ray leaving a dense medium: total internal reflection
+        let grazing = Vec3::new(1.0, -0.1, 0.0).normalize();
+        assert!(grazing.refract(&Vec3::Y, 1.5).is_none());
+    }
+
+    #[test]
+    fn test_vec3_project_and_reject() {
+        let v = Vec3::new(3.0, 4.0, 5.0);
+        let onto = Vec3::new(0.0, 2.0, 0.0);
+        assert_eq!(v.project_onto(&onto).as_array(), &[0.0, 4.0, 0.0]);
+        assert_eq!(v.reject_from(&onto).as_array(), &[3.0, 0.0, 5.0]);
+        assert_eq!(
+            v.project_onto(&Vec3::ZERO).as_array(),
+            Vec3::ZERO.as_array()
+        );
+    }
+
+    #[test]
+    fn test_vec3_angle_between() {
+        use std::f32::consts::{FRAC_PI_2, PI};
+        assert_relative_eq!(Vec3::X.angle_between(&Vec3::Y), FRAC_PI_2, epsilon = 1e-6);
+        assert_relative_eq!(Vec3::X.angle_between(&Vec3::NEG_X), PI, epsilon = 1e-6);
+        assert_relative_eq!(Vec3::X.angle_between(&(Vec3::X * 5.0)), 0.0, epsilon = 1e-6);
+        // Tiny angles stay accurate where acos(dot) would round to zero
+        let tiny = Vec3::new(1.0, 1e-5, 0.0);
+        assert_relative_eq!(Vec3::X.angle_between(&tiny), 1e-5, max_relative = 1e-3);
+    }
+
+    #[test]
+    fn test_vec3_slerp() {
+        use std::f32::consts::FRAC_1_SQRT_2;
+        let mid = Vec3::X.slerp(&Vec3::Y, 0.5);
+        assert_relative_eq!(mid.x, FRAC_1_SQRT_2, epsilon = 1e-6);
+        assert_relative_eq!(mid.y, FRAC_1_SQRT_2, epsilon = 1e-6);
+        assert_relative_eq!(mid.z, 0.0, epsilon = 1e-6);
+
+        // Magnitude is interpolated linearly
+        let scaled = Vec3::X.slerp(&(Vec3::Y * 3.0), 0.5);
+        assert_relative_eq!(scaled.magnitude(), 2.0, epsilon = 1e-6);
+
+        // Antiparallel inputs still produce a unit vector perpendicular halfway
+        let half_turn = Vec3::X.slerp(&Vec3::NEG_X, 0.5);
+        assert_relative_eq!(half_turn.magnitude(), 1.0, epsilon = 1e-6);
+        assert_relative_eq!(half_turn.dot(&Vec3::X), 0.0, epsilon = 1e-6);
+
+        // A zero endpoint has no direction, so the vectors are interpolated linearly
+        let from_zero = Vec3::ZERO.slerp(&Vec3::new(0.0, 2.0, 0.0), 0.25);
+        assert_eq!(from_zero.as_array(), &[0.0, 0.5, 0.0]);
+        let to_zero = Vec3::new(4.0, 0.0, 0.0).slerp(&Vec3::ZERO, 0.5);
+        assert_eq!(to_zero.as_array(), &[2.0, 0.0, 0.0]);
+        assert_eq!(Vec3::ZERO.slerp(&Vec3::ZERO, 0.5).as_array(), &[0.0; 3]);
+    }
+
+    #[test]
+    fn test_vec3_any_orthonormal_basis() {
+        let normals = [
+            Vec3::Z,
+            Vec3::NEG_Z,
+            Vec3::X,
+            Vec3::new(1.0, 2.0, 3.0).normalize(),
+            Vec3::new(0.3, -0.2, -0.9).normalize(),
+        ];
+        for n in normals {
+            let (b1, b2) = n.any_orthonormal_basis();
+            assert_relative_eq!(b1.magnitude(), 1.0, epsilon = 1e-6);
+            assert_relative_eq!(b2.magnitude(), 1.0, epsilon = 1e-6);
+            assert_relative_eq!(b1.dot(&b2), 0.0, epsilon = 1e-6);
+            assert_relative_eq!(b1.dot(&n), 0.0, epsilon = 1e-6);
+            assert_relative_eq!(b2.dot(&n), 0.0, epsilon = 1e-6);
+            let cross = b1.cross(&b2);
+            assert_relative_eq!(cross.x, n.x, epsilon = 1e-6);
+            assert_relative_eq!(cross.y, n.y, epsilon = 1e-6);
+            assert_relative_eq!(cross.z, n.z, epsilon = 1e-6);
+        }
+    }
+
+    #[test]
+    fn test_vec3_comparisons_and_select() {
+        let a = Vec3::new(1.0, 5.0, f32::NAN);
+        let b = Vec3::new(2.0, 5.0, 0.0);
+        let lt = a.cmplt(&b);
+        assert!(lt.x && !lt.y && !lt.z);
+        let eq = a.cmpeq(&b);
+        assert!(!eq.x && eq.y && !eq.z);
+        let ne = a.cmpne(&b);
+        assert!(ne.x && !ne.y && ne.z);
+        assert!(a.cmple(&b).any());
+        assert!(!a.cmpge(&b).all());
+
+        let picked = Vec3::select(a.cmpgt(&b), a, b);
+        assert_relative_eq!(picked.x, 2.0, epsilon = f32::EPSILON);
+        assert_relative_eq!(picked.y, 5.0, epsilon = f32::EPSILON);
+        assert_relative_eq!(picked.z, 0.0, epsilon = f32::EPSILON);
+    }
 }
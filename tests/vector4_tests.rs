@@ -1,7 +1,7 @@
 #[cfg(test)]
 mod tests {
     use approx::assert_relative_eq;
-    use vectorama::vector::vec4::Vec4;
+    use vectorama::vector::{Vector, vec2::Vec2, vec4::Vec4};
 
     #[test]
     fn test_vec4_new_and_fields() {
@@ -179,4 +179,71 @@ mod tests {
         assert_relative_eq!(yzw.y, 3.0, epsilon = f32::EPSILON);
         assert_relative_eq!(yzw.z, 4.0, epsilon = f32::EPSILON);
     }
+
+    #[test]
+    fn test_vec4_constants() {
+        const POINT: Vec4 = Vec4::W;
+        assert_relative_eq!(POINT.x, 0.0, epsilon = f32::EPSILON);
+        assert_relative_eq!(POINT.w, 1.0, epsilon = f32::EPSILON);
+        assert_relative_eq!(Vec4::NEG_ONE.z, -1.0, epsilon = f32::EPSILON);
+        assert!(Vec4::INFINITY.y.is_infinite());
+    }
+
+    #[test]
+    fn test_vec4_array_and_matrix_views() {
+        let mut v = Vec4::new(1.0, 2.0, 3.0, 4.0);
+        let slice: &[f32] = v.as_ref();
+        assert_eq!(slice, &[1.0, 2.0, 3.0, 4.0]);
+        assert_eq!(v.as_array(), &[1.0, 2.0, 3.0, 4.0]);
+
+        let matrix = Vector::<4>::from(v);
+        for m in 0..4 {
+            assert_relative_eq!(matrix[m], slice[m], epsilon = f32::EPSILON);
+        }
+        let back = Vec4::from(matrix);
+        assert_relative_eq!(back.x, 1.0, epsilon = f32::EPSILON);
+        assert_relative_eq!(back.y, 2.0, epsilon = f32::EPSILON);
+        assert_relative_eq!(back.z, 3.0, epsilon = f32::EPSILON);
+        assert_relative_eq!(back.w, 4.0, epsilon = f32::EPSILON);
+
+        v.x = 10.0;
+        assert_relative_eq!(v.as_array()[0], 10.0, epsilon = f32::EPSILON);
+    }
+
+    #[test]
+    fn test_vec4_swizzles() {
+        let mut v = Vec4::new(1.0, 2.0, 3.0, 4.0);
+        assert_eq!(v.wzyx().as_array(), &[4.0, 3.0, 2.0, 1.0]);
+        assert_eq!(v.xxyy().as_array(), &[1.0, 1.0, 2.0, 2.0]);
+        assert_eq!(v.abgr().as_array(), v.qpts().as_array());
+        assert_eq!(v.ww().as_array(), &[4.0, 4.0]);
+        v.set_wx(Vec2::new(5.0, 6.0));
+        assert_eq!(v.as_array(), &[6.0, 2.0, 3.0, 5.0]);
+    }
+
+    #[test]
+    fn test_vec4_component_wise() {
+        let a = Vec4::new(1.0, -2.0, 3.0, -4.0);
+        let b = Vec4::new(2.0, 2.0, 2.0, 2.0);
+        assert_eq!((a * b).as_array(), &[2.0, -4.0, 6.0, -8.0]);
+        assert_eq!(a.signum().as_array(), &[1.0, -1.0, 1.0, -1.0]);
+        assert_eq!(a.clamp(&-b, &b).as_array(), &[1.0, -2.0, 2.0, -2.0]);
+        let product: Vec4 = [a, b].iter().product();
+        assert_eq!(product.as_array(), &[2.0, -4.0, 6.0, -8.0]);
+    }
+
+    #[test]
+    fn test_vector_component_wise() {
+        let a = Vector::<4>::from([1.0, -2.0, 3.0, -4.0]);
+        let b = Vector::<4>::from([0.5, 0.5, 0.5, 0.5]);
+        let mul: [f32; 4] = a.component_mul(&b).into();
+        assert_eq!(mul, [0.5, -1.0, 1.5, -2.0]);
+        let div: [f32; 4] = a.component_div(&b).into();
+        assert_eq!(div, [2.0, -4.0, 6.0, -8.0]);
+        assert_relative_eq!(a.min_element(), -4.0, epsilon = f32::EPSILON);
+        let sum: [f32; 4] = [a, b].into_iter().sum::<Vector<4>>().into();
+        assert_eq!(sum, [1.5, -1.5, 3.5, -3.5]);
+        let product: [f32; 4] = [a, b].into_iter().product::<Vector<4>>().into();
+        assert_eq!(product, mul);
+    }
 }